@@ -52,6 +52,8 @@ export interface JsMinifyOptions {
   outputPath?: string
 
   inlineSourcesContent?: boolean
+
+  nameCacheFile?: string
 }
 
 /**
@@ -0,0 +1,343 @@
+#[macro_use]
+extern crate napi_derive;
+
+mod util;
+
+use std::{backtrace::Backtrace, collections::HashMap, env, panic::set_hook};
+
+use anyhow::{bail, Context};
+use napi::{bindgen_prelude::*, Task};
+use serde::{Deserialize, Serialize};
+use swc_core::common::{FileName, SourceFile, Span};
+use swc_css::{
+    codegen::CodegenConfig,
+    compat::compiler,
+    minifier::options::{MinifyOptions, Targets},
+    modules::CssClassName,
+    parser::{error::Error as ParserError, parser::ParserConfig},
+    prefixer::options::Options as PrefixerOptions,
+    transform::{self, transform, PluginOptions, SourceMapOptions, TransformError},
+};
+use swc_nodejs_common::{deserialize_json, get_deserialized, MapErr};
+
+use crate::util::try_with;
+
+#[napi::module_init]
+fn init() {
+    if cfg!(debug_assertions) || env::var("SWC_DEBUG").unwrap_or_default() == "1" {
+        set_hook(Box::new(|panic_info| {
+            let backtrace = Backtrace::force_capture();
+            println!("Panic: {:?}\nBacktrace: {:?}", panic_info, backtrace);
+        }));
+    }
+}
+
+#[napi_derive::napi(object)]
+#[derive(Debug, Serialize)]
+pub struct Diagnostic {
+    pub level: String,
+    pub message: String,
+    pub span: serde_json::Value,
+}
+
+#[napi_derive::napi(object)]
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TransformOutput {
+    pub code: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub map: Option<String>,
+    /// A map of the class names in the stylesheet to the class names to use
+    /// from JavaScript, if `cssModules` is enabled.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub css_modules: Option<HashMap<String, Vec<CssModuleClassName>>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub errors: Option<Vec<Diagnostic>>,
+}
+
+#[napi_derive::napi(object)]
+#[derive(Debug, Serialize)]
+pub struct CssModuleClassName {
+    /// `local`, `global` or `import`.
+    pub kind: String,
+    pub name: String,
+    /// The module specifier, if `kind` is `import`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub from: Option<String>,
+}
+
+struct TransformTask {
+    code: String,
+    options: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TransformOptions {
+    #[serde(default)]
+    filename: Option<String>,
+
+    #[serde(default)]
+    parser: ParserConfig,
+
+    #[serde(default)]
+    minify: bool,
+
+    #[serde(default)]
+    css_modules: Option<CssModulesOption>,
+
+    /// Browsers to support. If specified, syntax not supported by them is
+    /// lowered and vendor prefixes are added.
+    #[serde(default)]
+    targets: Option<Targets>,
+
+    #[serde(default)]
+    source_map: bool,
+
+    /// The source map of the input, e.g. one generated by a SCSS compiler.
+    /// The generated source map points to its sources if specified.
+    #[serde(default)]
+    input_source_map: Option<String>,
+
+    /// Wasm plugins to run, as pairs of the path to the plugin and its config.
+    #[serde(default)]
+    plugins: Vec<(String, serde_json::Value)>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+pub enum CssModulesOption {
+    Bool(bool),
+    Options(CssModulesOptions),
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct CssModulesOptions {
+    /// The pattern of the renamed class names. `[name]` is replaced with the
+    /// name of the file, `[local]` with the original class name and `[hash]`
+    /// with a hash of the path of the file relative to the current directory.
+    ///
+    /// Defaults to `[local]_[hash]`.
+    #[serde(default = "default_css_modules_pattern")]
+    pattern: String,
+}
+
+fn default_css_modules_pattern() -> String {
+    "[local]_[hash]".into()
+}
+
+#[napi]
+impl Task for TransformTask {
+    type JsValue = TransformOutput;
+    type Output = TransformOutput;
+
+    fn compute(&mut self) -> napi::Result<Self::Output> {
+        let opts = deserialize_json(&self.options)
+            .context("failed to deserialize css options")
+            .convert_err()?;
+
+        transform_inner(&self.code, opts).convert_err()
+    }
+
+    fn resolve(&mut self, _env: napi::Env, output: Self::Output) -> napi::Result<Self::JsValue> {
+        Ok(output)
+    }
+}
+
+fn to_diagnostic(fm: &SourceFile, err: ParserError) -> Diagnostic {
+    let message = err.message().to_string();
+    let span: Span = err.into_inner().0;
+
+    Diagnostic {
+        level: "error".into(),
+        message,
+        span: serde_json::json!({
+            "start": (span.lo - fm.start_pos).0,
+            "end": (span.hi - fm.start_pos).0,
+        }),
+    }
+}
+
+fn transform_inner(code: &str, opts: TransformOptions) -> anyhow::Result<TransformOutput> {
+    swc_core::common::GLOBALS.set(&Default::default(), || {
+        try_with(|cm, handler| {
+            let filename = match &opts.filename {
+                Some(v) => FileName::Real(v.into()),
+                None => FileName::Anon,
+            };
+
+            let fm = cm.new_source_file(filename.into(), code.into());
+
+            let css_modules = match opts.css_modules {
+                Some(CssModulesOption::Bool(true)) => Some(default_css_modules_pattern()),
+                Some(CssModulesOption::Options(options)) => Some(options.pattern),
+                Some(CssModulesOption::Bool(false)) | None => None,
+            };
+            let css_modules = css_modules
+                .map(|pattern| -> anyhow::Result<_> {
+                    Ok(transform::CssModulesOptions {
+                        pattern,
+                        root: env::current_dir().context("failed to get the current directory")?,
+                    })
+                })
+                .transpose()?;
+
+            let compat = opts
+                .targets
+                .clone()
+                .map(|targets| {
+                    compiler::Config::from_targets(targets).context("failed to resolve targets")
+                })
+                .transpose()?;
+
+            let input_source_map = opts
+                .input_source_map
+                .as_deref()
+                .map(|map| {
+                    swc_css::sourcemap::SourceMap::from_slice(map.as_bytes())
+                        .context("failed to parse the input source map")
+                })
+                .transpose()?;
+
+            let output = transform(
+                cm,
+                &fm,
+                transform::TransformOptions {
+                    parser: opts.parser,
+                    plugins: opts
+                        .plugins
+                        .into_iter()
+                        .map(|(path, config)| PluginOptions {
+                            path: path.into(),
+                            config,
+                        })
+                        .collect(),
+                    css_modules,
+                    compat,
+                    prefixer: opts
+                        .targets
+                        .clone()
+                        .map(|targets| PrefixerOptions { env: Some(targets) }),
+                    minify: opts.minify.then(|| MinifyOptions {
+                        targets: opts.targets.clone(),
+                        ..Default::default()
+                    }),
+                    codegen: CodegenConfig {
+                        minify: opts.minify,
+                    },
+                    source_map: opts.source_map.then_some(SourceMapOptions {
+                        input: input_source_map,
+                        inline_sources_content: true,
+                    }),
+                },
+            );
+
+            let output = match output {
+                Ok(v) => v,
+                Err(TransformError::Parse(err)) => {
+                    err.to_diagnostics(handler).emit();
+
+                    bail!("failed to parse input as stylesheet")
+                }
+                Err(TransformError::Transform(err)) => return Err(err),
+            };
+
+            let errors = if output.errors.is_empty() {
+                None
+            } else {
+                Some(
+                    output
+                        .errors
+                        .into_iter()
+                        .map(|err| to_diagnostic(&fm, err))
+                        .collect::<Vec<_>>(),
+                )
+            };
+
+            let css_modules = output.css_modules.map(|result| {
+                result
+                    .renamed
+                    .into_iter()
+                    .map(|(local, names)| {
+                        let names = names
+                            .into_iter()
+                            .map(|name| match name {
+                                CssClassName::Local { name } => CssModuleClassName {
+                                    kind: "local".into(),
+                                    name: name.value.to_string(),
+                                    from: None,
+                                },
+                                CssClassName::Global { name } => CssModuleClassName {
+                                    kind: "global".into(),
+                                    name: name.value.to_string(),
+                                    from: None,
+                                },
+                                CssClassName::Import { name, from } => CssModuleClassName {
+                                    kind: "import".into(),
+                                    name: name.value.to_string(),
+                                    from: Some(from.to_string()),
+                                },
+                            })
+                            .collect();
+
+                        (local.to_string(), names)
+                    })
+                    .collect()
+            });
+
+            let map = output
+                .map
+                .map(|map| -> anyhow::Result<_> {
+                    let mut buf = Vec::new();
+
+                    map.to_writer(&mut buf)
+                        .context("failed to write source map")?;
+
+                    String::from_utf8(buf).context("source map is not utf-8")
+                })
+                .transpose()?;
+
+            Ok(TransformOutput {
+                code: output.code,
+                map,
+                css_modules,
+                errors,
+            })
+        })
+    })
+}
+
+fn to_string(code: Either<Buffer, String>) -> String {
+    match code {
+        Either::A(code) => String::from_utf8_lossy(code.as_ref()).to_string(),
+        Either::B(code) => code,
+    }
+}
+
+#[allow(unused)]
+#[napi]
+fn transform_css(
+    code: Either<Buffer, String>,
+    opts: Buffer,
+    signal: Option<AbortSignal>,
+) -> AsyncTask<TransformTask> {
+    let code = to_string(code);
+    let options = String::from_utf8_lossy(opts.as_ref()).to_string();
+
+    let task = TransformTask { code, options };
+
+    AsyncTask::with_optional_signal(task, signal)
+}
+
+#[allow(unused)]
+#[napi]
+pub fn transform_css_sync(
+    code: Either<Buffer, String>,
+    opts: Buffer,
+) -> napi::Result<TransformOutput> {
+    let code = to_string(code);
+    let options = get_deserialized(opts)?;
+
+    transform_inner(&code, options).convert_err()
+}
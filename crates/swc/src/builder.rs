@@ -1,14 +1,18 @@
-use std::sync::Arc;
+use std::{path::Path, sync::Arc};
 
 use compat::es2015::regenerator;
 use either::Either;
 use rustc_hash::FxHashMap;
 use swc_atoms::Atom;
 use swc_common::{
-    comments::Comments, errors::Handler, sync::Lrc, util::take::Take, FileName, Mark, SourceMap,
+    comments::Comments,
+    errors::{Handler, HANDLER},
+    sync::Lrc,
+    util::take::Take,
+    FileName, Mark, SourceMap,
 };
 use swc_ecma_ast::{EsVersion, Module, Pass, Script};
-use swc_ecma_minifier::option::{terser::TerserTopLevelOptions, MinifyOptions};
+use swc_ecma_minifier::option::{terser::TerserTopLevelOptions, MangleCache, MinifyOptions};
 use swc_ecma_parser::Syntax;
 use swc_ecma_transforms::{
     compat,
@@ -23,7 +27,10 @@ use swc_ecma_transforms::{
 use swc_ecma_visit::{noop_visit_mut_type, visit_mut_pass, VisitMut, VisitMutWith};
 use swc_visit::Optional;
 
-use crate::config::{GlobalPassOption, JsMinifyOptions, ModuleConfig};
+use crate::{
+    config::{GlobalPassOption, JsMinifyOptions, ModuleConfig},
+    name_cache::SharedNameCache,
+};
 
 /// Builder is used to create a high performance `Compiler`.
 pub struct PassBuilder<'a, 'b, P: Pass> {
@@ -172,6 +179,32 @@ impl<'a, 'b, P: Pass> PassBuilder<'a, 'b, P> {
     where
         P: 'cmt,
     {
+        let rewrite_relative_import_extensions = match module {
+            Some(ModuleConfig::CommonJs(ref c)) => c
+                .rewrite_relative_import_extensions
+                .then_some(&*c.out_file_extension),
+            Some(ModuleConfig::Amd(ref c)) => c
+                .config
+                .rewrite_relative_import_extensions
+                .then_some(&*c.config.out_file_extension),
+            Some(ModuleConfig::Umd(ref c)) => c
+                .config
+                .rewrite_relative_import_extensions
+                .then_some(&*c.config.out_file_extension),
+            Some(ModuleConfig::SystemJs(ref c)) => c
+                .config
+                .rewrite_relative_import_extensions
+                .then_some(&*c.config.out_file_extension),
+            Some(ModuleConfig::Es6(ref c)) | Some(ModuleConfig::NodeNext(ref c)) => c
+                .config
+                .rewrite_relative_import_extensions
+                .then_some(&*c.config.out_file_extension),
+            None => None,
+        }
+        .map(|out_file_extension| {
+            modules::rewrite_import_extensions(self.unresolved_mark, out_file_extension)
+        });
+
         let (need_analyzer, import_interop, ignore_dynamic) = match module {
             Some(ModuleConfig::CommonJs(ref c)) => (true, c.import_interop(), c.ignore_dynamic),
             Some(ModuleConfig::Amd(ref c)) => {
@@ -328,6 +361,7 @@ impl<'a, 'b, P: Pass> PassBuilder<'a, 'b, P> {
             ),
             compat_pass,
             // module / helper
+            rewrite_relative_import_extensions,
             Optional::new(
                 modules::import_analysis::import_analyzer(import_interop, ignore_dynamic),
                 need_analyzer,
@@ -418,6 +452,8 @@ impl VisitMut for MinifierPass<'_> {
 
             m.visit_mut_with(&mut resolver(unresolved_mark, top_level_mark, false));
 
+            let name_cache = load_name_cache(options.name_cache_file.as_deref());
+
             m.map_with_mut(|m| {
                 swc_ecma_minifier::optimize(
                     m.into(),
@@ -428,11 +464,15 @@ impl VisitMut for MinifierPass<'_> {
                     &swc_ecma_minifier::option::ExtraOptions {
                         unresolved_mark,
                         top_level_mark,
-                        mangle_name_cache: None,
+                        mangle_name_cache: name_cache
+                            .as_ref()
+                            .map(|name_cache| name_cache.cache.clone() as Arc<dyn MangleCache>),
                     },
                 )
                 .expect_module()
-            })
+            });
+
+            save_name_cache(options.name_cache_file.as_deref(), name_cache.as_deref());
         }
     }
 
@@ -481,6 +521,8 @@ impl VisitMut for MinifierPass<'_> {
 
             m.visit_mut_with(&mut resolver(unresolved_mark, top_level_mark, false));
 
+            let name_cache = load_name_cache(options.name_cache_file.as_deref());
+
             m.map_with_mut(|m| {
                 swc_ecma_minifier::optimize(
                     m.into(),
@@ -491,15 +533,46 @@ impl VisitMut for MinifierPass<'_> {
                     &swc_ecma_minifier::option::ExtraOptions {
                         unresolved_mark,
                         top_level_mark,
-                        mangle_name_cache: None,
+                        mangle_name_cache: name_cache
+                            .as_ref()
+                            .map(|name_cache| name_cache.cache.clone() as Arc<dyn MangleCache>),
                     },
                 )
                 .expect_script()
-            })
+            });
+
+            save_name_cache(options.name_cache_file.as_deref(), name_cache.as_deref());
         }
     }
 }
 
+/// Loads `jsc.minify.nameCacheFile`. Errors are reported to [HANDLER].
+fn load_name_cache(path: Option<&str>) -> Option<Arc<SharedNameCache>> {
+    let path = path?;
+
+    match SharedNameCache::get_or_load(Path::new(path)) {
+        Ok(cache) => Some(cache),
+        Err(err) => {
+            HANDLER.with(|handler| {
+                handler.err(&format!("failed to load the name cache from {path}: {err}"));
+            });
+            None
+        }
+    }
+}
+
+fn save_name_cache(path: Option<&str>, cache: Option<&SharedNameCache>) {
+    let (Some(path), Some(cache)) = (path, cache) else {
+        return;
+    };
+
+    if let Err(err) = cache.save() {
+        HANDLER.with(|handler| {
+            handler.err(&format!("failed to save the name cache to {path}: {err}"));
+        });
+    }
+}
+
 pub(crate) fn should_enable(target: EsVersion, feature: EsVersion) -> bool {
     target < feature
 }
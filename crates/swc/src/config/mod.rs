@@ -26,13 +26,20 @@ use swc_config::{
     merge::Merge,
 };
 use swc_ecma_ast::{noop_pass, EsVersion, Expr, Pass, Program};
+use swc_ecma_codegen::{Indent, QuoteStyle, TrailingComma};
 use swc_ecma_ext_transforms::jest;
 use swc_ecma_lints::{
     config::LintConfig,
     rules::{lint_pass, LintParams},
 };
-use swc_ecma_loader::resolvers::{
-    lru::CachingResolver, node::NodeModulesResolver, tsc::TsConfigResolver,
+use swc_ecma_loader::{
+    resolve::{ImportKind, Resolution, Resolve},
+    resolvers::{
+        lru::CachingResolver,
+        node::NodeModulesResolver,
+        pnp::{find_manifest, PnpResolver},
+        tsc::TsConfigResolver,
+    },
 };
 pub use swc_ecma_minifier::js::*;
 use swc_ecma_minifier::option::terser::TerserTopLevelOptions;
@@ -67,7 +74,7 @@ use swc_ecma_utils::NodeIgnoringSpan;
 use swc_ecma_visit::VisitMutWith;
 use swc_visit::Optional;
 
-pub use crate::plugin::PluginConfig;
+pub use crate::plugin::{PluginConfig, PluginLimitsConfig};
 use crate::{
     builder::PassBuilder, dropped_comments_preserver::dropped_comments_preserver, SwcImportResolver,
 };
@@ -188,6 +195,36 @@ pub struct Options {
 pub struct ExperimentalOptions {
     #[serde(default)]
     pub error_format: Option<ErrorFormat>,
+
+    /// Caches the output of [crate::Compiler::process_js_file] on disk.
+    #[serde(default)]
+    pub transform_cache: Option<TransformCacheOptions>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields, rename_all = "camelCase")]
+pub struct TransformCacheOptions {
+    /// The directory to store the cache in. It's created if it does not
+    /// exist.
+    pub dir: PathBuf,
+
+    /// The maximum total size of the cache in bytes. Least recently used
+    /// entries are removed when it's exceeded.
+    #[serde(default = "default_transform_cache_max_size")]
+    pub max_size: u64,
+}
+
+impl TransformCacheOptions {
+    pub fn new(dir: PathBuf) -> Self {
+        TransformCacheOptions {
+            dir,
+            max_size: default_transform_cache_max_size(),
+        }
+    }
+}
+
+const fn default_transform_cache_max_size() -> u64 {
+    512 * 1024 * 1024
 }
 
 impl Options {
@@ -253,6 +290,7 @@ impl Options {
             keep_class_names,
             base_url,
             paths,
+            conditions,
             minify: mut js_minify,
             experimental,
             lints,
@@ -545,7 +583,8 @@ impl Options {
         };
 
         let paths = paths.into_iter().collect();
-        let resolver = ModuleConfig::get_resolver(&base_url, paths, base, cfg.module.as_ref());
+        let resolver =
+            ModuleConfig::get_resolver(&base_url, paths, conditions, base, cfg.module.as_ref());
 
         let pass = PassBuilder::new(
             cm,
@@ -609,6 +648,7 @@ impl Options {
                 Box::new(crate::plugin::plugins(
                     experimental.plugins,
                     experimental.plugin_env_vars,
+                    experimental.plugin_limits,
                     transform_metadata_context,
                     comments.cloned(),
                     cm.clone(),
@@ -774,6 +814,7 @@ impl Options {
                 .into_bool(),
             codegen_inline_script,
             emit_isolated_dts: experimental.emit_isolated_dts.into_bool(),
+            emit_isolated_dts_map: experimental.emit_isolated_dts_map.into_bool(),
             unresolved_mark,
             resolver,
         })
@@ -1087,6 +1128,7 @@ pub struct BuiltInput<P: Pass> {
     pub codegen_inline_script: bool,
 
     pub emit_isolated_dts: bool,
+    pub emit_isolated_dts_map: bool,
     pub unresolved_mark: Mark,
     pub resolver: Option<(FileName, Arc<dyn ImportResolver>)>,
 }
@@ -1120,6 +1162,7 @@ where
             emit_assert_for_import_attributes: self.emit_assert_for_import_attributes,
             codegen_inline_script: self.codegen_inline_script,
             emit_isolated_dts: self.emit_isolated_dts,
+            emit_isolated_dts_map: self.emit_isolated_dts_map,
             unresolved_mark: self.unresolved_mark,
             resolver: self.resolver,
         }
@@ -1157,6 +1200,14 @@ pub struct JscConfig {
     #[serde(default)]
     pub paths: Paths,
 
+    /// Conditions used to resolve `exports` and `imports` of package.json
+    /// while rewriting import paths, e.g. `["node", "import"]`.
+    ///
+    /// `default` is always matched. If not set, `node` is used along with
+    /// `import` or `require` depending on how the module is imported.
+    #[serde(default)]
+    pub conditions: Option<Vec<String>>,
+
     #[serde(default)]
     pub minify: Option<JsMinifyOptions>,
 
@@ -1187,6 +1238,23 @@ pub struct JscOutputConfig {
 
     #[serde(default)]
     pub source_map_url: Option<String>,
+
+    /// Quotes to use for string literals. Ignored if minifying.
+    #[serde(default)]
+    pub quote_style: Option<QuoteStyle>,
+
+    /// Where to emit trailing commas in lists printed on multiple lines.
+    #[serde(default)]
+    pub trailing_comma: Option<TrailingComma>,
+
+    /// Prints arguments of calls on multiple lines if the line would be
+    /// longer than this. Ignored if minifying.
+    #[serde(default)]
+    pub print_width: Option<usize>,
+
+    /// Indentation of lines. Defaults to four spaces.
+    #[serde(default)]
+    pub indent: Option<Indent>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
@@ -1208,6 +1276,9 @@ pub struct JscExperimental {
     pub plugins: Option<Vec<PluginConfig>>,
     #[serde(default)]
     pub plugin_env_vars: Option<Vec<Atom>>,
+    /// Limits on resources of plugins, keyed by the names used in `plugins`.
+    #[serde(default)]
+    pub plugin_limits: Option<FxHashMap<String, PluginLimitsConfig>>,
     /// If true, keeps import assertions in the output.
     #[serde(default, alias = "keepImportAssertions")]
     pub keep_import_attributes: BoolConfig<false>,
@@ -1234,6 +1305,13 @@ pub struct JscExperimental {
     #[serde(default)]
     pub emit_isolated_dts: BoolConfig<false>,
 
+    /// Emit source maps of TypeScript definitions (`.d.ts.map`) mapping
+    /// declarations to the original source.
+    ///
+    /// This requires `emitIsolatedDts`.
+    #[serde(default)]
+    pub emit_isolated_dts_map: BoolConfig<false>,
+
     #[serde(default)]
     pub disable_all_lints: BoolConfig<true>,
 }
@@ -1352,6 +1430,7 @@ impl ModuleConfig {
     pub fn get_resolver(
         base_url: &Path,
         paths: CompiledPaths,
+        conditions: Option<Vec<String>>,
         base: &FileName,
         config: Option<&ModuleConfig>,
     ) -> Option<(FileName, Arc<dyn ImportResolver>)> {
@@ -1370,11 +1449,18 @@ impl ModuleConfig {
 
         let base_url = base_url.to_path_buf();
         let resolver = match config {
-            None => build_resolver(base_url, paths, false, &util::Config::default_js_ext()),
+            None => build_resolver(
+                base_url,
+                paths,
+                conditions,
+                false,
+                &util::Config::default_js_ext(),
+            ),
             Some(ModuleConfig::Es6(config)) | Some(ModuleConfig::NodeNext(config)) => {
                 build_resolver(
                     base_url,
                     paths,
+                    conditions,
                     config.config.resolve_fully,
                     &config.config.out_file_extension,
                 )
@@ -1382,24 +1468,28 @@ impl ModuleConfig {
             Some(ModuleConfig::CommonJs(config)) => build_resolver(
                 base_url,
                 paths,
+                conditions,
                 config.resolve_fully,
                 &config.out_file_extension,
             ),
             Some(ModuleConfig::Umd(config)) => build_resolver(
                 base_url,
                 paths,
+                conditions,
                 config.config.resolve_fully,
                 &config.config.out_file_extension,
             ),
             Some(ModuleConfig::Amd(config)) => build_resolver(
                 base_url,
                 paths,
+                conditions,
                 config.config.resolve_fully,
                 &config.config.out_file_extension,
             ),
             Some(ModuleConfig::SystemJs(config)) => build_resolver(
                 base_url,
                 paths,
+                conditions,
                 config.config.resolve_fully,
                 &config.config.out_file_extension,
             ),
@@ -1712,13 +1802,49 @@ pub(crate) fn default_env_name() -> String {
     }
 }
 
+/// Keeps imports of packages resolved using a Yarn PnP manifest as they are.
+///
+/// The files of packages are in zip archives, so they can't be imported by
+/// paths relative to the importing file.
+struct PnpPackageSpecifiers<R>(R);
+
+impl<R> Resolve for PnpPackageSpecifiers<R>
+where
+    R: Resolve,
+{
+    fn resolve(&self, base: &FileName, module_specifier: &str) -> Result<Resolution, Error> {
+        self.resolve_with_kind(base, module_specifier, Default::default())
+    }
+
+    fn resolve_with_kind(
+        &self,
+        base: &FileName,
+        module_specifier: &str,
+        kind: ImportKind,
+    ) -> Result<Resolution, Error> {
+        let resolved = self.0.resolve_with_kind(base, module_specifier, kind)?;
+
+        if module_specifier.starts_with('.') || Path::new(module_specifier).is_absolute() {
+            return Ok(resolved);
+        }
+
+        Ok(Resolution {
+            filename: FileName::Custom(module_specifier.into()),
+            ..resolved
+        })
+    }
+}
+
 fn build_resolver(
     mut base_url: PathBuf,
     paths: CompiledPaths,
+    conditions: Option<Vec<String>>,
     resolve_fully: bool,
     file_extension: &str,
 ) -> SwcImportResolver {
-    static CACHE: Lazy<DashMap<(PathBuf, CompiledPaths, bool), SwcImportResolver, FxBuildHasher>> =
+    type CacheKey = (PathBuf, CompiledPaths, Option<Vec<String>>, bool);
+
+    static CACHE: Lazy<DashMap<CacheKey, SwcImportResolver, FxBuildHasher>> =
         Lazy::new(Default::default);
 
     // On Windows, we need to normalize path as UNC path.
@@ -1735,16 +1861,47 @@ fn build_resolver(
             .unwrap();
     }
 
-    if let Some(cached) = CACHE.get(&(base_url.clone(), paths.clone(), resolve_fully)) {
+    let key = (
+        base_url.clone(),
+        paths.clone(),
+        conditions.clone(),
+        resolve_fully,
+    );
+
+    if let Some(cached) = CACHE.get(&key) {
         return cached.clone();
     }
 
     let r = {
-        let r = NodeModulesResolver::without_node_modules(
-            swc_ecma_loader::TargetEnv::Node,
-            Default::default(),
-            true,
-        );
+        let node_resolver = || {
+            let r = NodeModulesResolver::without_node_modules(
+                swc_ecma_loader::TargetEnv::Node,
+                Default::default(),
+                true,
+            );
+
+            match &conditions {
+                Some(conditions) => r.with_conditions(conditions.clone()),
+                None => r,
+            }
+        };
+        // Yarn PnP projects don't have `node_modules`, so dependencies are
+        // resolved using the manifest.
+        let r: Box<dyn Resolve> = match find_manifest(&base_url) {
+            Some(manifest) => match PnpResolver::new(&manifest, node_resolver()) {
+                Ok(pnp) => Box::new(PnpPackageSpecifiers(pnp)),
+                Err(err) => {
+                    tracing::warn!(
+                        "failed to load the PnP manifest `{}`, falling back to the node resolver: \
+                         {:?}",
+                        manifest.display(),
+                        err
+                    );
+                    Box::new(node_resolver())
+                }
+            },
+            None => Box::new(node_resolver()),
+        };
 
         let r = CachingResolver::new(1024, r);
 
@@ -1762,7 +1919,7 @@ fn build_resolver(
         Arc::new(r)
     };
 
-    CACHE.insert((base_url, paths, resolve_fully), r.clone());
+    CACHE.insert(key, r.clone());
 
     r
 }
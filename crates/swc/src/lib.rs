@@ -134,12 +134,13 @@ use swc_common::{
     comments::Comments, errors::Handler, sync::Lrc, FileName, Mark, SourceFile, SourceMap, Spanned,
     GLOBALS,
 };
-pub use swc_compiler_base::{PrintArgs, TransformOutput};
+pub use swc_compiler_base::{PrintArgs, TransformArtifact, TransformOutput};
 pub use swc_config::config_types::{BoolConfig, BoolOr, BoolOrDataConfig};
 use swc_ecma_ast::{noop_pass, EsVersion, Pass, Program};
 use swc_ecma_codegen::{to_code_with_comments, Node};
-use swc_ecma_loader::resolvers::{
-    lru::CachingResolver, node::NodeModulesResolver, tsc::TsConfigResolver,
+use swc_ecma_loader::{
+    resolve::Resolve,
+    resolvers::{lru::CachingResolver, tsc::TsConfigResolver},
 };
 use swc_ecma_minifier::option::{MangleCache, MinifyOptions, TopLevelOptions};
 use swc_ecma_parser::{EsSyntax, Syntax};
@@ -155,21 +156,28 @@ use swc_ecma_visit::{FoldWith, VisitMutWith, VisitWith};
 pub use swc_error_reporters::handler::{try_with_handler, HandlerOpts};
 pub use swc_node_comments::SwcComments;
 use swc_timer::timer;
-use swc_transform_common::output::experimental_emit;
+use swc_transform_common::output::{capture_artifacts, experimental_emit};
 use swc_typescript::fast_dts::FastDts;
 use tracing::warn;
 use url::Url;
 
 pub use crate::builder::PassBuilder;
-use crate::config::{
-    BuiltInput, Config, ConfigFile, InputSourceMap, IsModule, JsMinifyCommentOption,
-    JsMinifyOptions, Options, OutputCharset, Rc, RootMode, SourceMapsConfig,
+use crate::{
+    config::{
+        BuiltInput, Config, ConfigFile, InputSourceMap, IsModule, JsMinifyCommentOption,
+        JsMinifyOptions, Options, OutputCharset, Rc, RootMode, SourceMapsConfig,
+        TransformCacheOptions,
+    },
+    name_cache::SharedNameCache,
+    transform_cache::{CachedOutput, TransformCache},
 };
 
 mod builder;
 pub mod config;
 mod dropped_comments_preserver;
+mod name_cache;
 mod plugin;
+mod transform_cache;
 pub mod wasm_analysis;
 pub mod resolver {
     use std::path::PathBuf;
@@ -211,9 +219,8 @@ pub mod resolver {
     }
 }
 
-type SwcImportResolver = Arc<
-    NodeImportResolver<CachingResolver<TsConfigResolver<CachingResolver<NodeModulesResolver>>>>,
->;
+type SwcImportResolver =
+    Arc<NodeImportResolver<CachingResolver<TsConfigResolver<CachingResolver<Box<dyn Resolve>>>>>>;
 
 /// All methods accept [Handler], which is a storage for errors.
 ///
@@ -594,16 +601,51 @@ impl Compiler {
     where
         P: 'a + Pass,
     {
-        self.run(move || {
-            let _timer = timer!("Compiler.parse");
+        let config = self.config_for_file(opts, name)?;
 
-            if let FileName::Real(ref path) = name {
-                if !opts.config.matches(path)? {
-                    return Ok(None);
-                }
+        self.parse_js_with_config(
+            fm,
+            program,
+            handler,
+            opts,
+            name,
+            config,
+            comments,
+            before_pass,
+        )
+    }
+
+    /// Returns the config for a file, or [None] if the file should be skipped.
+    fn config_for_file(&self, opts: &Options, name: &FileName) -> Result<Option<Config>, Error> {
+        if let FileName::Real(ref path) = name {
+            if !opts.config.matches(path)? {
+                return Ok(None);
             }
+        }
+
+        self.read_config(opts, name)
+    }
+
+    /// [Compiler::parse_js_as_input] with a config returned by
+    /// [Compiler::config_for_file].
+    #[allow(clippy::too_many_arguments)]
+    fn parse_js_with_config<'a, P>(
+        &'a self,
+        fm: Lrc<SourceFile>,
+        program: Option<Program>,
+        handler: &'a Handler,
+        opts: &Options,
+        name: &FileName,
+        config: Option<Config>,
+        comments: Option<&'a SingleThreadedComments>,
+        before_pass: impl 'a + FnOnce(&Program) -> P,
+    ) -> Result<Option<BuiltInput<impl 'a + Pass>>, Error>
+    where
+        P: 'a + Pass,
+    {
+        self.run(move || {
+            let _timer = timer!("Compiler.parse");
 
-            let config = self.read_config(opts, name)?;
             let config = match config {
                 Some(v) => v,
                 None => return Ok(None),
@@ -683,18 +725,51 @@ impl Compiler {
         custom_before_pass: impl FnOnce(&Program) -> P1,
         custom_after_pass: impl FnOnce(&Program) -> P2,
     ) -> Result<TransformOutput, Error>
+    where
+        P1: Pass,
+        P2: Pass,
+    {
+        let config = self.run(|| self.config_for_file(opts, &fm.name))?;
+
+        self.process_js_with_config(
+            fm,
+            program,
+            handler,
+            opts,
+            config,
+            comments,
+            custom_before_pass,
+            custom_after_pass,
+        )
+    }
+
+    /// [Compiler::process_js_with_custom_pass] with a config returned by
+    /// [Compiler::config_for_file].
+    #[allow(clippy::too_many_arguments)]
+    fn process_js_with_config<P1, P2>(
+        &self,
+        fm: Arc<SourceFile>,
+        program: Option<Program>,
+        handler: &Handler,
+        opts: &Options,
+        config: Option<Config>,
+        comments: SingleThreadedComments,
+        custom_before_pass: impl FnOnce(&Program) -> P1,
+        custom_after_pass: impl FnOnce(&Program) -> P2,
+    ) -> Result<TransformOutput, Error>
     where
         P1: Pass,
         P2: Pass,
     {
         self.run(|| -> Result<_, Error> {
             let config = self.run(|| {
-                self.parse_js_as_input(
+                self.parse_js_with_config(
                     fm.clone(),
                     program,
                     handler,
                     opts,
                     &fm.name,
+                    config,
                     Some(&comments),
                     |program| custom_before_pass(program),
                 )
@@ -736,6 +811,10 @@ impl Compiler {
         handler: &Handler,
         opts: &Options,
     ) -> Result<TransformOutput, Error> {
+        if let Some(cache) = &opts.experimental.transform_cache {
+            return self.process_js_file_with_cache(fm, handler, opts, cache);
+        }
+
         self.process_js_with_custom_pass(
             fm,
             None,
@@ -747,6 +826,69 @@ impl Compiler {
         )
     }
 
+    fn process_js_file_with_cache(
+        &self,
+        fm: Arc<SourceFile>,
+        handler: &Handler,
+        opts: &Options,
+        cache_opts: &TransformCacheOptions,
+    ) -> Result<TransformOutput, Error> {
+        let config = self.config_for_file(opts, &fm.name)?;
+
+        let process = |handler: &Handler, config: Option<Config>| {
+            self.process_js_with_config(
+                fm.clone(),
+                None,
+                handler,
+                opts,
+                config,
+                SingleThreadedComments::default(),
+                |_| noop_pass(),
+                |_| noop_pass(),
+            )
+        };
+
+        let Some(config) = config else {
+            return process(handler, None);
+        };
+        if transform_cache::depends_on_file_system(opts, &config) {
+            return process(handler, Some(config));
+        }
+
+        let plugin_hashes = match &config.jsc.experimental.plugins {
+            Some(plugins) => crate::plugin::plugin_module_hashes(
+                config.jsc.experimental.cache_root.as_deref(),
+                plugins,
+            )?,
+            None => Some(Vec::new()),
+        };
+        let Some(plugin_hashes) = plugin_hashes else {
+            // The output cannot be keyed on plugins without known bytes.
+            return process(handler, Some(config));
+        };
+
+        let key = transform_cache::cache_key(&fm, opts, &config, &plugin_hashes);
+        let cache = TransformCache::get_or_create(&cache_opts.dir);
+
+        if let Some(cached) = cache.get(&key) {
+            return Ok(cached.replay(&fm, handler));
+        }
+
+        let (output, diagnostics) =
+            transform_cache::capture_diagnostics(handler, |handler| process(handler, Some(config)));
+        let output = output?;
+
+        if let Some(diagnostics) = diagnostics {
+            if let Some(cached) = CachedOutput::new(&fm, &output, &diagnostics) {
+                if let Err(err) = cache.insert(key, &cached, cache_opts.max_size) {
+                    warn!("{:?}", err);
+                }
+            }
+        }
+
+        Ok(output)
+    }
+
     #[tracing::instrument(skip_all)]
     pub fn minify(
         &self,
@@ -858,6 +1000,20 @@ impl Compiler {
 
             let is_mangler_enabled = min_opts.mangle.is_some();
 
+            let name_cache = opts
+                .name_cache_file
+                .as_deref()
+                .map(|path| {
+                    SharedNameCache::get_or_load(Path::new(path))
+                        .with_context(|| format!("failed to load the name cache from {path}"))
+                })
+                .transpose()?;
+            let mangle_name_cache = extras.mangle_name_cache.or_else(|| {
+                name_cache
+                    .as_ref()
+                    .map(|name_cache| name_cache.cache.clone() as Arc<dyn MangleCache>)
+            });
+
             program = self.run_transform(handler, false, || {
                 program.mutate(&mut paren_remover(Some(&comments)));
 
@@ -872,7 +1028,7 @@ impl Compiler {
                     &swc_ecma_minifier::option::ExtraOptions {
                         unresolved_mark,
                         top_level_mark,
-                        mangle_name_cache: extras.mangle_name_cache,
+                        mangle_name_cache,
                     },
                 );
 
@@ -883,6 +1039,12 @@ impl Compiler {
                 program
             });
 
+            if let (Some(path), Some(name_cache)) = (&opts.name_cache_file, &name_cache) {
+                name_cache
+                    .save()
+                    .with_context(|| format!("failed to save the name cache to {path}"))?;
+            }
+
             let preserve_comments = opts
                 .format
                 .comments
@@ -978,6 +1140,13 @@ impl Compiler {
                 );
             }
 
+            if config.emit_isolated_dts_map && !config.emit_isolated_dts {
+                handler.warn(
+                    "jsc.experimental.emitIsolatedDtsMap is enabled but \
+                     jsc.experimental.emitIsolatedDts is not",
+                );
+            }
+
             let emit_dts = config.syntax.typescript() && config.emit_isolated_dts;
             let source_map_names = if config.source_maps.enabled() {
                 let mut v = swc_compiler_base::IdentCollector {
@@ -1014,22 +1183,45 @@ impl Compiler {
                         .emit();
                 }
 
-                let dts_code = to_code_with_comments(Some(&comments), &program);
-                Some(dts_code)
+                if config.emit_isolated_dts_map {
+                    let output = swc_compiler_base::print(
+                        self.cm.clone(),
+                        &program,
+                        PrintArgs {
+                            source_root: config.source_root.as_deref(),
+                            source_file_name: config.source_file_name.as_deref(),
+                            output_path: config.output_path.clone(),
+                            source_map: SourceMapsConfig::Bool(true),
+                            comments: Some(&comments),
+                            ..Default::default()
+                        },
+                    )?;
+
+                    Some((output.code, output.map))
+                } else {
+                    let dts_code = to_code_with_comments(Some(&comments), &program);
+                    Some((dts_code, None))
+                }
             } else {
                 None
             };
 
             let pass = config.pass;
-            let (program, output) = swc_transform_common::output::capture(|| {
-                if let Some(dts_code) = dts_code {
-                    experimental_emit("__swc_isolated_declarations__".into(), dts_code);
-                }
+            let ((program, output), artifacts) = capture_artifacts(|| {
+                swc_transform_common::output::capture(|| {
+                    if let Some((dts_code, dts_map)) = dts_code {
+                        experimental_emit("__swc_isolated_declarations__".into(), dts_code);
+
+                        if let Some(dts_map) = dts_map {
+                            experimental_emit("__swc_isolated_declarations_map__".into(), dts_map);
+                        }
+                    }
 
-                helpers::HELPERS.set(&Helpers::new(config.external_helpers), || {
-                    HANDLER.set(handler, || {
-                        // Fold module
-                        program.apply(pass)
+                    helpers::HELPERS.set(&Helpers::new(config.external_helpers), || {
+                        HANDLER.set(handler, || {
+                            // Fold module
+                            program.apply(pass)
+                        })
                     })
                 })
             });
@@ -1068,7 +1260,11 @@ impl Compiler {
                         .with_emit_assert_for_import_attributes(
                             config.emit_assert_for_import_attributes,
                         )
-                        .with_inline_script(config.codegen_inline_script),
+                        .with_inline_script(config.codegen_inline_script)
+                        .with_quote_style(config.output.quote_style.unwrap_or_default())
+                        .with_trailing_comma(config.output.trailing_comma.unwrap_or_default())
+                        .with_print_width(config.output.print_width)
+                        .with_indent(config.output.indent.unwrap_or_default()),
                     output: if output.is_empty() {
                         None
                     } else {
@@ -1077,6 +1273,17 @@ impl Compiler {
                     source_map_url: config.output.source_map_url.as_deref(),
                 },
             )
+            .map(|mut output| {
+                output.artifacts = artifacts
+                    .into_iter()
+                    .map(|artifact| TransformArtifact {
+                        path: artifact.path,
+                        content: BASE64_STANDARD.encode(artifact.content),
+                    })
+                    .collect();
+
+                output
+            })
         })
     }
 }
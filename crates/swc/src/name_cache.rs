@@ -0,0 +1,127 @@
+//! Name caches of `jsc.minify.nameCacheFile` and `nameCacheFile` of
+//! [crate::Compiler::minify].
+//!
+//! A file is loaded once per process and the cache is shared by all files
+//! compiled with it, so names mangled in parallel are merged in memory. Writes
+//! are serialized and contain all names of the process, so the last write
+//! does not drop names added by other files. If the file is modified by
+//! something else, e.g. another process, it's reloaded on the next use, and
+//! names in memory are replaced with its content.
+
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+    sync::Arc,
+    time::SystemTime,
+};
+
+use dashmap::DashMap;
+use once_cell::sync::Lazy;
+use parking_lot::Mutex;
+use swc_ecma_minifier::option::{NameCache, SimpleMangleCache};
+
+static NAME_CACHES: Lazy<DashMap<PathBuf, Arc<SharedNameCache>>> = Lazy::new(Default::default);
+
+pub(crate) struct SharedNameCache {
+    path: PathBuf,
+    pub cache: Arc<SimpleMangleCache>,
+    /// The file as of the last load or save.
+    saved: Mutex<SavedFile>,
+}
+
+struct SavedFile {
+    content: NameCache,
+    /// `None` if the file does not exist.
+    modified: Option<SystemTime>,
+}
+
+impl SharedNameCache {
+    /// Returns the cache of the file at `path`, loading it on first use and
+    /// reloading it if the file is modified by something else.
+    pub fn get_or_load(path: &Path) -> io::Result<Arc<Self>> {
+        let path = canonicalize(path);
+
+        if let Some(cache) = NAME_CACHES.get(&path).map(|cache| cache.clone()) {
+            cache.reload_if_modified()?;
+            return Ok(cache);
+        }
+
+        let modified = modified_time(&path)?;
+        let cache = SimpleMangleCache::load(&path)?;
+        let shared = Arc::new(SharedNameCache {
+            saved: Mutex::new(SavedFile {
+                content: cache.to_name_cache(),
+                modified,
+            }),
+            path: path.clone(),
+            cache: Arc::new(cache),
+        });
+
+        // Another thread may have loaded it in the meantime.
+        Ok(NAME_CACHES.entry(path).or_insert(shared).clone())
+    }
+
+    /// Replaces the names in memory with the content of the file if the
+    /// modification time of the file differs from the last load or save.
+    fn reload_if_modified(&self) -> io::Result<()> {
+        let mut saved = self.saved.lock();
+
+        let modified = modified_time(&self.path)?;
+        if saved.modified == modified {
+            return Ok(());
+        }
+
+        let loaded = SimpleMangleCache::load(&self.path)?;
+        saved.content = loaded.to_name_cache();
+        saved.modified = modified;
+        *self.cache.props.write() = loaded.props.into_inner();
+
+        Ok(())
+    }
+
+    /// Writes the cache to the file, unless it's not changed since the last
+    /// write.
+    pub fn save(&self) -> io::Result<()> {
+        let mut saved = self.saved.lock();
+
+        let name_cache = self.cache.to_name_cache();
+        if saved.content == name_cache {
+            return Ok(());
+        }
+
+        fs::write(&self.path, serde_json::to_string_pretty(&name_cache)?)?;
+        saved.content = name_cache;
+        saved.modified = modified_time(&self.path)?;
+
+        Ok(())
+    }
+}
+
+/// Resolves `path` so that all spellings of it share a cache. The file itself
+/// may not exist yet.
+fn canonicalize(path: &Path) -> PathBuf {
+    if let Ok(path) = fs::canonicalize(path) {
+        return path;
+    }
+
+    let (Some(dir), Some(name)) = (path.parent(), path.file_name()) else {
+        return path.to_path_buf();
+    };
+    let dir = if dir.as_os_str().is_empty() {
+        Path::new(".")
+    } else {
+        dir
+    };
+
+    fs::canonicalize(dir)
+        .map(|dir| dir.join(name))
+        .unwrap_or_else(|_| path.to_path_buf())
+}
+
+fn modified_time(path: &Path) -> io::Result<Option<SystemTime>> {
+    match fs::metadata(path) {
+        Ok(metadata) => metadata.modified().map(Some),
+        Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(None),
+        Err(err) => Err(err),
+    }
+}
@@ -11,6 +11,7 @@ use std::path::PathBuf;
 use anyhow::{Context, Result};
 use atoms::Atom;
 use common::FileName;
+use rustc_hash::FxHashMap;
 use serde::{Deserialize, Serialize};
 use swc_common::errors::{DiagnosticId, HANDLER};
 use swc_ecma_ast::Pass;
@@ -33,9 +34,28 @@ use swc_ecma_visit::{fold_pass, noop_fold_type, Fold};
 #[serde(deny_unknown_fields, rename_all = "camelCase")]
 pub struct PluginConfig(pub String, pub serde_json::Value);
 
+/// Limits on resources a plugin can use while transforming a file.
+///
+/// A plugin exceeding them fails the transform instead of hanging or
+/// exhausting the memory of the process.
+///
+/// Only instructions and memory are limited, and there is no wall-clock
+/// deadline. Time spent in host functions called by the plugin is not counted.
+#[derive(Debug, Default, Clone, Copy, Serialize, Deserialize)]
+#[serde(deny_unknown_fields, rename_all = "camelCase")]
+pub struct PluginLimitsConfig {
+    /// The maximum number of Wasm instructions the plugin can execute.
+    #[serde(default)]
+    pub fuel: Option<u64>,
+    /// The maximum size of the linear memory of the plugin, in bytes.
+    #[serde(default)]
+    pub max_memory: Option<u64>,
+}
+
 pub fn plugins(
     configured_plugins: Option<Vec<PluginConfig>>,
     plugin_env_vars: Option<Vec<Atom>>,
+    plugin_limits: Option<FxHashMap<String, PluginLimitsConfig>>,
     metadata_context: std::sync::Arc<swc_common::plugin::metadata::TransformPluginMetadataContext>,
     comments: Option<swc_common::comments::SingleThreadedComments>,
     source_map: std::sync::Arc<swc_common::SourceMap>,
@@ -44,6 +64,7 @@ pub fn plugins(
     fold_pass(RustPlugins {
         plugins: configured_plugins,
         plugin_env_vars: plugin_env_vars.map(std::sync::Arc::new),
+        plugin_limits: plugin_limits.unwrap_or_default(),
         metadata_context,
         comments,
         source_map,
@@ -54,6 +75,7 @@ pub fn plugins(
 struct RustPlugins {
     plugins: Option<Vec<PluginConfig>>,
     plugin_env_vars: Option<std::sync::Arc<Vec<Atom>>>,
+    plugin_limits: FxHashMap<String, PluginLimitsConfig>,
     metadata_context: std::sync::Arc<swc_common::plugin::metadata::TransformPluginMetadataContext>,
     comments: Option<swc_common::comments::SingleThreadedComments>,
     source_map: std::sync::Arc<swc_common::SourceMap>,
@@ -111,13 +133,20 @@ impl RustPlugins {
                 // transform.
                 if let Some(plugins) = &mut self.plugins {
                     for p in plugins.drain(..) {
-                        let plugin_module_bytes = crate::config::PLUGIN_MODULE_CACHE
+                        let limits = self.plugin_limits.get(&p.0).copied().unwrap_or_default();
+                        let mut cache = crate::config::PLUGIN_MODULE_CACHE
                             .inner
                             .get()
                             .unwrap()
-                            .lock()
-                            .get(&p.0)
-                            .expect("plugin module should be loaded");
+                            .lock();
+                        // Counting instructions requires a module compiled with metering.
+                        let plugin_module_bytes = if limits.fuel.is_some() {
+                            cache.get_metered(&p.0)?
+                        } else {
+                            cache.get(&p.0)
+                        }
+                        .expect("plugin module should be loaded");
+                        drop(cache);
 
                         let plugin_name = plugin_module_bytes.get_module_name().to_string();
                         let runtime = swc_plugin_runner::wasix_runtime::build_wasi_runtime(
@@ -139,6 +168,12 @@ impl RustPlugins {
                                 plugin_module_bytes,
                                 Some(p.1),
                                 runtime,
+                            )
+                            .with_limits(
+                                swc_plugin_runner::limits::PluginLimits {
+                                    fuel: limits.fuel,
+                                    max_memory: limits.max_memory,
+                                },
                             );
 
                         let span = tracing::span!(
@@ -247,3 +282,36 @@ pub(crate) fn compile_wasm_plugins(
 
     Ok(())
 }
+
+/// Returns the hashes of the modules of `plugins`, or [None] if the bytes of a
+/// module are not available.
+#[cfg(all(feature = "plugin", not(target_arch = "wasm32")))]
+pub(crate) fn plugin_module_hashes(
+    cache_root: Option<&str>,
+    plugins: &[PluginConfig],
+) -> Result<Option<Vec<String>>> {
+    compile_wasm_plugins(cache_root, plugins).context("Failed to compile wasm plugins")?;
+
+    let inner_cache = crate::config::PLUGIN_MODULE_CACHE
+        .inner
+        .get()
+        .expect("Cache should be available")
+        .lock();
+
+    Ok(plugins
+        .iter()
+        .map(|p| {
+            crate::transform_cache::plugin_hash(&p.0, || {
+                inner_cache.get_raw_bytes(&p.0).map(|bytes| bytes.to_vec())
+            })
+        })
+        .collect())
+}
+
+#[cfg(not(all(feature = "plugin", not(target_arch = "wasm32"))))]
+pub(crate) fn plugin_module_hashes(
+    _cache_root: Option<&str>,
+    _plugins: &[PluginConfig],
+) -> Result<Option<Vec<String>>> {
+    Ok(Some(Vec::new()))
+}
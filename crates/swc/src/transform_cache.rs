@@ -0,0 +1,530 @@
+//! On-disk cache for the output of [crate::Compiler::process_js_file].
+//!
+//! An entry is keyed on everything the output depends on: the source, the
+//! options, the resolved `.swcrc`, the input source map, the browserslist
+//! config used by `env`, the version of swc and the Wasm plugins in use.
+//! Diagnostics are stored with spans relative to the source file, so they can
+//! be replayed against a [SourceMap](swc_common::SourceMap) of another
+//! process.
+//!
+//! Files probed to resolve imports, with `jsc.baseUrl`, `jsc.paths` or
+//! `resolveFully`, are not tracked, so the cache is bypassed if the import
+//! resolver is enabled.
+
+use std::{
+    env,
+    fmt::Write,
+    fs,
+    io::ErrorKind,
+    mem::take,
+    path::{Path, PathBuf},
+    sync::Arc,
+    time::SystemTime,
+};
+
+use anyhow::{Context, Error};
+use dashmap::DashMap;
+use once_cell::sync::Lazy;
+use parking_lot::Mutex;
+use preset_env_base::query::targets_to_versions;
+use rustc_hash::FxHashMap;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use swc_common::{
+    errors::{Diagnostic, DiagnosticBuilder, Emitter, Handler, HANDLER},
+    BytePos, FileName, MultiSpan, SourceFile, Span,
+};
+use swc_config::merge::Merge;
+
+use crate::{
+    config::{
+        Config, ExperimentalOptions, GlobalInliningPassEnvs, InputSourceMap, ModuleConfig, Options,
+    },
+    TransformArtifact, TransformOutput,
+};
+
+/// Start of the source file in the positions of cached spans.
+const CACHED_START_POS: BytePos = BytePos(1);
+
+/// Caches are shared by all compilers in the process, so the size of each
+/// directory is tracked in one place.
+static CACHES: Lazy<DashMap<PathBuf, Arc<TransformCache>>> = Lazy::new(Default::default);
+
+pub(crate) struct TransformCache {
+    dir: PathBuf,
+    /// Loaded from the directory on first use.
+    index: Mutex<Option<Index>>,
+}
+
+#[derive(Default)]
+struct Index {
+    entries: FxHashMap<String, IndexEntry>,
+    total_size: u64,
+}
+
+struct IndexEntry {
+    size: u64,
+    last_used: SystemTime,
+}
+
+#[derive(Serialize, Deserialize)]
+pub(crate) struct CachedOutput {
+    code: String,
+    map: Option<String>,
+    output: Option<String>,
+    artifacts: Vec<(String, String)>,
+    diagnostics: Vec<Diagnostic>,
+}
+
+impl TransformCache {
+    pub fn get_or_create(dir: &Path) -> Arc<Self> {
+        CACHES
+            .entry(dir.to_path_buf())
+            .or_insert_with(|| {
+                Arc::new(TransformCache {
+                    dir: dir.to_path_buf(),
+                    index: Default::default(),
+                })
+            })
+            .clone()
+    }
+
+    /// Returns the cached output for `key`, marking it as recently used.
+    ///
+    /// Uses are only tracked in memory, so entries written by other processes
+    /// are ordered by the time they were written.
+    ///
+    /// Unreadable entries are treated as missing.
+    pub fn get(&self, key: &str) -> Option<CachedOutput> {
+        let path = self.dir.join(key);
+        let bytes = fs::read(&path).ok()?;
+        let output = serde_json::from_slice(&bytes).ok()?;
+
+        let mut index = self.index.lock();
+        if let Some(entry) = index.as_mut().and_then(|index| index.entries.get_mut(key)) {
+            entry.last_used = SystemTime::now();
+        }
+
+        Some(output)
+    }
+
+    /// Stores `output` and removes least recently used entries until the
+    /// cache fits in `max_size` bytes.
+    pub fn insert(&self, key: String, output: &CachedOutput, max_size: u64) -> Result<(), Error> {
+        let bytes = serde_json::to_vec(output)?;
+        let size = bytes.len() as u64;
+
+        fs::create_dir_all(&self.dir).with_context(|| {
+            format!(
+                "failed to create the transform cache directory {}",
+                self.dir.display()
+            )
+        })?;
+
+        // Write to a temporary file first, so other processes never read a
+        // partially written entry.
+        let tmp = self.dir.join(format!("{key}.{}.tmp", std::process::id()));
+        fs::write(&tmp, bytes)
+            .and_then(|_| fs::rename(&tmp, self.dir.join(&key)))
+            .with_context(|| format!("failed to write the transform cache entry {key}"))?;
+
+        let mut index = self.index.lock();
+        let index = index.get_or_insert_with(|| Index::load(&self.dir));
+
+        if let Some(prev) = index.entries.insert(
+            key,
+            IndexEntry {
+                size,
+                last_used: SystemTime::now(),
+            },
+        ) {
+            index.total_size -= prev.size;
+        }
+        index.total_size += size;
+
+        if index.total_size > max_size {
+            index.evict(&self.dir, max_size);
+        }
+
+        Ok(())
+    }
+}
+
+impl Index {
+    fn load(dir: &Path) -> Self {
+        let mut index = Index::default();
+
+        let Ok(read_dir) = fs::read_dir(dir) else {
+            return index;
+        };
+
+        for entry in read_dir.flatten() {
+            let name = entry.file_name().to_string_lossy().into_owned();
+            if name.ends_with(".tmp") {
+                continue;
+            }
+            let Ok(metadata) = entry.metadata() else {
+                continue;
+            };
+            if !metadata.is_file() {
+                continue;
+            }
+
+            index.total_size += metadata.len();
+            index.entries.insert(
+                name,
+                IndexEntry {
+                    size: metadata.len(),
+                    last_used: metadata.modified().unwrap_or(SystemTime::UNIX_EPOCH),
+                },
+            );
+        }
+
+        index
+    }
+
+    /// Removes entries until the cache uses 80% of `max_size`, so that
+    /// eviction does not run again on the next insertion.
+    fn evict(&mut self, dir: &Path, max_size: u64) {
+        let target = max_size / 5 * 4;
+
+        let mut entries = self
+            .entries
+            .iter()
+            .map(|(key, entry)| (entry.last_used, key.clone()))
+            .collect::<Vec<_>>();
+        entries.sort_unstable();
+
+        for (_, key) in entries {
+            if self.total_size <= target {
+                break;
+            }
+
+            match fs::remove_file(dir.join(&key)) {
+                Ok(()) => {}
+                Err(err) if err.kind() == ErrorKind::NotFound => {}
+                Err(err) => {
+                    tracing::warn!("failed to remove the transform cache entry {key}: {err}");
+                    continue;
+                }
+            }
+
+            if let Some(entry) = self.entries.remove(&key) {
+                self.total_size -= entry.size;
+            }
+        }
+    }
+}
+
+/// Returns the config used for a file, i.e. `config` overridden by the config
+/// in `opts`.
+fn merged_config(opts: &Options, config: &Config) -> Config {
+    let mut merged = opts.config.clone();
+    merged.merge(config.clone());
+    merged
+}
+
+/// Returns true if the output depends on files which are not tracked by
+/// [cache_key], i.e. files probed by the import resolver.
+pub(crate) fn depends_on_file_system(opts: &Options, config: &Config) -> bool {
+    let config = merged_config(opts, config);
+
+    let resolve_fully = match &config.module {
+        Some(ModuleConfig::CommonJs(c)) => c.resolve_fully,
+        Some(ModuleConfig::Umd(c)) => c.config.resolve_fully,
+        Some(ModuleConfig::Amd(c)) => c.config.resolve_fully,
+        Some(ModuleConfig::SystemJs(c)) => c.config.resolve_fully,
+        Some(ModuleConfig::Es6(c)) | Some(ModuleConfig::NodeNext(c)) => c.config.resolve_fully,
+        None => false,
+    };
+
+    resolve_fully || !config.jsc.base_url.as_os_str().is_empty() || !config.jsc.paths.is_empty()
+}
+
+/// Computes the key of the cache entry for `fm`.
+///
+/// `config` is the config resolved for the file, and `plugin_hashes` are the
+/// hashes of the Wasm plugins it uses.
+pub(crate) fn cache_key(
+    fm: &SourceFile,
+    opts: &Options,
+    config: &Config,
+    plugin_hashes: &[String],
+) -> String {
+    let mut hasher = Sha256::new();
+    let mut update = |bytes: &[u8]| {
+        hasher.update((bytes.len() as u64).to_le_bytes());
+        hasher.update(bytes);
+    };
+
+    update(env!("CARGO_PKG_VERSION").as_bytes());
+    update(format!("{:?}", fm.name).as_bytes());
+    update(fm.src.as_bytes());
+    // The location and the size of the cache do not affect the output.
+    let opts = Options {
+        experimental: ExperimentalOptions {
+            transform_cache: None,
+            ..opts.experimental.clone()
+        },
+        ..opts.clone()
+    };
+    update(format!("{opts:?}").as_bytes());
+    update(format!("{config:?}").as_bytes());
+
+    let config = merged_config(&opts, config);
+
+    // Values of environment variables can be inlined by the optimizer or read
+    // by plugins.
+    let mut env_vars = config
+        .jsc
+        .experimental
+        .plugin_env_vars
+        .iter()
+        .flatten()
+        .map(|name| name.to_string())
+        .collect::<Vec<_>>();
+    if let Some(GlobalInliningPassEnvs::List(names)) = config
+        .jsc
+        .transform
+        .as_ref()
+        .and_then(|transform| transform.optimizer.as_ref())
+        .and_then(|optimizer| optimizer.globals.as_ref())
+        .map(|globals| &globals.envs)
+    {
+        env_vars.extend(names.iter().cloned());
+    }
+    env_vars.sort_unstable();
+    env_vars.dedup();
+
+    for name in env_vars {
+        update(name.as_bytes());
+        update(env::var(&name).unwrap_or_default().as_bytes());
+    }
+
+    // Without targets, `env` reads them from the browserslist config.
+    if let Some(env) = config.env.as_ref().filter(|env| env.targets.is_none()) {
+        let versions = targets_to_versions(None, env.path.clone()).map_err(|err| err.to_string());
+        update(format!("{versions:?}").as_bytes());
+    }
+
+    if !matches!(config.input_source_map, Some(InputSourceMap::Bool(false))) {
+        match input_source_map_file(fm).map(fs::read) {
+            Some(Ok(map)) => update(&map),
+            _ => update(&[]),
+        }
+    }
+
+    for hash in plugin_hashes {
+        update(hash.as_bytes());
+    }
+
+    hex(&hasher.finalize())
+}
+
+/// Returns the path of the input source map which is read from disk for `fm`,
+/// if any.
+///
+/// This follows [crate::Compiler::process_js_file], but looks for
+/// `sourceMappingURL` in the whole source instead of the trailing comments.
+/// Inline source maps are a part of the source, so they are not returned.
+fn input_source_map_file(fm: &SourceFile) -> Option<PathBuf> {
+    let FileName::Real(filename) = &*fm.name else {
+        return None;
+    };
+
+    const PREFIX: &str = "sourceMappingURL=";
+    let url = fm.src.rfind(PREFIX).map(|idx| {
+        let url = &fm.src[idx + PREFIX.len()..];
+        url[..url.find(char::is_whitespace).unwrap_or(url.len())].trim_end_matches("*/")
+    });
+    if url.is_some_and(|url| url.starts_with("data:")) {
+        return None;
+    }
+
+    let fallback = PathBuf::from(format!("{}.map", filename.display()));
+    match url {
+        Some(url) => {
+            let path = filename.parent()?.join(url);
+            if path.exists() {
+                Some(path)
+            } else {
+                Some(fallback)
+            }
+        }
+        None => Some(fallback),
+    }
+}
+
+/// Returns the hash of the bytes of a Wasm plugin.
+///
+/// Plugins are not revalidated in the lifecycle of a process, so the hash
+/// is computed once for each plugin.
+#[cfg(all(feature = "plugin", not(target_arch = "wasm32")))]
+pub(crate) fn plugin_hash(name: &str, bytes: impl FnOnce() -> Option<Vec<u8>>) -> Option<String> {
+    static HASHES: Lazy<DashMap<String, String>> = Lazy::new(Default::default);
+
+    if let Some(hash) = HASHES.get(name) {
+        return Some(hash.clone());
+    }
+
+    let hash = hex(&Sha256::digest(bytes()?));
+    HASHES.insert(name.to_string(), hash.clone());
+    Some(hash)
+}
+
+fn hex(bytes: &[u8]) -> String {
+    let mut s = String::with_capacity(bytes.len() * 2);
+    for b in bytes {
+        let _ = write!(s, "{b:02x}");
+    }
+    s
+}
+
+impl CachedOutput {
+    /// Returns [None] if a diagnostic points outside of `fm`, as it cannot be
+    /// replayed.
+    pub fn new(
+        fm: &SourceFile,
+        output: &TransformOutput,
+        diagnostics: &[Diagnostic],
+    ) -> Option<Self> {
+        let diagnostics = diagnostics
+            .iter()
+            .map(|diagnostic| {
+                rebase_diagnostic(diagnostic, fm.start_pos, fm.end_pos, CACHED_START_POS)
+            })
+            .collect::<Option<_>>()?;
+
+        Some(CachedOutput {
+            code: output.code.clone(),
+            map: output.map.clone(),
+            output: output.output.clone(),
+            artifacts: output
+                .artifacts
+                .iter()
+                .map(|artifact| (artifact.path.clone(), artifact.content.clone()))
+                .collect(),
+            diagnostics,
+        })
+    }
+
+    /// Emits the cached diagnostics to `handler` and returns the output.
+    pub fn replay(self, fm: &SourceFile, handler: &Handler) -> TransformOutput {
+        let len = fm.end_pos - fm.start_pos;
+
+        for diagnostic in &self.diagnostics {
+            // Entries are keyed on the source, so the spans always fit.
+            if let Some(diagnostic) = rebase_diagnostic(
+                diagnostic,
+                CACHED_START_POS,
+                CACHED_START_POS + len,
+                fm.start_pos,
+            ) {
+                DiagnosticBuilder::new_diagnostic(handler, diagnostic).emit();
+            }
+        }
+
+        TransformOutput {
+            code: self.code,
+            map: self.map,
+            output: self.output,
+            artifacts: self
+                .artifacts
+                .into_iter()
+                .map(|(path, content)| TransformArtifact { path, content })
+                .collect(),
+            diagnostics: Default::default(),
+        }
+    }
+}
+
+/// Moves the spans of `diagnostic` in `start..=end` to start at `to`.
+///
+/// Returns [None] if a span is not in the range.
+fn rebase_diagnostic(
+    diagnostic: &Diagnostic,
+    start: BytePos,
+    end: BytePos,
+    to: BytePos,
+) -> Option<Diagnostic> {
+    let rebase_span = |span: Span| -> Option<Span> {
+        if span.is_dummy() {
+            return Some(span);
+        }
+        if span.lo < start || span.hi > end {
+            return None;
+        }
+
+        Some(Span::new(to + (span.lo - start), to + (span.hi - start)))
+    };
+    let rebase_multi_span = |span: &MultiSpan| -> Option<MultiSpan> {
+        let mut rebased = MultiSpan::from_spans(
+            span.primary_spans()
+                .iter()
+                .map(|&span| rebase_span(span))
+                .collect::<Option<_>>()?,
+        );
+        for label in span.span_labels() {
+            if let Some(text) = label.label {
+                rebased.push_span_label(rebase_span(label.span)?, text);
+            }
+        }
+
+        Some(rebased)
+    };
+
+    let mut diagnostic = diagnostic.clone();
+
+    diagnostic.span = rebase_multi_span(&diagnostic.span)?;
+    for child in &mut diagnostic.children {
+        child.span = rebase_multi_span(&child.span)?;
+        if let Some(render_span) = &child.render_span {
+            child.render_span = Some(rebase_multi_span(render_span)?);
+        }
+    }
+    for suggestion in &mut diagnostic.suggestions {
+        for substitution in &mut suggestion.substitutions {
+            for part in &mut substitution.parts {
+                part.span = rebase_span(part.span)?;
+            }
+        }
+    }
+
+    Some(diagnostic)
+}
+
+/// Runs `op` with a handler collecting diagnostics, and emits them to
+/// `handler` afterwards.
+///
+/// The diagnostics are returned if `op` did not emit errors.
+pub(crate) fn capture_diagnostics<Ret>(
+    handler: &Handler,
+    op: impl FnOnce(&Handler) -> Ret,
+) -> (Ret, Option<Vec<Diagnostic>>) {
+    let capturing = Capturing::default();
+    let capturing_handler = Handler::with_emitter(true, false, Box::new(capturing.clone()));
+
+    let ret = HANDLER.set(&capturing_handler, || op(&capturing_handler));
+
+    let diagnostics = take(&mut *capturing.diagnostics.lock());
+    for diagnostic in &diagnostics {
+        DiagnosticBuilder::new_diagnostic(handler, diagnostic.clone()).emit();
+    }
+
+    if capturing_handler.has_errors() {
+        (ret, None)
+    } else {
+        (ret, Some(diagnostics))
+    }
+}
+
+#[derive(Default, Clone)]
+struct Capturing {
+    diagnostics: Arc<Mutex<Vec<Diagnostic>>>,
+}
+
+impl Emitter for Capturing {
+    fn emit(&mut self, db: &mut DiagnosticBuilder<'_>) {
+        self.diagnostics.lock().push(db.take());
+    }
+}
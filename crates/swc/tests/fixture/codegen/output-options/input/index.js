@@ -0,0 +1,5 @@
+export function foo() {
+    if (a) {
+        return someFunctionWithALongName("first", "second");
+    }
+}
@@ -0,0 +1,8 @@
+export function foo() {
+  if (a) {
+    return someFunctionWithALongName(
+      'first',
+      'second',
+    );
+  }
+}
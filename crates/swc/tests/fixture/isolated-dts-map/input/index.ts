@@ -0,0 +1,20 @@
+export interface Point {
+    x: number;
+    y: number;
+}
+
+export function distance(a: Point, b: Point): number {
+    return Math.hypot(a.x - b.x, a.y - b.y);
+}
+
+export class Vector {
+    constructor(public readonly x: number, public readonly y: number) {}
+
+    length(): number {
+        return Math.hypot(this.x, this.y);
+    }
+}
+
+export const origin: Point = { x: 0, y: 0 };
+
+export default 1 as number;
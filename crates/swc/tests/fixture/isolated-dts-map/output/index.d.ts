@@ -0,0 +1,14 @@
+export interface Point {
+    x: number;
+    y: number;
+}
+export declare function distance(a: Point, b: Point): number;
+export declare class Vector {
+    readonly x: number;
+    readonly y: number;
+    constructor(x: number, y: number);
+    length(): number;
+}
+export declare const origin: Point;
+declare const _default_1: number;
+export default _default_1;
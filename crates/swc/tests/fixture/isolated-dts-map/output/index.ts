@@ -0,0 +1,19 @@
+export function distance(a, b) {
+    return Math.hypot(a.x - b.x, a.y - b.y);
+}
+export class Vector {
+    x;
+    y;
+    constructor(x, y){
+        this.x = x;
+        this.y = y;
+    }
+    length() {
+        return Math.hypot(this.x, this.y);
+    }
+}
+export const origin = {
+    x: 0,
+    y: 0
+};
+export default 1;
@@ -0,0 +1 @@
+export var env = "node";
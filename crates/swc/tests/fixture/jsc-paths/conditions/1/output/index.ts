@@ -0,0 +1,2 @@
+import { env } from "./env/deno";
+console.log(env);
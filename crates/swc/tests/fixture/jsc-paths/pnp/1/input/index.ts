@@ -0,0 +1,5 @@
+import { own } from "@shims/own";
+import get from "@shims/get";
+import _ from "lodash";
+
+console.log(own, get, _);
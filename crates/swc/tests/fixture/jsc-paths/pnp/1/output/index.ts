@@ -0,0 +1,4 @@
+import { own } from "./shims/own";
+import get from "lodash/get";
+import _ from "lodash";
+console.log(own, get, _);
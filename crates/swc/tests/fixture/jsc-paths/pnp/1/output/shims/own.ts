@@ -0,0 +1 @@
+export var own = 1;
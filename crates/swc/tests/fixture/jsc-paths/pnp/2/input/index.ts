@@ -0,0 +1,3 @@
+import { own } from "@shims/own";
+
+console.log(own);
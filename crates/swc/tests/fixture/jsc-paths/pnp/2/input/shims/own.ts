@@ -0,0 +1 @@
+export const own = 1;
@@ -0,0 +1,2 @@
+import { own } from "./shims/own";
+console.log(own);
@@ -0,0 +1,12 @@
+import { a } from "./a.ts";
+import b from "./b.tsx";
+import type { C } from "./c.ts";
+export * from "./d.mts";
+export { e } from "../e.cts";
+import f from "./f.d.ts";
+
+export async function load(): Promise<C> {
+    return import("./g.ts");
+}
+
+console.log(a, b, f);
@@ -0,0 +1,9 @@
+import { a } from "./a.mjs";
+import b from "./b.mjs";
+export * from "./d.mjs";
+export { e } from "../e.cjs";
+import f from "./f.d.ts";
+export async function load() {
+    return import("./g.mjs");
+}
+console.log(a, b, f);
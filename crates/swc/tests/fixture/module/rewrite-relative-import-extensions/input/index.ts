@@ -0,0 +1,12 @@
+import { a } from "./a.ts";
+import type { B } from "./b.ts";
+export * from "./c.mts";
+export { d } from "../d.cts";
+export type { E } from "./e.ts";
+
+export async function load(name: string): Promise<B> {
+    await import("./f.tsx");
+    return import(name);
+}
+
+console.log(a);
@@ -0,0 +1,9 @@
+import { _ as _ts_rewrite_relative_import_extension } from "@swc/helpers/_/_ts_rewrite_relative_import_extension";
+import { a } from "./a.js";
+export * from "./c.mjs";
+export { d } from "../d.cjs";
+export async function load(name) {
+    await import("./f.js");
+    return import(_ts_rewrite_relative_import_extension(name));
+}
+console.log(a);
@@ -867,6 +867,19 @@ fn tests(input_dir: PathBuf, is_module: Option<IsModule>) {
                                 .unwrap();
                         }
 
+                        if let Some(v) = value.remove("__swc_isolated_declarations_map__") {
+                            let map = v
+                                .as_str()
+                                .expect("isolated declaration pass should emit string");
+                            let map: serde_json::Value = serde_json::from_str(map).unwrap();
+
+                            NormalizedOutput::from(serde_json::to_string_pretty(&map).unwrap())
+                                .compare_to_file(
+                                    output_dir.join(rel_path).with_extension("d.ts.map"),
+                                )
+                                .unwrap();
+                        }
+
                         if !value.is_empty() {
                             let extra = serde_json::to_string_pretty(&value).unwrap();
 
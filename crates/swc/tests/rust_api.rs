@@ -1,11 +1,23 @@
+use std::{
+    env, fs,
+    path::{Path, PathBuf},
+    sync::{Arc, Mutex},
+};
+
+use serde_json::json;
 use swc::{
     config::{Config, InputSourceMap, JscConfig, ModuleConfig, Options, SourceMapsConfig},
     Compiler,
 };
-use swc_common::{comments::SingleThreadedComments, FileName};
+use swc_common::{
+    comments::SingleThreadedComments,
+    errors::{Diagnostic, DiagnosticBuilder, Emitter, Handler},
+    FileName, SourceMap, SourceMapper, GLOBALS,
+};
 use swc_ecma_ast::*;
 use swc_ecma_parser::{EsSyntax, Syntax, TsSyntax};
 use swc_ecma_visit::{noop_visit_mut_type, visit_mut_pass, VisitMut};
+use swc_transform_common::output::emit_artifact;
 
 struct PanicOnVisit;
 
@@ -332,3 +344,357 @@ fn shopify_4_reduce_more() {
     })
     .unwrap()
 }
+
+struct ArtifactEmitter;
+
+impl VisitMut for ArtifactEmitter {
+    noop_visit_mut_type!(fail);
+
+    fn visit_mut_str(&mut self, s: &mut Str) {
+        emit_artifact("strings.txt".into(), s.value.as_bytes().to_vec());
+    }
+}
+
+#[test]
+fn artifacts() {
+    testing::run_test2(false, |cm, handler| {
+        let c = Compiler::new(cm.clone());
+
+        let fm = cm.new_source_file(FileName::Anon.into(), "console.log('foo')".into());
+
+        let res = c
+            .process_js_with_custom_pass(
+                fm,
+                None,
+                &handler,
+                &Options::default(),
+                SingleThreadedComments::default(),
+                |_| visit_mut_pass(ArtifactEmitter),
+                |_| noop_pass(),
+            )
+            .unwrap();
+
+        assert_eq!(res.artifacts.len(), 1);
+        assert_eq!(res.artifacts[0].path, "strings.txt");
+        assert_eq!(res.artifacts[0].content, "Zm9v");
+
+        Ok(())
+    })
+    .unwrap()
+}
+
+#[derive(Clone, Default)]
+struct Collect(Arc<Mutex<Vec<Diagnostic>>>);
+
+impl Emitter for Collect {
+    fn emit(&mut self, db: &mut DiagnosticBuilder<'_>) {
+        self.0.lock().unwrap().push(db.take());
+    }
+}
+
+fn cache_dir(name: &str) -> PathBuf {
+    let dir = env::temp_dir().join(format!("swc-{name}-{}", std::process::id()));
+    let _ = fs::remove_dir_all(&dir);
+    dir
+}
+
+fn cache_entries(dir: &Path) -> Vec<PathBuf> {
+    fs::read_dir(dir)
+        .unwrap()
+        .map(|entry| entry.unwrap().path())
+        .collect()
+}
+
+/// Compiles `src` in a new [SourceMap], and returns the code and the snippets
+/// of the warnings.
+fn compile_with_cache(dir: &Path, max_size: u64, src: &str) -> (String, Vec<String>) {
+    let opts: Options = serde_json::from_value(json!({
+        "jsc": {
+            "lints": {
+                "noConsole": ["warning"]
+            },
+            "experimental": {
+                "disableAllLints": false
+            }
+        },
+        "experimental": {
+            "transformCache": {
+                "dir": dir,
+                "maxSize": max_size
+            }
+        }
+    }))
+    .unwrap();
+
+    GLOBALS.set(&Default::default(), || {
+        let cm = Arc::new(SourceMap::default());
+        // Moves the positions of the input, like other files compiled before it.
+        cm.new_source_file(FileName::Anon.into(), "x".repeat(100));
+        let fm = cm.new_source_file(FileName::Custom("input.js".into()).into(), src.into());
+
+        let emitter = Collect::default();
+        let handler = Handler::with_emitter(true, false, Box::new(emitter.clone()));
+
+        let output = Compiler::new(cm.clone())
+            .process_js_file(fm, &handler, &opts)
+            .unwrap();
+
+        let warnings = emitter
+            .0
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|d| cm.span_to_snippet(d.span.primary_span().unwrap()).unwrap())
+            .collect();
+
+        (output.code, warnings)
+    })
+}
+
+#[test]
+fn transform_cache() {
+    let dir = cache_dir("transform-cache");
+    let src = "console.log('foo');";
+
+    let (code, warnings) = compile_with_cache(&dir, u64::MAX, src);
+    assert_eq!(warnings, ["console.log"]);
+
+    let entries = cache_entries(&dir);
+    assert_eq!(entries.len(), 1);
+
+    // Changes the cached code to check that the second compilation uses it.
+    let mut entry: serde_json::Value =
+        serde_json::from_slice(&fs::read(&entries[0]).unwrap()).unwrap();
+    entry["code"] = code.replace("foo", "cached").into();
+    fs::write(&entries[0], serde_json::to_vec(&entry).unwrap()).unwrap();
+
+    let (cached_code, cached_warnings) = compile_with_cache(&dir, u64::MAX, src);
+    assert_eq!(cached_code, code.replace("foo", "cached"));
+    assert_eq!(cached_warnings, warnings);
+
+    let (code, _) = compile_with_cache(&dir, u64::MAX, "console.log('bar');");
+    assert!(code.contains("bar"));
+    assert_eq!(cache_entries(&dir).len(), 2);
+
+    fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn transform_cache_eviction() {
+    let dir = cache_dir("transform-cache-eviction");
+
+    compile_with_cache(&dir, u64::MAX, "console.log('foo');");
+    let entries = cache_entries(&dir);
+    let size = fs::metadata(&entries[0]).unwrap().len();
+
+    // Fits one entry, but not two.
+    compile_with_cache(&dir, size * 3 / 2, "console.log('bar');");
+
+    let remaining = cache_entries(&dir);
+    assert_eq!(remaining.len(), 1);
+    assert_ne!(remaining, entries);
+
+    fs::remove_dir_all(&dir).unwrap();
+}
+
+/// Compiles the file at `path` with the cache in `dir`, and returns the code.
+fn compile_file_with_cache(dir: &Path, path: &Path, jsc: serde_json::Value) -> String {
+    let opts: Options = serde_json::from_value(json!({
+        "swcrc": false,
+        "sourceMaps": true,
+        "jsc": jsc,
+        "experimental": {
+            "transformCache": {
+                "dir": dir
+            }
+        }
+    }))
+    .unwrap();
+
+    GLOBALS.set(&Default::default(), || {
+        let cm = Arc::new(SourceMap::default());
+        let fm = cm.load_file(path).unwrap();
+        let handler = Handler::with_emitter(true, false, Box::new(Collect::default()));
+
+        Compiler::new(cm)
+            .process_js_file(fm, &handler, &opts)
+            .unwrap()
+            .map
+            .unwrap()
+    })
+}
+
+#[test]
+fn transform_cache_input_source_map() {
+    let dir = cache_dir("transform-cache-input-source-map");
+    let input_dir = dir.join("input");
+    let cache = dir.join("cache");
+    fs::create_dir_all(&input_dir).unwrap();
+
+    let input = input_dir.join("input.js");
+    fs::write(
+        &input,
+        "console.log('foo');\n//# sourceMappingURL=input.js.map\n",
+    )
+    .unwrap();
+
+    let write_map = |source: &str| {
+        fs::write(
+            input_dir.join("input.js.map"),
+            json!({
+                "version": 3,
+                "sources": [source],
+                "names": [],
+                "mappings": "AAAA"
+            })
+            .to_string(),
+        )
+        .unwrap();
+    };
+
+    write_map("a.ts");
+    let map = compile_file_with_cache(&cache, &input, json!({}));
+    assert!(map.contains("a.ts"));
+
+    write_map("b.ts");
+    let map = compile_file_with_cache(&cache, &input, json!({}));
+    assert!(map.contains("b.ts"));
+    assert_eq!(cache_entries(&cache).len(), 2);
+
+    fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn transform_cache_bypassed_with_paths() {
+    let dir = cache_dir("transform-cache-paths");
+    let input_dir = dir.join("input");
+    let cache = dir.join("cache");
+    fs::create_dir_all(&input_dir).unwrap();
+
+    let input = input_dir.join("input.js");
+    fs::write(&input, "import foo from '@/foo';\nconsole.log(foo);\n").unwrap();
+
+    compile_file_with_cache(
+        &cache,
+        &input,
+        json!({
+            "baseUrl": input_dir,
+            "paths": {
+                "@/*": ["./*"]
+            }
+        }),
+    );
+    assert!(!cache.exists());
+
+    fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn name_cache_is_shared_by_parallel_minification() {
+    let dir = cache_dir("name-cache");
+    fs::create_dir_all(&dir).unwrap();
+    let path = dir.join("names.json");
+
+    let opts: swc::config::JsMinifyOptions = serde_json::from_value(json!({
+        "mangle": {
+            "props": {}
+        },
+        "module": true,
+        "nameCacheFile": path
+    }))
+    .unwrap();
+
+    std::thread::scope(|s| {
+        for i in 0..8 {
+            let opts = &opts;
+            s.spawn(move || {
+                GLOBALS.set(&Default::default(), || {
+                    let cm = Arc::new(SourceMap::default());
+                    let fm = cm.new_source_file(
+                        FileName::Anon.into(),
+                        format!("export const x = {{ prop{i}: 1 }}; x.prop{i};"),
+                    );
+                    let handler = Handler::with_emitter(true, false, Box::new(Collect::default()));
+
+                    Compiler::new(cm)
+                        .minify(fm, &handler, opts, Default::default())
+                        .unwrap();
+                })
+            });
+        }
+    });
+
+    let names: serde_json::Value = serde_json::from_slice(&fs::read(&path).unwrap()).unwrap();
+    let mut mangled = Vec::new();
+    for i in 0..8 {
+        mangled.push(
+            names["props"]["props"][format!("$prop{i}")]
+                .as_str()
+                .unwrap()
+                .to_string(),
+        );
+    }
+    mangled.sort();
+    mangled.dedup();
+    assert_eq!(mangled.len(), 8, "properties share mangled names: {names}");
+
+    fs::remove_dir_all(&dir).unwrap();
+}
+
+fn minify_with_name_cache(path: &Path, code: &str) -> String {
+    let opts: swc::config::JsMinifyOptions = serde_json::from_value(json!({
+        "mangle": {
+            "props": {}
+        },
+        "module": true,
+        "nameCacheFile": path
+    }))
+    .unwrap();
+
+    GLOBALS.set(&Default::default(), || {
+        let cm = Arc::new(SourceMap::default());
+        let fm = cm.new_source_file(FileName::Anon.into(), code.to_string());
+        let handler = Handler::with_emitter(true, false, Box::new(Collect::default()));
+
+        Compiler::new(cm)
+            .minify(fm, &handler, &opts, Default::default())
+            .unwrap()
+            .code
+    })
+}
+
+#[test]
+fn name_cache_is_reloaded_if_modified() {
+    let dir = cache_dir("name-cache-reload");
+    fs::create_dir_all(dir.join("sub")).unwrap();
+    let path = dir.join("names.json");
+
+    minify_with_name_cache(&path, "export const x = { foo: 1 }; x.foo;");
+
+    // Another spelling of the path uses the same cache and file.
+    minify_with_name_cache(
+        &dir.join("sub").join("..").join("names.json"),
+        "export const y = { bar: 1 }; y.bar;",
+    );
+    let names: serde_json::Value = serde_json::from_slice(&fs::read(&path).unwrap()).unwrap();
+    assert!(names["props"]["props"]["$foo"].is_string(), "{names}");
+    assert!(names["props"]["props"]["$bar"].is_string(), "{names}");
+
+    fs::write(&path, r#"{ "props": { "props": { "$baz": "zz" } } }"#).unwrap();
+    // Make sure that the modification time changes.
+    fs::File::options()
+        .write(true)
+        .open(&path)
+        .unwrap()
+        .set_modified(std::time::SystemTime::now() + std::time::Duration::from_secs(10))
+        .unwrap();
+
+    let code = minify_with_name_cache(&path, "export const z = { baz: 1 }; z.baz;");
+    assert!(code.contains("zz"), "{code}");
+
+    let names: serde_json::Value = serde_json::from_slice(&fs::read(&path).unwrap()).unwrap();
+    assert!(names["props"]["props"]["$foo"].is_null(), "{names}");
+    assert!(names["props"]["props"]["$bar"].is_null(), "{names}");
+
+    fs::remove_dir_all(&dir).unwrap();
+}
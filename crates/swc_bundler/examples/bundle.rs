@@ -86,6 +86,7 @@ fn do_test(_entry: &Path, entries: HashMap<String, FileName>, inline: bool, mini
                 disable_hygiene: minify,
                 disable_dce: false,
                 module: Default::default(),
+                split_chunks: None,
             },
             Box::new(Hook),
         );
@@ -4,15 +4,19 @@ use anyhow::Error;
 use indexmap::IndexSet;
 use petgraph::EdgeDirection;
 use rustc_hash::{FxBuildHasher, FxHashMap, FxHashSet};
+use swc_atoms::Atom;
 use swc_common::{sync::Lock, FileName, SyntaxContext, DUMMY_SP};
 use swc_ecma_ast::*;
 use swc_ecma_transforms_base::helpers::Helpers;
 use swc_ecma_utils::{find_pat_ids, prepend_stmt, private_ident, quote_ident, ExprFactory};
-use swc_ecma_visit::{VisitMut, VisitMutWith};
+use swc_ecma_visit::{noop_visit_type, Visit, VisitMut, VisitMutWith};
 use EdgeDirection::Outgoing;
 
 use crate::{
-    bundler::{keywords::KeywordRenamer, load::TransformedModule},
+    bundler::{
+        keywords::KeywordRenamer,
+        load::{Specifier, TransformedModule},
+    },
     dep_graph::ModuleGraph,
     id::{Id, ModuleId},
     inline::inline,
@@ -29,6 +33,11 @@ pub(super) struct Ctx {
     pub cycles: Vec<Vec<ModuleId>>,
     pub transitive_remap: CloneMap<SyntaxContext, SyntaxContext>,
     pub export_stars_in_wrapped: Lock<FxHashMap<ModuleId, Vec<SyntaxContext>>>,
+    /// Modules extracted into shared chunks, mapped to the root module of the
+    /// chunk.
+    pub chunk_owners: FxHashMap<ModuleId, ModuleId>,
+    /// Paths of the root modules of shared chunks.
+    pub chunk_srcs: FxHashSet<Atom>,
 }
 
 impl Ctx {
@@ -94,8 +103,14 @@ where
 
             let entry_info = self.scope.get_module(entry_id).unwrap();
 
-            let all_deps_of_entry =
-                self.collect_all_deps(&ctx.graph, entry_id, &mut Default::default());
+            let mut chunks = IndexSet::default();
+            let all_deps_of_entry = self.collect_all_deps(
+                ctx,
+                entry_id,
+                entry_id,
+                &mut Default::default(),
+                &mut chunks,
+            );
 
             tracing::debug!("Merging dependencies: {:?}", all_deps_of_entry);
 
@@ -132,31 +147,119 @@ where
             }
 
             self.replace_import_specifiers(&entry_info, entry);
+            for chunk_id in chunks {
+                self.import_chunk(entry_id, chunk_id, entry);
+            }
             self.finalize_merging_of_entry(ctx, entry_id, entry);
             self.remove_wrong_exports(ctx, &entry_info, entry);
         })
     }
 
+    /// Collects dependencies to merge into `entry_id`.
+    ///
+    /// Shared chunks are not merged, and the root modules of them are stored in
+    /// `chunks` instead.
     #[allow(clippy::only_used_in_recursion)]
     fn collect_all_deps(
         &self,
-        graph: &ModuleGraph,
+        ctx: &Ctx,
+        entry_id: ModuleId,
         start: ModuleId,
         dejavu: &mut FxHashSet<ModuleId>,
+        chunks: &mut IndexSet<ModuleId, FxBuildHasher>,
     ) -> IndexSet<ModuleId, FxBuildHasher> {
         let mut set = IndexSet::default();
 
-        for dep in graph.neighbors_directed(start, Outgoing) {
+        for dep in ctx.graph.neighbors_directed(start, Outgoing) {
             if !dejavu.insert(dep) {
                 continue;
             }
+            match ctx.chunk_owners.get(&dep) {
+                Some(&owner) if owner != entry_id => {
+                    chunks.insert(owner);
+                    continue;
+                }
+                _ => {}
+            }
             set.insert(dep);
-            set.extend(self.collect_all_deps(graph, dep, dejavu));
+            set.extend(self.collect_all_deps(ctx, entry_id, dep, dejavu, chunks));
         }
 
         set
     }
 
+    /// Imports bindings used by `entry` from the shared chunk `chunk_id`.
+    ///
+    /// Bindings exported by a module have the export context of the module, so
+    /// they are imported using the same syntax context. The imported names are
+    /// taken from the exports of the chunk.
+    fn import_chunk(&self, entry_id: ModuleId, chunk_id: ModuleId, entry: &mut Modules) {
+        let info = self.scope.get_module(chunk_id).unwrap();
+        let src = match &*info.fm.name {
+            FileName::Real(path) => path.to_string_lossy().to_string(),
+            _ => unreachable!("shared chunks should be real files"),
+        };
+
+        // Exported names, keyed by the symbol of the binding in the export context.
+        let exported = info
+            .exports
+            .items
+            .iter()
+            .filter_map(|s| match s {
+                Specifier::Specific { local, alias } => {
+                    let exported = match alias {
+                        Some(alias)
+                            if *alias.sym() == "default"
+                                && alias.ctxt() == SyntaxContext::empty() =>
+                        {
+                            alias.sym().clone()
+                        }
+                        _ => local.sym().clone(),
+                    };
+
+                    Some((local.sym().clone(), exported))
+                }
+                Specifier::Namespace { .. } => None,
+            })
+            .collect::<FxHashMap<_, _>>();
+
+        let mut v = ChunkBindingCollector {
+            export_ctxt: info.export_ctxt(),
+            found: Default::default(),
+        };
+        entry.visit_with(&mut v);
+
+        let specifiers = v
+            .found
+            .into_iter()
+            .filter_map(|id| {
+                let imported = exported.get(id.sym())?.clone();
+                let local = id.into_ident();
+
+                Some(ImportSpecifier::Named(ImportNamedSpecifier {
+                    span: DUMMY_SP,
+                    imported: Some(ModuleExportName::Ident(Ident::new_no_ctxt(
+                        imported, DUMMY_SP,
+                    ))),
+                    local,
+                    is_type_only: false,
+                }))
+            })
+            .collect();
+
+        entry.prepend(
+            entry_id,
+            ModuleItem::ModuleDecl(ModuleDecl::Import(ImportDecl {
+                span: DUMMY_SP,
+                specifiers,
+                src: Box::new(src.into()),
+                type_only: false,
+                with: None,
+                phase: Default::default(),
+            })),
+        );
+    }
+
     pub(crate) fn apply_hooks(
         &self,
         module_id: ModuleId,
@@ -467,7 +570,9 @@ where
                 }
 
                 ModuleItem::ModuleDecl(ModuleDecl::Import(import)) => {
-                    if self.config.external_modules.contains(&import.src.value) {
+                    if self.config.external_modules.contains(&import.src.value)
+                        || ctx.chunk_srcs.contains(&import.src.value)
+                    {
                         return true;
                     }
 
@@ -1333,6 +1438,22 @@ where
     }
 }
 
+/// Finds bindings exported from a shared chunk.
+struct ChunkBindingCollector {
+    export_ctxt: SyntaxContext,
+    found: IndexSet<Id, FxBuildHasher>,
+}
+
+impl Visit for ChunkBindingCollector {
+    noop_visit_type!();
+
+    fn visit_ident(&mut self, i: &Ident) {
+        if i.ctxt == self.export_ctxt {
+            self.found.insert(Id::from(i));
+        }
+    }
+}
+
 struct ImportMetaHandler<'a, 'b> {
     file: &'a FileName,
     #[allow(clippy::borrowed_box)]
@@ -4,6 +4,7 @@ use anyhow::{Context, Error};
 #[cfg(feature = "rayon")]
 use rayon::iter::ParallelIterator;
 use rustc_hash::FxHashMap;
+use swc_common::FileName;
 
 use super::{load::TransformedModule, Bundler};
 use crate::{
@@ -53,11 +54,23 @@ where
             }
         }
 
+        let chunk_srcs = plan
+            .chunk_owners
+            .iter()
+            .filter(|(id, owner)| id == owner)
+            .filter_map(|(id, _)| match &*self.scope.get_module(*id)?.fm.name {
+                FileName::Real(path) => Some(path.to_string_lossy().into()),
+                _ => None,
+            })
+            .collect();
+
         let ctx = Ctx {
             graph,
             cycles,
             transitive_remap: Default::default(),
             export_stars_in_wrapped: Default::default(),
+            chunk_owners: plan.chunk_owners,
+            chunk_srcs,
         };
 
         #[cfg(not(target_arch = "wasm32"))]
@@ -149,8 +162,6 @@ where
 
 #[cfg(test)]
 mod tests {
-    use swc_common::FileName;
-
     use super::*;
     use crate::bundler::tests::suite;
 
@@ -1,11 +1,14 @@
+use std::path::Path;
+
 use anyhow::{bail, Error};
-use rustc_hash::FxHashMap;
+use petgraph::EdgeDirection::{Incoming, Outgoing};
+use rustc_hash::{FxHashMap, FxHashSet};
 use swc_graph_analyzer::{DepGraph, GraphAnalyzer};
 
 use crate::{
     bundler::{load::TransformedModule, scope::Scope},
     dep_graph::ModuleGraph,
-    BundleKind, Bundler, Load, ModuleId, Resolve,
+    BundleKind, Bundler, Load, ModuleId, Resolve, SplitChunks,
 };
 
 #[cfg(test)]
@@ -22,6 +25,19 @@ pub(super) struct Plan {
 
     /// Id of all modules.
     pub all: Vec<ModuleId>,
+
+    /// Modules extracted into a shared chunk, mapped to the root module of
+    /// the chunk.
+    pub chunk_owners: FxHashMap<ModuleId, ModuleId>,
+}
+
+/// Modules in a group are emitted in the same chunks.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+enum ChunkGroup {
+    /// Index of [crate::ManualChunk].
+    Manual(usize),
+    /// Sorted list of entries which depend on the module.
+    Shared(Vec<ModuleId>),
 }
 
 impl DepGraph for Scope {
@@ -62,13 +78,218 @@ where
 
         // dbg!(&builder.cycles);
 
+        let chunk_owners = match &self.config.split_chunks {
+            Some(config) => {
+                let owners = self.split_chunks(config, &builder.kinds, &res.graph, &res.cycles);
+
+                for (&id, &owner) in &owners {
+                    if id != owner {
+                        continue;
+                    }
+
+                    let name = match self.chunk_group_of(config, id) {
+                        Some(i) => config.manual_chunks[i].name.clone(),
+                        None => self.chunk_name(id),
+                    };
+                    builder.kinds.insert(id, BundleKind::Lib { name });
+                }
+
+                owners
+            }
+            None => Default::default(),
+        };
+
         Ok((
             Plan {
                 entries: builder.kinds,
                 all: res.all,
+                chunk_owners,
             },
             res.graph,
             res.cycles,
         ))
     }
+
+    /// Determines modules to extract into shared chunks.
+    ///
+    /// Returns the root module of the chunk for each extracted module. Only
+    /// roots are imported by other chunks, and other modules in a chunk are
+    /// merged into the root.
+    fn split_chunks(
+        &self,
+        config: &SplitChunks,
+        entries: &FxHashMap<ModuleId, BundleKind>,
+        graph: &ModuleGraph,
+        cycles: &[Vec<ModuleId>],
+    ) -> FxHashMap<ModuleId, ModuleId> {
+        let mut entry_ids = entries.keys().copied().collect::<Vec<_>>();
+        entry_ids.sort();
+
+        // Entries which depend on each module.
+        let mut dependents = FxHashMap::<ModuleId, Vec<ModuleId>>::default();
+        for &entry in &entry_ids {
+            for dep in collect_deps(graph, entry) {
+                dependents.entry(dep).or_default().push(entry);
+            }
+        }
+
+        // Modules which cannot be imported from other chunks.
+        let mut excluded = cycles.iter().flatten().copied().collect::<FxHashSet<_>>();
+        excluded.extend(entry_ids.iter().copied());
+        for &id in dependents.keys() {
+            let info = self.scope.get_module(id).unwrap();
+
+            // Bindings of wrapped or commonjs modules and reexported bindings are not
+            // accessed with their export context.
+            if !info.is_es6 || self.scope.is_cjs(id) || self.scope.should_be_wrapped_with_a_fn(id) {
+                excluded.insert(id);
+            }
+            excluded.extend(info.exports.reexports.iter().map(|(src, _)| src.module_id));
+        }
+
+        let mut groups = FxHashMap::<ModuleId, ChunkGroup>::default();
+
+        for (i, _) in config.manual_chunks.iter().enumerate() {
+            let mut queue = dependents
+                .keys()
+                .copied()
+                .filter(|&id| self.chunk_group_of(config, id) == Some(i))
+                .collect::<Vec<_>>();
+            queue.sort();
+
+            while let Some(id) = queue.pop() {
+                if excluded.contains(&id) || groups.contains_key(&id) {
+                    continue;
+                }
+                groups.insert(id, ChunkGroup::Manual(i));
+
+                queue.extend(
+                    graph
+                        .neighbors_directed(id, Outgoing)
+                        .filter(|&dep| self.chunk_group_of(config, dep).is_none()),
+                );
+            }
+        }
+
+        let mut sizes = FxHashMap::<ChunkGroup, usize>::default();
+        for (&id, entries) in &dependents {
+            if excluded.contains(&id) || groups.contains_key(&id) {
+                continue;
+            }
+            if entries.len() < config.min_shared_count.max(2) {
+                continue;
+            }
+
+            let group = ChunkGroup::Shared(entries.clone());
+            *sizes.entry(group.clone()).or_default() +=
+                self.scope.get_module(id).unwrap().fm.src.len();
+            groups.insert(id, group);
+        }
+        groups.retain(|_, group| match group {
+            ChunkGroup::Manual(..) => true,
+            ChunkGroup::Shared(..) => sizes[group] >= config.min_size,
+        });
+
+        // A module is merged into the chunk of its importers if all of them are in the
+        // same chunk. Otherwise, the module becomes the root of a new chunk.
+        let mut owners = FxHashMap::default();
+        for id in importers_first(graph, &entry_ids) {
+            let group = match groups.get(&id) {
+                Some(v) => v,
+                None => continue,
+            };
+
+            let mut owner = None;
+            for importer in graph.neighbors_directed(id, Incoming) {
+                let importer_owner = match owners.get(&importer) {
+                    Some(&v) if groups.get(&importer) == Some(group) => v,
+                    _ => {
+                        owner = None;
+                        break;
+                    }
+                };
+
+                match owner {
+                    Some(v) if v != importer_owner => {
+                        owner = None;
+                        break;
+                    }
+                    _ => owner = Some(importer_owner),
+                }
+            }
+
+            owners.insert(id, owner.unwrap_or(id));
+        }
+
+        owners
+    }
+
+    /// Returns the index of the manual chunk which contains the module.
+    fn chunk_group_of(&self, config: &SplitChunks, id: ModuleId) -> Option<usize> {
+        let info = self.scope.get_module(id)?;
+        let file_name = info.fm.name.to_string();
+
+        config
+            .manual_chunks
+            .iter()
+            .position(|chunk| chunk.modules.iter().any(|m| file_name.contains(&**m)))
+    }
+
+    /// Name of a shared chunk, which is the file stem of the root module.
+    fn chunk_name(&self, id: ModuleId) -> String {
+        let info = self.scope.get_module(id).unwrap();
+        let file_name = info.fm.name.to_string();
+
+        Path::new(&file_name)
+            .file_stem()
+            .map(|v| v.to_string_lossy().to_string())
+            .unwrap_or_else(|| format!("chunk-{}", id))
+    }
+}
+
+/// Transitive dependencies of `entry`.
+fn collect_deps(graph: &ModuleGraph, entry: ModuleId) -> FxHashSet<ModuleId> {
+    let mut deps = FxHashSet::default();
+    let mut queue = vec![entry];
+
+    while let Some(id) = queue.pop() {
+        for dep in graph.neighbors_directed(id, Outgoing) {
+            if dep != entry && deps.insert(dep) {
+                queue.push(dep);
+            }
+        }
+    }
+
+    deps
+}
+
+/// Returns modules reachable from `entries`, where a module comes after all of
+/// its importers unless they form a cycle.
+fn importers_first(graph: &ModuleGraph, entries: &[ModuleId]) -> Vec<ModuleId> {
+    fn visit(
+        graph: &ModuleGraph,
+        id: ModuleId,
+        visited: &mut FxHashSet<ModuleId>,
+        order: &mut Vec<ModuleId>,
+    ) {
+        if !visited.insert(id) {
+            return;
+        }
+
+        for dep in graph.neighbors_directed(id, Outgoing) {
+            visit(graph, dep, visited, order);
+        }
+
+        order.push(id);
+    }
+
+    let mut visited = FxHashSet::default();
+    let mut order = Vec::new();
+
+    for &entry in entries {
+        visit(graph, entry, &mut visited, &mut order);
+    }
+
+    order.reverse();
+    order
 }
@@ -10,7 +10,12 @@ use super::{
     load::{Source, Specifier},
     Bundler,
 };
-use crate::{id::Id, load::Load, resolve::Resolve, util::ExportMetadata};
+use crate::{
+    id::Id,
+    load::Load,
+    resolve::{ImportKind, Resolve},
+    util::ExportMetadata,
+};
 
 impl<L, R> Bundler<'_, L, R>
 where
@@ -80,7 +85,10 @@ where
         {
             return None;
         }
-        let path = self.bundler.resolve(self.file_name, src).ok()?;
+        let path = self
+            .bundler
+            .resolve(self.file_name, src, ImportKind::Import)
+            .ok()?;
         let (_, local_mark, export_mark) = self.bundler.scope.module_id_gen.gen(&path);
 
         Some((
@@ -100,7 +108,9 @@ where
         {
             return;
         }
-        let path = self.bundler.resolve(self.file_name, src);
+        let path = self
+            .bundler
+            .resolve(self.file_name, src, ImportKind::Import);
         let path = match path {
             Ok(v) => v,
             _ => return,
@@ -1,18 +1,28 @@
-use std::path::{Path, PathBuf};
+use std::{
+    path::{Path, PathBuf},
+    sync::atomic::Ordering,
+};
 
-use anyhow::Error;
+use anyhow::{bail, Error};
 use relative_path::RelativePath;
 use rustc_hash::FxHashMap;
-use swc_common::{util::move_map::MoveMap, FileName, Mark, DUMMY_SP};
+use swc_common::{
+    util::{move_map::MoveMap, take::Take},
+    FileName, Mark, DUMMY_SP,
+};
 use swc_ecma_ast::*;
 use swc_ecma_transforms_base::{
     fixer::fixer,
     helpers::{inject_helpers, Helpers, HELPERS},
     hygiene::hygiene,
 };
-use swc_ecma_utils::{contains_top_level_await, find_pat_ids, private_ident, ExprFactory};
-use swc_ecma_visit::{noop_fold_type, Fold, FoldWith, VisitMutWith};
+use swc_ecma_utils::{
+    contains_top_level_await, find_pat_ids, private_ident, quote_ident, quote_str, ExprFactory,
+    FunctionFactory,
+};
+use swc_ecma_visit::{noop_fold_type, noop_visit_mut_type, Fold, FoldWith, VisitMut, VisitMutWith};
 
+use super::helpers::{wrap_with_umd, Helpers as BundlerHelpers};
 use crate::{hash::calc_hash, Bundle, BundleKind, Bundler, Load, ModuleType, Resolve};
 
 impl<L, R> Bundler<'_, L, R>
@@ -33,6 +43,7 @@ where
         self.run(|| {
             let mut new = Vec::with_capacity(bundles.len());
             let mut renamed = FxHashMap::default();
+            let mut chunks = FxHashMap::default();
 
             for mut bundle in bundles {
                 bundle.module = self.optimize(bundle.module);
@@ -41,7 +52,7 @@ where
                     bundle.module.visit_mut_with(&mut hygiene());
                 }
 
-                bundle.module = self.may_wrap_with_iife(bundle.module);
+                bundle.module = self.may_wrap(bundle.module)?;
 
                 if !self.config.disable_fixer {
                     bundle.module.visit_mut_with(&mut fixer(None));
@@ -76,6 +87,31 @@ where
 
                         new.push(bundle);
                     }
+                    BundleKind::Lib { name } if self.config.split_chunks.is_some() => {
+                        let hash = calc_hash(self.cm.clone(), &bundle.module)?;
+                        let name = Path::new(&name);
+                        let file_name = match name.extension() {
+                            Some(ext) if ["js", "mjs", "cjs"].iter().any(|v| ext == *v) => format!(
+                                "{}-{}.{}",
+                                name.file_stem().unwrap().to_string_lossy(),
+                                hash,
+                                ext.to_string_lossy()
+                            ),
+                            _ => format!("{}-{}.js", name.to_string_lossy(), hash),
+                        };
+
+                        // Other bundles import the chunk using the path of its root module.
+                        if let FileName::Real(path) =
+                            &*self.scope.get_module(bundle.id).unwrap().fm.name
+                        {
+                            chunks.insert(path.clone(), file_name.clone());
+                        }
+
+                        new.push(Bundle {
+                            kind: BundleKind::Named { name: file_name },
+                            ..bundle
+                        })
+                    }
                     BundleKind::Lib { name } => {
                         let hash = calc_hash(self.cm.clone(), &bundle.module)?;
                         let mut new_name = PathBuf::from(name);
@@ -119,6 +155,10 @@ where
             }
 
             new = new.move_map(|bundle| {
+                let output = match &bundle.kind {
+                    BundleKind::Named { name } => name.clone(),
+                    _ => String::new(),
+                };
                 let path = match &*self.scope.get_module(bundle.id).unwrap().fm.name {
                     FileName::Real(ref v) => v.clone(),
                     _ => {
@@ -132,7 +172,9 @@ where
                     let mut v = Renamer {
                         resolver: &self.resolver,
                         base: &path,
+                        output: &output,
                         renamed: &renamed,
+                        chunks: &chunks,
                     };
                     bundle.module.fold_with(&mut v)
                 };
@@ -144,192 +186,40 @@ where
         })
     }
 
-    fn may_wrap_with_iife(&self, module: Module) -> Module {
-        if self.config.module != ModuleType::Iife {
-            return module;
+    fn may_wrap(&self, module: Module) -> Result<Module, Error> {
+        match &self.config.module {
+            ModuleType::Es => Ok(module),
+            ModuleType::Iife => Ok(self.wrap_with_iife(module)),
+            ModuleType::Cjs => {
+                let body = self.convert_to_cjs(module)?;
+
+                Ok(Module {
+                    span: DUMMY_SP,
+                    shebang: None,
+                    body: body.into_iter().map(ModuleItem::Stmt).collect(),
+                })
+            }
+            ModuleType::Umd { name, globals } => {
+                let body = self.convert_to_cjs(module)?;
+
+                Ok(Module {
+                    span: DUMMY_SP,
+                    shebang: None,
+                    body: wrap_with_umd(name, globals, body),
+                })
+            }
         }
+    }
 
+    fn wrap_with_iife(&self, module: Module) -> Module {
         let is_async = contains_top_level_await(&module);
 
-        // Properties of returned object
-        let mut props = Vec::new();
+        // Imports and reexports are dropped.
+        let (stmts, props) = split_exports(module.body, |_, _| None);
 
         let mut body = BlockStmt {
             span: module.span,
-            stmts: module
-                .body
-                .into_iter()
-                .filter_map(|item| {
-                    let decl = match item {
-                        ModuleItem::ModuleDecl(v) => v,
-                        ModuleItem::Stmt(stmt) => return Some(stmt),
-                    };
-
-                    match decl {
-                        ModuleDecl::ExportNamed(NamedExport { src: Some(..), .. })
-                        | ModuleDecl::TsImportEquals(_)
-                        | ModuleDecl::TsExportAssignment(_)
-                        | ModuleDecl::TsNamespaceExport(_)
-                        | ModuleDecl::Import(_) => None,
-
-                        ModuleDecl::ExportDecl(export) => {
-                            match &export.decl {
-                                Decl::Class(ClassDecl { ident, .. })
-                                | Decl::Fn(FnDecl { ident, .. }) => {
-                                    props.push(PropOrSpread::Prop(Box::new(Prop::Shorthand(
-                                        ident.clone(),
-                                    ))));
-                                }
-                                Decl::Var(decl) => {
-                                    let ids: Vec<Ident> = find_pat_ids(decl);
-                                    props.extend(
-                                        ids.into_iter()
-                                            .map(Prop::Shorthand)
-                                            .map(Box::new)
-                                            .map(PropOrSpread::Prop),
-                                    );
-                                }
-                                _ => unreachable!(),
-                            }
-
-                            Some(export.decl.into())
-                        }
-
-                        ModuleDecl::ExportNamed(NamedExport {
-                            specifiers,
-                            src: None,
-                            ..
-                        }) => {
-                            for s in specifiers {
-                                match s {
-                                    ExportSpecifier::Namespace(..) => {
-                                        // unreachable
-                                    }
-                                    ExportSpecifier::Default(s) => {
-                                        props.push(PropOrSpread::Prop(Box::new(Prop::KeyValue(
-                                            KeyValueProp {
-                                                key: PropName::Ident(IdentName::new(
-                                                    "default".into(),
-                                                    DUMMY_SP,
-                                                )),
-                                                value: s.exported.into(),
-                                            },
-                                        ))));
-                                    }
-                                    ExportSpecifier::Named(s) => match s.exported {
-                                        Some(ModuleExportName::Ident(exported)) => {
-                                            let orig = match s.orig {
-                                                ModuleExportName::Ident(ident) => ident,
-                                                ModuleExportName::Str(..) => unimplemented!(
-                                                    "module string names unimplemented"
-                                                ),
-                                            };
-                                            props.push(PropOrSpread::Prop(Box::new(
-                                                Prop::KeyValue(KeyValueProp {
-                                                    key: PropName::Ident(exported.into()),
-                                                    value: orig.into(),
-                                                }),
-                                            )));
-                                        }
-                                        Some(ModuleExportName::Str(..)) => {
-                                            unimplemented!("module string names unimplemented")
-                                        }
-                                        None => {
-                                            let orig = match s.orig {
-                                                ModuleExportName::Ident(ident) => ident,
-                                                ModuleExportName::Str(..) => unimplemented!(
-                                                    "module string names unimplemented"
-                                                ),
-                                            };
-                                            props.push(PropOrSpread::Prop(Box::new(
-                                                Prop::Shorthand(orig),
-                                            )));
-                                        }
-                                    },
-                                }
-                            }
-
-                            None
-                        }
-
-                        ModuleDecl::ExportDefaultDecl(export) => match export.decl {
-                            DefaultDecl::Class(expr) => {
-                                let ident = expr.ident;
-                                let ident =
-                                    ident.unwrap_or_else(|| private_ident!("_default_decl"));
-
-                                props.push(PropOrSpread::Prop(Box::new(Prop::KeyValue(
-                                    KeyValueProp {
-                                        key: PropName::Ident(IdentName::new(
-                                            "default".into(),
-                                            export.span,
-                                        )),
-                                        value: ident.clone().into(),
-                                    },
-                                ))));
-
-                                Some(
-                                    ClassDecl {
-                                        ident,
-                                        class: expr.class,
-                                        declare: false,
-                                    }
-                                    .into(),
-                                )
-                            }
-                            DefaultDecl::Fn(expr) => {
-                                let ident = expr.ident;
-                                let ident =
-                                    ident.unwrap_or_else(|| private_ident!("_default_decl"));
-
-                                props.push(PropOrSpread::Prop(Box::new(Prop::KeyValue(
-                                    KeyValueProp {
-                                        key: PropName::Ident(IdentName::new(
-                                            "default".into(),
-                                            export.span,
-                                        )),
-                                        value: ident.clone().into(),
-                                    },
-                                ))));
-
-                                Some(
-                                    FnDecl {
-                                        ident,
-                                        function: expr.function,
-                                        declare: false,
-                                    }
-                                    .into(),
-                                )
-                            }
-                            DefaultDecl::TsInterfaceDecl(_) => None,
-                        },
-                        ModuleDecl::ExportDefaultExpr(export) => {
-                            let default_var = private_ident!("default");
-                            props.push(PropOrSpread::Prop(Box::new(Prop::Shorthand(
-                                default_var.clone(),
-                            ))));
-                            let var = VarDeclarator {
-                                span: DUMMY_SP,
-                                name: default_var.into(),
-                                init: Some(export.expr),
-                                definite: false,
-                            };
-                            Some(
-                                VarDecl {
-                                    span: DUMMY_SP,
-                                    kind: VarDeclKind::Const,
-                                    declare: false,
-                                    decls: vec![var],
-                                    ..Default::default()
-                                }
-                                .into(),
-                            )
-                        }
-
-                        ModuleDecl::ExportAll(_) => None,
-                    }
-                })
-                .collect(),
+            stmts,
             ..Default::default()
         };
         body.stmts.push(
@@ -376,6 +266,565 @@ where
             }))],
         }
     }
+
+    /// Converts a merged module into statements of a CommonJS module.
+    ///
+    /// Imports, dynamic imports and reexports are converted to `require`
+    /// calls. Exported bindings are defined as getters on `exports` before the
+    /// module body, so they are live bindings like ES module exports.
+    fn convert_to_cjs(&self, mut module: Module) -> Result<Vec<Stmt>, Error> {
+        if contains_top_level_await(&module) {
+            bail!(
+                "top-level await cannot be used with {:?}",
+                self.config.module
+            )
+        }
+
+        let mut imports = CjsImports::default();
+        module.visit_mut_with(&mut imports);
+
+        let (body, props) = split_exports(module.body, |decl, props| imports.convert(decl, props));
+
+        let mut stmts = Vec::with_capacity(body.len() + props.len() + 1);
+
+        if imports.export_star || !props.is_empty() {
+            // Object.defineProperty(exports, "__esModule", { value: true });
+            let define_es_module = quote_ident!("Object")
+                .make_member(quote_ident!("defineProperty"))
+                .as_call(
+                    DUMMY_SP,
+                    vec![
+                        quote_ident!("exports").as_arg(),
+                        quote_str!("__esModule").as_arg(),
+                        ObjectLit {
+                            span: DUMMY_SP,
+                            props: vec![PropOrSpread::Prop(Box::new(Prop::KeyValue(
+                                KeyValueProp {
+                                    key: PropName::Ident(quote_ident!("value")),
+                                    value: true.into(),
+                                },
+                            )))],
+                        }
+                        .as_arg(),
+                    ],
+                );
+
+            stmts.push(define_es_module.into_stmt());
+        }
+
+        for prop in props {
+            let (key, value) = match prop {
+                PropOrSpread::Prop(prop) => match *prop {
+                    Prop::Shorthand(i) => (PropName::Ident(i.clone().into()), Box::new(i.into())),
+                    Prop::KeyValue(KeyValueProp { key, value }) => (key, value),
+                    _ => unreachable!("exported bindings are shorthand or key-value"),
+                },
+                PropOrSpread::Spread(..) => unreachable!("exported bindings are not spread"),
+            };
+
+            let key: Expr = match key {
+                PropName::Ident(key) => quote_str!(key.sym).into(),
+                PropName::Str(key) => key.into(),
+                _ => unreachable!("exported names are identifiers or strings"),
+            };
+
+            // Object.defineProperty(exports, key, {
+            //     enumerable: true,
+            //     get: function () { return value; }
+            // });
+            let define_export = quote_ident!("Object")
+                .make_member(quote_ident!("defineProperty"))
+                .as_call(
+                    DUMMY_SP,
+                    vec![
+                        quote_ident!("exports").as_arg(),
+                        key.as_arg(),
+                        ObjectLit {
+                            span: DUMMY_SP,
+                            props: vec![
+                                PropOrSpread::Prop(Box::new(Prop::KeyValue(KeyValueProp {
+                                    key: PropName::Ident(quote_ident!("enumerable")),
+                                    value: true.into(),
+                                }))),
+                                PropOrSpread::Prop(Box::new(Prop::KeyValue(KeyValueProp {
+                                    key: PropName::Ident(quote_ident!("get")),
+                                    value: value.into_lazy_fn(Vec::new()).into_fn_expr(None).into(),
+                                }))),
+                            ],
+                        }
+                        .as_arg(),
+                    ],
+                );
+
+            stmts.push(define_export.into_stmt());
+        }
+
+        stmts.extend(body);
+
+        if imports.interop || imports.export_star {
+            let helpers = BundlerHelpers::default();
+            helpers.interop.store(imports.interop, Ordering::SeqCst);
+            helpers
+                .export_star
+                .store(imports.export_star, Ordering::SeqCst);
+
+            let mut items = stmts.into_iter().map(ModuleItem::Stmt).collect::<Vec<_>>();
+            helpers.add_to(&mut items);
+
+            stmts = items.into_iter().map(|item| item.expect_stmt()).collect();
+        }
+
+        Ok(stmts)
+    }
+}
+
+/// Converts module items into statements, collecting exported bindings as
+/// properties of an object.
+///
+/// `external` is called for imports and reexports from other modules, and
+/// may convert them into a statement or an exported property.
+fn split_exports(
+    items: Vec<ModuleItem>,
+    mut external: impl FnMut(ModuleDecl, &mut Vec<PropOrSpread>) -> Option<Stmt>,
+) -> (Vec<Stmt>, Vec<PropOrSpread>) {
+    // Properties of returned object
+    let mut props = Vec::new();
+
+    let stmts = items
+        .into_iter()
+        .filter_map(|item| {
+            let decl = match item {
+                ModuleItem::ModuleDecl(v) => v,
+                ModuleItem::Stmt(stmt) => return Some(stmt),
+            };
+
+            match decl {
+                decl @ (ModuleDecl::ExportNamed(NamedExport { src: Some(..), .. })
+                | ModuleDecl::ExportAll(_)
+                | ModuleDecl::Import(_)) => external(decl, &mut props),
+
+                ModuleDecl::TsImportEquals(_)
+                | ModuleDecl::TsExportAssignment(_)
+                | ModuleDecl::TsNamespaceExport(_) => None,
+
+                ModuleDecl::ExportDecl(export) => {
+                    match &export.decl {
+                        Decl::Class(ClassDecl { ident, .. }) | Decl::Fn(FnDecl { ident, .. }) => {
+                            props
+                                .push(PropOrSpread::Prop(Box::new(Prop::Shorthand(ident.clone()))));
+                        }
+                        Decl::Var(decl) => {
+                            let ids: Vec<Ident> = find_pat_ids(decl);
+                            props.extend(
+                                ids.into_iter()
+                                    .map(Prop::Shorthand)
+                                    .map(Box::new)
+                                    .map(PropOrSpread::Prop),
+                            );
+                        }
+                        _ => unreachable!(),
+                    }
+
+                    Some(export.decl.into())
+                }
+
+                ModuleDecl::ExportNamed(NamedExport {
+                    specifiers,
+                    src: None,
+                    ..
+                }) => {
+                    for s in specifiers {
+                        match s {
+                            ExportSpecifier::Namespace(..) => {
+                                // unreachable
+                            }
+                            ExportSpecifier::Default(s) => {
+                                props.push(PropOrSpread::Prop(Box::new(Prop::KeyValue(
+                                    KeyValueProp {
+                                        key: PropName::Ident(IdentName::new(
+                                            "default".into(),
+                                            DUMMY_SP,
+                                        )),
+                                        value: s.exported.into(),
+                                    },
+                                ))));
+                            }
+                            ExportSpecifier::Named(s) => match s.exported {
+                                Some(ModuleExportName::Ident(exported)) => {
+                                    let orig = match s.orig {
+                                        ModuleExportName::Ident(ident) => ident,
+                                        ModuleExportName::Str(..) => {
+                                            unimplemented!("module string names unimplemented")
+                                        }
+                                    };
+                                    props.push(PropOrSpread::Prop(Box::new(Prop::KeyValue(
+                                        KeyValueProp {
+                                            key: PropName::Ident(exported.into()),
+                                            value: orig.into(),
+                                        },
+                                    ))));
+                                }
+                                Some(ModuleExportName::Str(..)) => {
+                                    unimplemented!("module string names unimplemented")
+                                }
+                                None => {
+                                    let orig = match s.orig {
+                                        ModuleExportName::Ident(ident) => ident,
+                                        ModuleExportName::Str(..) => {
+                                            unimplemented!("module string names unimplemented")
+                                        }
+                                    };
+                                    props.push(PropOrSpread::Prop(Box::new(Prop::Shorthand(orig))));
+                                }
+                            },
+                        }
+                    }
+
+                    None
+                }
+
+                ModuleDecl::ExportDefaultDecl(export) => match export.decl {
+                    DefaultDecl::Class(expr) => {
+                        let ident = expr.ident;
+                        let ident = ident.unwrap_or_else(|| private_ident!("_default_decl"));
+
+                        props.push(PropOrSpread::Prop(Box::new(Prop::KeyValue(KeyValueProp {
+                            key: PropName::Ident(IdentName::new("default".into(), export.span)),
+                            value: ident.clone().into(),
+                        }))));
+
+                        Some(
+                            ClassDecl {
+                                ident,
+                                class: expr.class,
+                                declare: false,
+                            }
+                            .into(),
+                        )
+                    }
+                    DefaultDecl::Fn(expr) => {
+                        let ident = expr.ident;
+                        let ident = ident.unwrap_or_else(|| private_ident!("_default_decl"));
+
+                        props.push(PropOrSpread::Prop(Box::new(Prop::KeyValue(KeyValueProp {
+                            key: PropName::Ident(IdentName::new("default".into(), export.span)),
+                            value: ident.clone().into(),
+                        }))));
+
+                        Some(
+                            FnDecl {
+                                ident,
+                                function: expr.function,
+                                declare: false,
+                            }
+                            .into(),
+                        )
+                    }
+                    DefaultDecl::TsInterfaceDecl(_) => None,
+                },
+                ModuleDecl::ExportDefaultExpr(export) => {
+                    let default_var = private_ident!("default");
+                    props.push(PropOrSpread::Prop(Box::new(Prop::Shorthand(
+                        default_var.clone(),
+                    ))));
+                    let var = VarDeclarator {
+                        span: DUMMY_SP,
+                        name: default_var.into(),
+                        init: Some(export.expr),
+                        definite: false,
+                    };
+                    Some(
+                        VarDecl {
+                            span: DUMMY_SP,
+                            kind: VarDeclKind::Const,
+                            declare: false,
+                            decls: vec![var],
+                            ..Default::default()
+                        }
+                        .into(),
+                    )
+                }
+            }
+        })
+        .collect();
+
+    (stmts, props)
+}
+
+/// Converts imports from other modules into `require` calls.
+#[derive(Default)]
+struct CjsImports {
+    /// `__swcpack_interop__` is used.
+    interop: bool,
+    /// `__swcpack_export_star__` is used.
+    export_star: bool,
+}
+
+impl CjsImports {
+    /// `require(src)`
+    fn require(src: &Str) -> Expr {
+        quote_ident!("require").as_call(DUMMY_SP, vec![Expr::from(src.clone()).as_arg()])
+    }
+
+    /// `__swcpack_interop__(expr)`
+    fn interop(&mut self, expr: Expr) -> Expr {
+        self.interop = true;
+
+        quote_ident!("__swcpack_interop__").as_call(DUMMY_SP, vec![expr.as_arg()])
+    }
+
+    /// Returns `__swcpack_interop__(module)` for reexports, declaring it as a
+    /// variable on the first use so that every access returns the same object.
+    fn namespace(
+        &mut self,
+        module: &Ident,
+        namespace: &mut Option<Ident>,
+        decls: &mut Vec<VarDeclarator>,
+    ) -> Expr {
+        if let Some(namespace) = namespace {
+            return namespace.clone().into();
+        }
+
+        let ident = private_ident!("_reexport_ns");
+        decls.push(VarDeclarator {
+            span: DUMMY_SP,
+            name: ident.clone().into(),
+            init: Some(self.interop(module.clone().into()).into()),
+            definite: false,
+        });
+        *namespace = Some(ident.clone());
+
+        ident.into()
+    }
+
+    fn convert(&mut self, decl: ModuleDecl, props: &mut Vec<PropOrSpread>) -> Option<Stmt> {
+        match decl {
+            ModuleDecl::Import(import) => {
+                if import.type_only {
+                    return None;
+                }
+
+                // import 'foo';
+                if import.specifiers.is_empty() {
+                    return Some(Self::require(&import.src).into_stmt());
+                }
+
+                let mut decls = Vec::new();
+                let mut named = Vec::new();
+
+                for specifier in import.specifiers {
+                    match specifier {
+                        ImportSpecifier::Named(s) => {
+                            if s.is_type_only {
+                                continue;
+                            }
+
+                            let key = match s.imported {
+                                Some(imported) => export_name_to_prop_name(imported),
+                                None => PropName::Ident(s.local.clone().into()),
+                            };
+
+                            named.push(ObjectPatProp::KeyValue(KeyValuePatProp {
+                                key,
+                                value: Box::new(s.local.into()),
+                            }));
+                        }
+                        ImportSpecifier::Default(s) => {
+                            let init = self
+                                .interop(Self::require(&import.src))
+                                .make_member(quote_ident!("default"));
+
+                            decls.push(VarDeclarator {
+                                span: s.span,
+                                name: s.local.into(),
+                                init: Some(init.into()),
+                                definite: false,
+                            });
+                        }
+                        ImportSpecifier::Namespace(s) => {
+                            let init = self.interop(Self::require(&import.src));
+
+                            decls.push(VarDeclarator {
+                                span: s.span,
+                                name: s.local.into(),
+                                init: Some(init.into()),
+                                definite: false,
+                            });
+                        }
+                    }
+                }
+
+                if !named.is_empty() {
+                    decls.push(VarDeclarator {
+                        span: DUMMY_SP,
+                        name: ObjectPat {
+                            span: DUMMY_SP,
+                            props: named,
+                            optional: false,
+                            type_ann: None,
+                        }
+                        .into(),
+                        init: Some(Self::require(&import.src).into()),
+                        definite: false,
+                    });
+                }
+
+                if decls.is_empty() {
+                    return None;
+                }
+
+                Some(
+                    VarDecl {
+                        span: import.span,
+                        kind: VarDeclKind::Var,
+                        declare: false,
+                        decls,
+                        ..Default::default()
+                    }
+                    .into(),
+                )
+            }
+
+            ModuleDecl::ExportNamed(NamedExport {
+                src: Some(src),
+                specifiers,
+                type_only: false,
+                ..
+            }) => {
+                // The module is required where it's reexported, and the getters of the
+                // reexported bindings read from it.
+                //
+                // var _reexport = require('foo');
+                let module = private_ident!("_reexport");
+                let mut namespace = None;
+                let mut decls = vec![VarDeclarator {
+                    span: DUMMY_SP,
+                    name: module.clone().into(),
+                    init: Some(Self::require(&src).into()),
+                    definite: false,
+                }];
+
+                for specifier in specifiers {
+                    let (key, value) = match specifier {
+                        // export * as foo from 'foo';
+                        ExportSpecifier::Namespace(s) => {
+                            (s.name, self.namespace(&module, &mut namespace, &mut decls))
+                        }
+                        // export foo from 'foo';
+                        ExportSpecifier::Default(s) => (
+                            ModuleExportName::Ident(s.exported),
+                            self.namespace(&module, &mut namespace, &mut decls)
+                                .make_member(quote_ident!("default"))
+                                .into(),
+                        ),
+                        // export { foo as bar } from 'foo';
+                        ExportSpecifier::Named(s) => {
+                            if s.is_type_only {
+                                continue;
+                            }
+
+                            let value = match &s.orig {
+                                ModuleExportName::Ident(orig) => {
+                                    module.clone().make_member(orig.clone().into()).into()
+                                }
+                                ModuleExportName::Str(orig) => module
+                                    .clone()
+                                    .computed_member(Expr::from(orig.clone()))
+                                    .into(),
+                            };
+
+                            (s.exported.unwrap_or(s.orig), value)
+                        }
+                    };
+
+                    props.push(PropOrSpread::Prop(Box::new(Prop::KeyValue(KeyValueProp {
+                        key: export_name_to_prop_name(key),
+                        value: value.into(),
+                    }))));
+                }
+
+                Some(
+                    VarDecl {
+                        span: DUMMY_SP,
+                        kind: VarDeclKind::Var,
+                        declare: false,
+                        decls,
+                        ..Default::default()
+                    }
+                    .into(),
+                )
+            }
+
+            // export * from 'foo';
+            ModuleDecl::ExportAll(export) => {
+                if export.type_only {
+                    return None;
+                }
+
+                self.export_star = true;
+
+                Some(
+                    quote_ident!("__swcpack_export_star__")
+                        .as_call(
+                            DUMMY_SP,
+                            vec![
+                                Self::require(&export.src).as_arg(),
+                                quote_ident!("exports").as_arg(),
+                            ],
+                        )
+                        .into_stmt(),
+                )
+            }
+
+            _ => None,
+        }
+    }
+}
+
+impl VisitMut for CjsImports {
+    noop_visit_mut_type!(fail);
+
+    fn visit_mut_expr(&mut self, e: &mut Expr) {
+        e.visit_mut_children_with(self);
+
+        // import('foo')
+        //
+        // =>
+        //
+        // Promise.resolve().then(function () {
+        //     return __swcpack_interop__(require('foo'));
+        // })
+        if let Expr::Call(CallExpr {
+            span,
+            callee: Callee::Import(..),
+            args,
+            ..
+        }) = e
+        {
+            if args.len() != 1 || args[0].spread.is_some() {
+                return;
+            }
+
+            let require = quote_ident!("require").as_call(DUMMY_SP, args.take());
+            let load = self
+                .interop(require)
+                .into_lazy_fn(Vec::new())
+                .into_fn_expr(None);
+
+            *e = quote_ident!("Promise")
+                .make_member(quote_ident!("resolve"))
+                .as_call(DUMMY_SP, Vec::new())
+                .make_member(quote_ident!("then"))
+                .as_call(*span, vec![load.as_arg()]);
+        }
+    }
+}
+
+fn export_name_to_prop_name(name: ModuleExportName) -> PropName {
+    match name {
+        ModuleExportName::Ident(i) => PropName::Ident(i.into()),
+        ModuleExportName::Str(s) => PropName::Str(s),
+    }
 }
 
 /// Import renamer. This pass changes import path.
@@ -385,7 +834,11 @@ where
 {
     resolver: R,
     base: &'a PathBuf,
+    /// Output path of the bundle, relative to the directory of the bundles.
+    output: &'a str,
     renamed: &'a FxHashMap<PathBuf, String>,
+    /// File names of shared chunks, keyed by the path of their root modules.
+    chunks: &'a FxHashMap<PathBuf, String>,
 }
 
 impl<R> Fold for Renamer<'_, R>
@@ -395,45 +848,96 @@ where
     noop_fold_type!();
 
     fn fold_import_decl(&mut self, import: ImportDecl) -> ImportDecl {
+        match self.rename(&import.src) {
+            Some(src) => ImportDecl {
+                src: Box::new(src),
+                ..import
+            },
+            None => import,
+        }
+    }
+
+    fn fold_call_expr(&mut self, call: CallExpr) -> CallExpr {
+        let mut call = call.fold_children_with(self);
+
+        // require('./common'), emitted for commonjs bundles
+        if let Callee::Expr(callee) = &call.callee {
+            if let Expr::Ident(i) = &**callee {
+                if i.sym == *"require" && call.args.len() == 1 {
+                    if let Expr::Lit(Lit::Str(src)) = &mut *call.args[0].expr {
+                        if let Some(renamed) = self.rename(src) {
+                            *src = renamed;
+                        }
+                    }
+                }
+            }
+        }
+
+        call
+    }
+}
+
+impl<R> Renamer<'_, R>
+where
+    R: Resolve,
+{
+    fn rename(&self, src: &Str) -> Option<Str> {
+        // Shared chunks are imported using the path of the root module, and they are
+        // emitted to the root of the directory of the bundles, while entries may be
+        // emitted to subdirectories.
+        if let Some(v) = self.chunks.get(Path::new(&*src.value)) {
+            let dir = RelativePath::new(self.output)
+                .parent()
+                .unwrap_or_else(|| RelativePath::new(""));
+            let v = dir.relative(v);
+            let value = v.as_str();
+
+            return Some(Str {
+                value: if value.starts_with('.') {
+                    value.into()
+                } else {
+                    format!("./{}", value).into()
+                },
+                ..src.clone()
+            });
+        }
+
         let resolved = match self
             .resolver
-            .resolve(&FileName::Real(self.base.clone()), &import.src.value)
+            .resolve(&FileName::Real(self.base.clone()), &src.value)
         {
             Ok(v) => match v.filename {
                 FileName::Real(v) => v,
-                _ => panic!("rename_bundles called with non-path module"),
+                // Built-in or external modules are never renamed.
+                _ => return None,
             },
-            Err(_) => return import,
+            Err(_) => return None,
         };
 
-        if let Some(v) = self.renamed.get(&resolved) {
-            // We use parent because RelativePath uses ../common-[hash].js
-            // if we use `entry-a.js` as a base.
-            //
-            // entry-a.js
-            // common.js
-            let base = self
-                .base
-                .parent()
-                .unwrap_or(self.base)
-                .as_os_str()
-                .to_string_lossy();
-            let base = RelativePath::new(&*base);
-            let v = base.relative(v);
-            let value = v.as_str();
-            return ImportDecl {
-                src: Box::new(Str {
-                    value: if value.starts_with('.') {
-                        value.into()
-                    } else {
-                        format!("./{}", value).into()
-                    },
-                    ..*import.src
-                }),
-                ..import
-            };
-        }
-
-        import
+        let v = self.renamed.get(&resolved)?;
+
+        // We use parent because RelativePath uses ../common-[hash].js
+        // if we use `entry-a.js` as a base.
+        //
+        // entry-a.js
+        // common.js
+        let base = self
+            .base
+            .parent()
+            .unwrap_or(self.base)
+            .as_os_str()
+            .to_string_lossy();
+        let base = RelativePath::new(&*base);
+        let v = base.relative(v);
+        let value = v.as_str();
+
+        Some(Str {
+            value: if value.starts_with('.') {
+                value.into()
+            } else {
+                format!("./{}", value).into()
+            },
+            ..src.clone()
+        })
     }
 }
@@ -0,0 +1,15 @@
+function __swcpack_export_star__(from, to) {
+    Object.keys(from).forEach(function (key) {
+        if (key === "default" || key === "__esModule" || Object.prototype.hasOwnProperty.call(to, key)) {
+            return;
+        }
+
+        Object.defineProperty(to, key, {
+            enumerable: true,
+            get: function () {
+                return from[key];
+            },
+        });
+    });
+    return from;
+}
@@ -0,0 +1,19 @@
+function __swcpack_interop__(obj) {
+    // Ported from _interopRequireWildcard
+    if (obj && obj.__esModule) {
+        return obj;
+    }
+
+    var newObj = {};
+
+    if (obj != null) {
+        for (var key in obj) {
+            if (key !== "default" && Object.prototype.hasOwnProperty.call(obj, key)) {
+                newObj[key] = obj[key];
+            }
+        }
+    }
+
+    newObj.default = obj;
+    return newObj;
+}
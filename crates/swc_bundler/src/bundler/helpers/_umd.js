@@ -0,0 +1,22 @@
+(function (global, factory) {
+    if (typeof module === "object" && typeof module.exports === "object") {
+        factory(module, exports, require);
+    } else if (typeof define === "function" && define.amd) {
+        define(["module", "exports", "require"], factory);
+    } else {
+        var mod = {
+            exports: {},
+        };
+        factory(mod, mod.exports, function (id) {
+            return global[__swcpack_umd_globals__[id] || id];
+        });
+        global[__swcpack_umd_name__] = mod.exports;
+    }
+})(
+    typeof globalThis !== "undefined"
+        ? globalThis
+        : typeof self !== "undefined"
+        ? self
+        : this,
+    function (module, exports, require) {}
+);
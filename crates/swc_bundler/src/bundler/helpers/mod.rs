@@ -1,15 +1,28 @@
-use std::sync::atomic::{AtomicBool, Ordering::SeqCst};
+use std::{
+    collections::BTreeMap,
+    sync::atomic::{AtomicBool, Ordering::SeqCst},
+};
 
 use once_cell::sync::Lazy;
-use swc_common::{FileName, FilePathMapping, SourceMap};
+use swc_atoms::Atom;
+use swc_common::{FileName, FilePathMapping, SourceMap, DUMMY_SP};
 use swc_ecma_ast::*;
 use swc_ecma_parser::parse_file_as_module;
-use swc_ecma_utils::{drop_span, prepend_stmts};
+use swc_ecma_utils::{drop_span, prepend_stmts, ExprFactory};
+use swc_ecma_visit::{
+    noop_visit_mut_type, noop_visit_type, Visit, VisitMut, VisitMutWith, VisitWith,
+};
 
 #[derive(Debug, Default)]
 pub(crate) struct Helpers {
     /// `__swcpack_require__`
     pub require: AtomicBool,
+
+    /// `__swcpack_interop__`
+    pub interop: AtomicBool,
+
+    /// `__swcpack_export_star__`
+    pub export_star: AtomicBool,
 }
 
 fn parse(code: &'static str, name: &'static str) -> Vec<ModuleItem> {
@@ -49,6 +62,15 @@ macro_rules! define {
 
 define!(require {
     build: build_swcpack_require
+}
+interop {
+    build: build_swcpack_interop
+}
+export_star {
+    build: build_swcpack_export_star
+}
+umd {
+    build: build_umd_wrapper
 });
 
 impl Helpers {
@@ -56,6 +78,12 @@ impl Helpers {
         if rhs.require.load(SeqCst) {
             self.require.store(true, SeqCst);
         }
+        if rhs.interop.load(SeqCst) {
+            self.interop.store(true, SeqCst);
+        }
+        if rhs.export_star.load(SeqCst) {
+            self.export_star.store(true, SeqCst);
+        }
     }
 
     pub fn add_to(&self, to: &mut Vec<ModuleItem>) {
@@ -64,7 +92,143 @@ impl Helpers {
         if self.require.load(SeqCst) {
             build_swcpack_require(&mut buf);
         }
+        if self.interop.load(SeqCst) {
+            build_swcpack_interop(&mut buf);
+        }
+        if self.export_star.load(SeqCst) {
+            build_swcpack_export_star(&mut buf);
+        }
 
         prepend_stmts(to, buf.into_iter());
     }
 }
+
+/// Wraps `body` with an UMD header.
+///
+/// `body` is invoked with `module`, `exports` and `require`. Modules required
+/// by `body` are listed as dependencies for AMD, so they are loaded before
+/// `body` is invoked. If neither CommonJS nor AMD is available, externals are
+/// read from the global variables in `globals` and `module.exports` is stored
+/// in the global variable named `name`.
+pub(crate) fn wrap_with_umd(
+    name: &str,
+    globals: &BTreeMap<Atom, Atom>,
+    body: Vec<Stmt>,
+) -> Vec<ModuleItem> {
+    let mut deps = RequireCollector::default();
+    body.visit_with(&mut deps);
+
+    let mut buf = Vec::new();
+    build_umd_wrapper(&mut buf);
+
+    buf.visit_mut_with(&mut UmdFiller {
+        name,
+        globals,
+        deps: deps.srcs,
+        body: Some(body),
+    });
+
+    buf
+}
+
+/// Collects sources of `require` calls.
+#[derive(Default)]
+struct RequireCollector {
+    srcs: Vec<Str>,
+}
+
+impl Visit for RequireCollector {
+    noop_visit_type!(fail);
+
+    fn visit_call_expr(&mut self, call: &CallExpr) {
+        call.visit_children_with(self);
+
+        if let Callee::Expr(callee) = &call.callee {
+            if let Expr::Ident(i) = &**callee {
+                if i.sym == "require" && call.args.len() == 1 && call.args[0].spread.is_none() {
+                    if let Expr::Lit(Lit::Str(src)) = &*call.args[0].expr {
+                        if !self.srcs.iter().any(|v| v.value == src.value) {
+                            self.srcs.push(Str {
+                                span: DUMMY_SP,
+                                value: src.value.clone(),
+                                raw: None,
+                            });
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+struct UmdFiller<'a> {
+    name: &'a str,
+    globals: &'a BTreeMap<Atom, Atom>,
+    deps: Vec<Str>,
+    body: Option<Vec<Stmt>>,
+}
+
+impl VisitMut for UmdFiller<'_> {
+    noop_visit_mut_type!(fail);
+
+    fn visit_mut_array_lit(&mut self, a: &mut ArrayLit) {
+        a.visit_mut_children_with(self);
+
+        // The dependencies of AMD modules.
+        let is_amd_deps = a
+            .elems
+            .iter()
+            .flatten()
+            .any(|e| matches!(&*e.expr, Expr::Lit(Lit::Str(s)) if s.value == "require"));
+        if is_amd_deps {
+            a.elems.extend(
+                self.deps
+                    .drain(..)
+                    .map(|src| Some(Expr::from(src).as_arg())),
+            );
+        }
+    }
+
+    fn visit_mut_expr(&mut self, e: &mut Expr) {
+        e.visit_mut_children_with(self);
+
+        if let Expr::Ident(i) = e {
+            if i.sym == "__swcpack_umd_name__" {
+                *e = Lit::Str(Str {
+                    span: i.span,
+                    value: self.name.into(),
+                    raw: None,
+                })
+                .into();
+            } else if i.sym == "__swcpack_umd_globals__" {
+                *e = ObjectLit {
+                    span: i.span,
+                    props: self
+                        .globals
+                        .iter()
+                        .map(|(module, global)| {
+                            PropOrSpread::Prop(Box::new(Prop::KeyValue(KeyValueProp {
+                                key: PropName::Str(module.clone().into()),
+                                value: Box::new(Expr::from(Str::from(global.clone()))),
+                            })))
+                        })
+                        .collect(),
+                }
+                .into();
+            }
+        }
+    }
+
+    fn visit_mut_function(&mut self, f: &mut Function) {
+        f.visit_mut_children_with(self);
+
+        // The factory is the only function with an empty body.
+        if let Some(body) = &mut f.body {
+            if body.stmts.is_empty() {
+                if let Some(stmts) = self.body.take() {
+                    body.stmts = stmts;
+                }
+            }
+        }
+    }
+}
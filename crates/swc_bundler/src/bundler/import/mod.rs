@@ -7,7 +7,11 @@ use swc_ecma_utils::find_pat_ids;
 use swc_ecma_visit::{noop_visit_mut_type, VisitMut, VisitMutWith};
 
 use super::Bundler;
-use crate::{load::Load, resolve::Resolve, util::ExportMetadata};
+use crate::{
+    load::Load,
+    resolve::{ImportKind, Resolve},
+    util::ExportMetadata,
+};
 
 #[cfg(test)]
 mod tests;
@@ -49,11 +53,12 @@ where
         &self,
         base: &FileName,
         module_specifier: &str,
+        kind: ImportKind,
     ) -> Result<Lrc<FileName>, Error> {
         self.run(|| {
             let path = self
                 .resolver
-                .resolve(base, module_specifier)
+                .resolve_with_kind(base, module_specifier, kind)
                 .map(|v| v.filename)
                 .with_context(|| format!("failed to resolve {} from {}", module_specifier, base))?;
 
@@ -93,6 +98,9 @@ pub(super) struct RawImports {
     /// foo[bar()]
     /// ```
     pub forced_ns: FxHashSet<Atom>,
+
+    /// Sources imported using `require`.
+    pub required: FxHashSet<Atom>,
 }
 
 /// This type implements two operation (analysis, deglobbing) to reduce binary
@@ -138,6 +146,14 @@ impl RawImports {
 
         self.imports.push(import.clone());
     }
+
+    pub fn kind_of(&self, src: &Atom) -> ImportKind {
+        if self.required.contains(src) {
+            ImportKind::Require
+        } else {
+            ImportKind::Import
+        }
+    }
 }
 
 impl<L, R> ImportHandler<'_, '_, L, R>
@@ -151,7 +167,10 @@ where
         if self.bundler.is_external(src) {
             return None;
         }
-        let path = self.bundler.resolve(self.path, src).ok()?;
+        let path = self
+            .bundler
+            .resolve(self.path, src, self.info.kind_of(src))
+            .ok()?;
         let (_, local_mark, export_mark) = self.bundler.scope.module_id_gen.gen(&path);
 
         Some((
@@ -165,7 +184,7 @@ where
         if self.bundler.is_external(src) {
             return;
         }
-        let path = self.bundler.resolve(self.path, src);
+        let path = self.bundler.resolve(self.path, src, self.info.kind_of(src));
         let path = match path {
             Ok(v) => v,
             Err(_) => return,
@@ -176,7 +195,7 @@ where
     }
 
     fn mark_as_cjs(&self, src: &Atom) {
-        let path = self.bundler.resolve(self.path, src);
+        let path = self.bundler.resolve(self.path, src, ImportKind::Require);
         let path = match path {
             Ok(v) => v,
             Err(_) => return,
@@ -225,6 +244,7 @@ where
                         if self.bundler.is_external(&src.value) {
                             return;
                         }
+                        self.info.required.insert(src.value.clone());
                         if let Expr::Ident(i) = &mut **callee {
                             self.mark_as_cjs(&src.value);
                             if let Some((_, export_ctxt)) = self.ctxt_for(&src.value) {
@@ -618,6 +638,7 @@ where
                         return;
                     }
 
+                    self.info.required.insert(src.value.clone());
                     self.mark_as_cjs(&src.value);
 
                     if let Expr::Ident(i) = &mut **callee {
@@ -85,6 +85,14 @@ impl VisitMut for KeywordRenamer {
         }
     }
 
+    fn visit_mut_import_named_specifier(&mut self, n: &mut ImportNamedSpecifier) {
+        if let Some(renamed) = self.renamed(&n.local) {
+            n.imported
+                .get_or_insert_with(|| ModuleExportName::Ident(n.local.clone()));
+            n.local = renamed;
+        }
+    }
+
     fn visit_mut_object_pat_prop(&mut self, n: &mut ObjectPatProp) {
         if let ObjectPatProp::Assign(pat) = n {
             if let Some(renamed) = self.renamed(&pat.key) {
@@ -22,6 +22,7 @@ use crate::{
     bundler::{export::RawExports, import::RawImports},
     id::{Id, ModuleId},
     load::ModuleData,
+    resolve::ImportKind,
     util,
     util::IntoParallelIterator,
     Load, Resolve,
@@ -236,7 +237,7 @@ where
                     self.run(|| {
                         let info = match src {
                             Some(src) => {
-                                let name = self.resolve(base, &src.value)?;
+                                let name = self.resolve(base, &src.value, ImportKind::Import)?;
                                 let (id, local_mark, export_mark) =
                                     self.scope.module_id_gen.gen(&name);
                                 Some((id, local_mark, export_mark, name, src))
@@ -290,6 +291,7 @@ where
                 lazy_imports,
                 dynamic_imports,
                 forced_ns,
+                required,
             } = info;
 
             let loaded = imports
@@ -313,7 +315,12 @@ where
                 .map(|(decl, dynamic, unconditional)| -> Result<_, Error> {
                     self.run(|| {
                         //
-                        let file_name = self.resolve(base, &decl.src.value)?;
+                        let kind = if required.contains(&decl.src.value) {
+                            ImportKind::Require
+                        } else {
+                            ImportKind::Import
+                        };
+                        let file_name = self.resolve(base, &decl.src.value, kind)?;
                         let (id, local_mark, export_mark) =
                             self.scope.module_id_gen.gen(&file_name);
 
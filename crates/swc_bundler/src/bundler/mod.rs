@@ -1,4 +1,4 @@
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 
 use anyhow::{Context, Error};
 use rustc_hash::FxHashMap;
@@ -45,6 +45,53 @@ pub struct Config {
 
     /// Type of emitted module
     pub module: ModuleType,
+
+    /// If it's [Some], modules shared by multiple entries are extracted into
+    /// separate chunks instead of being duplicated in each entry.
+    pub split_chunks: Option<SplitChunks>,
+}
+
+/// Options for extracting shared modules into separate chunks.
+///
+/// Extracted chunks are emitted as [BundleKind::Lib] and renamed to
+/// [BundleKind::Named] with a content hash, e.g. `common-1a2b3c.js`. Names of
+/// bundles are treated as paths relative to the output directory, so an entry
+/// named `pages/a` imports the chunk from `../common-1a2b3c.js`.
+#[derive(Debug, Clone)]
+pub struct SplitChunks {
+    /// Minimum number of entries which should depend on a module to extract
+    /// the module.
+    pub min_shared_count: usize,
+
+    /// Minimum size of a chunk in bytes, measured with the size of the input
+    /// files. Smaller chunks are duplicated in each entry instead.
+    pub min_size: usize,
+
+    /// Modules placed in a named chunk, regardless of `min_shared_count` and
+    /// `min_size`.
+    pub manual_chunks: Vec<ManualChunk>,
+}
+
+impl Default for SplitChunks {
+    fn default() -> Self {
+        Self {
+            min_shared_count: 2,
+            min_size: 0,
+            manual_chunks: Default::default(),
+        }
+    }
+}
+
+/// A group of modules which should be emitted as a chunk named `name`.
+#[derive(Debug, Clone)]
+pub struct ManualChunk {
+    pub name: String,
+
+    /// A module belongs to the chunk if its file name contains one of these
+    /// patterns, e.g. `node_modules/react/`. Dependencies of the module
+    /// belong to the chunk too, unless they are entries or used by another
+    /// manual chunk.
+    pub modules: Vec<String>,
 }
 
 #[derive(Debug, PartialEq, Eq, Hash, Default)]
@@ -52,6 +99,19 @@ pub enum ModuleType {
     #[default]
     Es,
     Iife,
+    /// CommonJS module. Exports are assigned to `exports` and imports of
+    /// external modules or other bundles are converted to `require` calls.
+    Cjs,
+    /// CommonJS module wrapped with an UMD header.
+    Umd {
+        /// Name of the global variable used to expose exports if neither
+        /// CommonJS nor AMD is available.
+        name: Atom,
+        /// Global variables used for external modules if neither CommonJS nor
+        /// AMD is available, e.g. `react` => `React`. Modules not in the map
+        /// are read from the global variable named after the module.
+        globals: BTreeMap<Atom, Atom>,
+    },
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -144,6 +144,7 @@ impl TestBuilder {
                         disable_dce: false,
                         external_modules: Vec::new(),
                         module: Default::default(),
+                        split_chunks: None,
                     },
                     Box::new(Hook),
                 );
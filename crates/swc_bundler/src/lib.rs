@@ -4,10 +4,11 @@
 #![cfg_attr(not(test), allow(unused))]
 
 pub use self::{
-    bundler::{Bundle, BundleKind, Bundler, Config, ModuleType},
+    bundler::{Bundle, BundleKind, Bundler, Config, ManualChunk, ModuleType, SplitChunks},
     hook::{Hook, ModuleRecord},
     id::ModuleId,
     load::{Load, ModuleData},
+    print::{PrintConfig, PrintedBundle},
     resolve::Resolve,
 };
 
@@ -20,5 +21,6 @@ mod id;
 mod inline;
 mod load;
 mod modules;
+mod print;
 mod resolve;
 mod util;
@@ -0,0 +1,97 @@
+use anyhow::{Context, Error};
+use swc_common::{
+    comments::Comments, source_map::SourceMapGenConfig, sync::Lrc, FileName, SourceMap,
+};
+use swc_ecma_codegen::{text_writer::JsWriter, Emitter};
+
+use crate::Bundle;
+
+/// Configuration for [Bundle::print].
+#[derive(Debug, Clone, Default)]
+pub struct PrintConfig {
+    pub codegen: swc_ecma_codegen::Config,
+
+    /// Generate a source map for the bundle. `sources` of the source map
+    /// contains all input files which contributed to the bundle.
+    pub source_map: bool,
+
+    /// Embed the content of input files as `sourcesContent`.
+    pub inline_sources_content: bool,
+}
+
+/// Code and source map of a printed [Bundle].
+#[derive(Debug)]
+pub struct PrintedBundle {
+    pub code: String,
+
+    /// [None] if [PrintConfig::source_map] is false.
+    pub map: Option<sourcemap::SourceMap>,
+}
+
+impl Bundle {
+    /// Prints the merged module.
+    ///
+    /// `cm` should be the source map passed to [crate::Bundler::new], as spans
+    /// of the merged module point to the original input files.
+    pub fn print(
+        &self,
+        cm: Lrc<SourceMap>,
+        comments: Option<&dyn Comments>,
+        config: &PrintConfig,
+    ) -> Result<PrintedBundle, Error> {
+        let mut src_map_buf = Vec::new();
+        let mut buf = Vec::new();
+
+        {
+            let mut emitter = Emitter {
+                cfg: config.codegen,
+                cm: cm.clone(),
+                comments,
+                wr: Box::new(JsWriter::new(
+                    cm.clone(),
+                    "\n",
+                    &mut buf,
+                    if config.source_map {
+                        Some(&mut src_map_buf)
+                    } else {
+                        None
+                    },
+                )),
+            };
+
+            emitter
+                .emit_module(&self.module)
+                .context("failed to print bundle")?;
+        }
+
+        let code = String::from_utf8(buf).context("bundle is not valid utf-8")?;
+
+        let map = if config.source_map {
+            Some(cm.build_source_map_with_config(
+                &src_map_buf,
+                None,
+                SourceMapConfig {
+                    inline_sources_content: config.inline_sources_content,
+                },
+            ))
+        } else {
+            None
+        };
+
+        Ok(PrintedBundle { code, map })
+    }
+}
+
+struct SourceMapConfig {
+    inline_sources_content: bool,
+}
+
+impl SourceMapGenConfig for SourceMapConfig {
+    fn file_name_to_source(&self, f: &FileName) -> String {
+        f.to_string()
+    }
+
+    fn inline_sources_content(&self, _: &FileName) -> bool {
+        self.inline_sources_content
+    }
+}
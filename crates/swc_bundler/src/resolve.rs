@@ -1 +1 @@
-pub use swc_ecma_loader::resolve::Resolve;
+pub use swc_ecma_loader::resolve::{ImportKind, Resolve};
@@ -0,0 +1,9 @@
+import fs, { readFileSync as read } from 'fs';
+import * as path from 'path';
+import { add } from './math';
+
+export { add };
+
+export default function load(file) {
+    return read(path.resolve(file), add(fs.constants.F_OK, 0));
+}
@@ -0,0 +1,38 @@
+function __swcpack_interop__(obj) {
+    if (obj && obj.__esModule) {
+        return obj;
+    }
+    var newObj = {};
+    if (obj != null) {
+        for(var key in obj){
+            if (key !== "default" && Object.prototype.hasOwnProperty.call(obj, key)) {
+                newObj[key] = obj[key];
+            }
+        }
+    }
+    newObj.default = obj;
+    return newObj;
+}
+Object.defineProperty(exports, "__esModule", {
+    value: true
+});
+Object.defineProperty(exports, "add", {
+    enumerable: true,
+    get: function() {
+        return add;
+    }
+});
+Object.defineProperty(exports, "default", {
+    enumerable: true,
+    get: function() {
+        return load;
+    }
+});
+function add(a, b) {
+    return a + b;
+}
+var fs = __swcpack_interop__(require('fs')).default, { readFileSync: read } = require('fs');
+var path = __swcpack_interop__(require('path'));
+function load(file) {
+    return read(path.resolve(file), add(fs.constants.F_OK, 0));
+}
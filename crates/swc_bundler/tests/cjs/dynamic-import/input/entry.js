@@ -0,0 +1,4 @@
+export async function load() {
+    const { add } = await import('./math');
+    return add(1, 2);
+}
@@ -0,0 +1,30 @@
+function __swcpack_interop__(obj) {
+    if (obj && obj.__esModule) {
+        return obj;
+    }
+    var newObj = {};
+    if (obj != null) {
+        for(var key in obj){
+            if (key !== "default" && Object.prototype.hasOwnProperty.call(obj, key)) {
+                newObj[key] = obj[key];
+            }
+        }
+    }
+    newObj.default = obj;
+    return newObj;
+}
+Object.defineProperty(exports, "__esModule", {
+    value: true
+});
+Object.defineProperty(exports, "load", {
+    enumerable: true,
+    get: function() {
+        return load;
+    }
+});
+async function load() {
+    const { add } = await Promise.resolve().then(function() {
+        return __swcpack_interop__(require('./math'));
+    });
+    return add(1, 2);
+}
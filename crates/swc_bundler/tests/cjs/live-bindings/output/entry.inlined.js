@@ -0,0 +1,19 @@
+Object.defineProperty(exports, "__esModule", {
+    value: true
+});
+Object.defineProperty(exports, "count", {
+    enumerable: true,
+    get: function() {
+        return count;
+    }
+});
+Object.defineProperty(exports, "inc", {
+    enumerable: true,
+    get: function() {
+        return inc;
+    }
+});
+let count = 0;
+function inc() {
+    count++;
+}
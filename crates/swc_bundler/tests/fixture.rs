@@ -6,7 +6,7 @@ use std::{
 };
 
 use anyhow::Error;
-use swc_bundler::{BundleKind, Bundler, Config, ModuleRecord};
+use swc_bundler::{BundleKind, Bundler, Config, ModuleRecord, ModuleType};
 use swc_common::{errors::HANDLER, FileName, Globals, Span};
 use swc_ecma_ast::{
     Bool, Expr, IdentName, KeyValueProp, Lit, MemberExpr, MemberProp, MetaPropExpr, MetaPropKind,
@@ -22,7 +22,7 @@ use self::common::*;
 #[path = "common/mod.rs"]
 mod common;
 
-fn do_test(entry: &Path, entries: HashMap<String, FileName>, inline: bool) {
+fn do_test(entry: &Path, entries: HashMap<String, FileName>, inline: bool, module: ModuleType) {
     testing::run_test2(false, |cm, handler| {
         HANDLER.set(&handler, || {
             let globals = Globals::default();
@@ -35,7 +35,7 @@ fn do_test(entry: &Path, entries: HashMap<String, FileName>, inline: bool) {
                     require: true,
                     disable_inliner: !inline,
                     external_modules: NODE_BUILTINS.iter().copied().map(From::from).collect(),
-                    module: Default::default(),
+                    module,
                     ..Default::default()
                 },
                 Box::new(Hook),
@@ -110,9 +110,8 @@ fn do_test(entry: &Path, entries: HashMap<String, FileName>, inline: bool) {
     .expect("failed to process a module");
 }
 
-#[testing::fixture("tests/fixture/**/input")]
-fn pass(entry: PathBuf) {
-    let entries = read_dir(&entry)
+fn read_entries(entry: &Path) -> HashMap<String, FileName> {
+    read_dir(entry)
         .unwrap()
         .filter(|e| match e {
             Ok(e) => e
@@ -131,10 +130,44 @@ fn pass(entry: PathBuf) {
             ))
         })
         .collect::<Result<HashMap<_, _>, _>>()
-        .unwrap();
+        .unwrap()
+}
+
+#[testing::fixture("tests/fixture/**/input")]
+fn pass(entry: PathBuf) {
+    let entries = read_entries(&entry);
+
+    do_test(
+        entry.parent().unwrap(),
+        entries.clone(),
+        true,
+        ModuleType::Es,
+    );
+    do_test(entry.parent().unwrap(), entries, false, ModuleType::Es);
+}
+
+#[testing::fixture("tests/cjs/**/input")]
+fn cjs(entry: PathBuf) {
+    let entries = read_entries(&entry);
+
+    do_test(entry.parent().unwrap(), entries, true, ModuleType::Cjs);
+}
 
-    do_test(entry.parent().unwrap(), entries.clone(), true);
-    do_test(entry.parent().unwrap(), entries, false);
+#[testing::fixture("tests/umd/**/input")]
+fn umd(entry: PathBuf) {
+    let entries = read_entries(&entry);
+
+    do_test(
+        entry.parent().unwrap(),
+        entries,
+        true,
+        ModuleType::Umd {
+            name: "lib".into(),
+            globals: [("events".into(), "EventEmitter".into())]
+                .into_iter()
+                .collect(),
+        },
+    );
 }
 
 struct Hook;
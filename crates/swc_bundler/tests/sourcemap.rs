@@ -0,0 +1,107 @@
+use std::{collections::HashMap, path::PathBuf};
+
+use anyhow::Error;
+use swc_bundler::{Bundler, Config, ModuleRecord, PrintConfig};
+use swc_common::{FileName, Globals, Span};
+use swc_ecma_ast::KeyValueProp;
+
+use self::common::*;
+
+#[path = "common/mod.rs"]
+mod common;
+
+#[test]
+fn sources_of_merged_modules() {
+    let dir = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("tests")
+        .join("sourcemap")
+        .join("basic");
+    let entry = dir.join("entry.js");
+
+    testing::run_test2(false, |cm, _| {
+        let globals = Globals::default();
+        let mut bundler = Bundler::new(
+            &globals,
+            cm.clone(),
+            Loader { cm: cm.clone() },
+            NodeResolver,
+            Config {
+                require: true,
+                ..Default::default()
+            },
+            Box::new(Hook),
+        );
+
+        let mut entries = HashMap::new();
+        entries.insert("main".to_string(), FileName::Real(entry.clone()));
+
+        let bundles = bundler.bundle(entries).unwrap();
+        assert_eq!(bundles.len(), 1);
+
+        let printed = bundles[0]
+            .print(
+                cm.clone(),
+                None,
+                &PrintConfig {
+                    source_map: true,
+                    inline_sources_content: true,
+                    ..Default::default()
+                },
+            )
+            .unwrap();
+        let map = printed.map.unwrap();
+
+        let sources = map.sources().collect::<Vec<_>>();
+        assert!(
+            sources.iter().any(|s| s.ends_with("entry.js")),
+            "{:?}",
+            sources
+        );
+        assert!(
+            sources.iter().any(|s| s.ends_with("math.js")),
+            "{:?}",
+            sources
+        );
+        assert!(
+            map.source_contents().all(|content| content.is_some()),
+            "sourcesContent should be embedded"
+        );
+
+        // `add` in the call is an inlined reference to the imported binding.
+        let (line, col) = printed
+            .code
+            .lines()
+            .enumerate()
+            .find_map(|(line, s)| s.find("add(1").map(|col| (line, col)))
+            .unwrap();
+        let token = map.lookup_token(line as u32, col as u32).unwrap();
+        assert_eq!(
+            token.get_dst(),
+            (line as u32, col as u32),
+            "{}",
+            printed.code
+        );
+        assert!(
+            token.get_source().unwrap().ends_with("entry.js"),
+            "{:?}\n{}",
+            token,
+            printed.code
+        );
+
+        let printed = bundles[0]
+            .print(cm.clone(), None, &Default::default())
+            .unwrap();
+        assert!(printed.map.is_none());
+
+        Ok(())
+    })
+    .unwrap();
+}
+
+struct Hook;
+
+impl swc_bundler::Hook for Hook {
+    fn get_import_meta_props(&self, _: Span, _: &ModuleRecord) -> Result<Vec<KeyValueProp>, Error> {
+        unreachable!()
+    }
+}
@@ -0,0 +1,3 @@
+import { add as sum } from './math';
+
+console.log(sum(1, 2));
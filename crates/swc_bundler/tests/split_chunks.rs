@@ -0,0 +1,133 @@
+use std::{collections::HashMap, path::PathBuf};
+
+use anyhow::Error;
+use swc_bundler::{BundleKind, Bundler, Config, ManualChunk, ModuleRecord, SplitChunks};
+use swc_common::{FileName, Globals, Span};
+use swc_ecma_ast::KeyValueProp;
+use testing::NormalizedOutput;
+
+use self::common::*;
+
+#[path = "common/mod.rs"]
+mod common;
+
+/// Bundles `a.js` and `b.js` from `tests/split_chunks/{case}` and compares
+/// all bundles with `tests/split_chunks/{case}/output/{output}.js`.
+fn run(case: &str, output: &str, split_chunks: SplitChunks) {
+    run_with_entries(case, output, split_chunks, &[("a", "a.js"), ("b", "b.js")]);
+}
+
+/// Like [run], but with entries of the given names and files.
+fn run_with_entries(case: &str, output: &str, split_chunks: SplitChunks, entries: &[(&str, &str)]) {
+    let dir = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("tests")
+        .join("split_chunks")
+        .join(case);
+
+    testing::run_test2(false, |cm, _| {
+        let globals = Globals::default();
+        let mut bundler = Bundler::new(
+            &globals,
+            cm.clone(),
+            Loader { cm: cm.clone() },
+            NodeResolver,
+            Config {
+                require: true,
+                split_chunks: Some(split_chunks),
+                ..Default::default()
+            },
+            Box::new(Hook),
+        );
+
+        let entries = entries
+            .iter()
+            .map(|(name, file)| (name.to_string(), FileName::Real(dir.join(file))))
+            .collect::<HashMap<_, _>>();
+
+        let mut bundles = bundler
+            .bundle(entries)
+            .unwrap()
+            .into_iter()
+            .map(|bundle| {
+                let name = match &bundle.kind {
+                    BundleKind::Named { name } => name.clone(),
+                    kind => unreachable!("unexpected bundle kind: {:?}", kind),
+                };
+                let code = bundle
+                    .print(cm.clone(), None, &Default::default())
+                    .unwrap()
+                    .code;
+
+                (name, code)
+            })
+            .collect::<Vec<_>>();
+        bundles.sort();
+
+        let mut actual = String::new();
+        for (name, code) in bundles {
+            actual.push_str(&format!("// {}\n{}\n", name, code));
+        }
+
+        NormalizedOutput::from(actual)
+            .compare_to_file(dir.join("output").join(format!("{}.js", output)))
+            .unwrap();
+
+        Ok(())
+    })
+    .unwrap();
+}
+
+#[test]
+fn shared_modules() {
+    run("basic", "shared_modules", Default::default());
+}
+
+#[test]
+fn min_size() {
+    run(
+        "basic",
+        "min_size",
+        SplitChunks {
+            min_size: 10_000,
+            ..Default::default()
+        },
+    );
+}
+
+#[test]
+fn manual_chunks() {
+    run(
+        "basic",
+        "manual_chunks",
+        SplitChunks {
+            manual_chunks: vec![ManualChunk {
+                name: "vendor".into(),
+                modules: vec!["vendor/".into()],
+            }],
+            ..Default::default()
+        },
+    );
+}
+
+#[test]
+fn nested_entry() {
+    run_with_entries(
+        "basic",
+        "nested_entry",
+        Default::default(),
+        &[("pages/a", "a.js"), ("b", "b.js")],
+    );
+}
+
+#[test]
+fn aliased_export() {
+    run("alias", "aliased_export", Default::default());
+}
+
+struct Hook;
+
+impl swc_bundler::Hook for Hook {
+    fn get_import_meta_props(&self, _: Span, _: &ModuleRecord) -> Result<Vec<KeyValueProp>, Error> {
+        unreachable!()
+    }
+}
@@ -0,0 +1,3 @@
+import greet, { b } from './shared';
+
+console.log(greet(b('a')));
@@ -0,0 +1,19 @@
+// a
+import { default as __default, b as b } from "./shared-y7egb0q314bn.js";
+console.log(__default(b('a')));
+
+// b
+import { b as b } from "./shared-y7egb0q314bn.js";
+const value = b('b');
+export { value as value };
+
+// shared-y7egb0q314bn.js
+function a(v) {
+    return `[${v}]`;
+}
+export { a as b };
+function greet(v) {
+    return `Hello, ${v}`;
+}
+export { greet as default };
+
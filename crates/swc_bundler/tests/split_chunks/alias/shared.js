@@ -0,0 +1,9 @@
+function a(v) {
+    return `[${v}]`;
+}
+
+export { a as b };
+
+export default function greet(v) {
+    return `Hello, ${v}`;
+}
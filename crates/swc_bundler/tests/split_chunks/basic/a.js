@@ -0,0 +1,4 @@
+import { format } from './shared';
+import { clamp } from './vendor/math';
+
+console.log(format('a'), clamp(1, 0, 2));
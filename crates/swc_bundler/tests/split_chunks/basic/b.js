@@ -0,0 +1,3 @@
+import { format } from './shared';
+
+export const b = format('b');
@@ -0,0 +1,25 @@
+// a
+import { format as format } from "./shared-2yvimmtbtsdi8.js";
+import { clamp as clamp } from "./vendor-13p2itjoafxl0.js";
+console.log(format('a'), clamp(1, 0, 2));
+
+// b
+import { format as format } from "./shared-2yvimmtbtsdi8.js";
+const b = format('b');
+export { b as b };
+
+// shared-2yvimmtbtsdi8.js
+function upper(v) {
+    return v.toUpperCase();
+}
+function format(v) {
+    return `[${upper(v)}]`;
+}
+export { format as format };
+
+// vendor-13p2itjoafxl0.js
+function clamp(v, min, max) {
+    return Math.min(Math.max(v, min), max);
+}
+export { clamp as clamp };
+
@@ -0,0 +1,22 @@
+// a
+function upper(v) {
+    return v.toUpperCase();
+}
+function format(v) {
+    return `[${upper(v)}]`;
+}
+function clamp(v, min, max) {
+    return Math.min(Math.max(v, min), max);
+}
+console.log(format('a'), clamp(1, 0, 2));
+
+// b
+function upper(v) {
+    return v.toUpperCase();
+}
+function format(v) {
+    return `[${upper(v)}]`;
+}
+const b = format('b');
+export { b as b };
+
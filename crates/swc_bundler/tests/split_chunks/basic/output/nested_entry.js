@@ -0,0 +1,21 @@
+// b
+import { format as format } from "./shared-2yvimmtbtsdi8.js";
+const b = format('b');
+export { b as b };
+
+// pages/a
+function clamp(v, min, max) {
+    return Math.min(Math.max(v, min), max);
+}
+import { format as format } from "../shared-2yvimmtbtsdi8.js";
+console.log(format('a'), clamp(1, 0, 2));
+
+// shared-2yvimmtbtsdi8.js
+function upper(v) {
+    return v.toUpperCase();
+}
+function format(v) {
+    return `[${upper(v)}]`;
+}
+export { format as format };
+
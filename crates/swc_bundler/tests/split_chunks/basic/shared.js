@@ -0,0 +1,5 @@
+import { upper } from './upper';
+
+export function format(v) {
+    return `[${upper(v)}]`;
+}
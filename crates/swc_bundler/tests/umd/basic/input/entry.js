@@ -0,0 +1,8 @@
+import { add } from './math';
+import { EventEmitter } from 'events';
+
+export const emitter = new EventEmitter();
+
+export function sum(values) {
+    return values.reduce(add, 0);
+}
@@ -0,0 +1,46 @@
+(function(global, factory) {
+    if (typeof module === "object" && typeof module.exports === "object") {
+        factory(module, exports, require);
+    } else if (typeof define === "function" && define.amd) {
+        define([
+            "module",
+            "exports",
+            "require",
+            "events"
+        ], factory);
+    } else {
+        var mod = {
+            exports: {}
+        };
+        factory(mod, mod.exports, function(id) {
+            return global[({
+                "events": "EventEmitter"
+            })[id] || id];
+        });
+        global["lib"] = mod.exports;
+    }
+})(typeof globalThis !== "undefined" ? globalThis : typeof self !== "undefined" ? self : this, function(module, exports, require) {
+    Object.defineProperty(exports, "__esModule", {
+        value: true
+    });
+    Object.defineProperty(exports, "emitter", {
+        enumerable: true,
+        get: function() {
+            return emitter;
+        }
+    });
+    Object.defineProperty(exports, "sum", {
+        enumerable: true,
+        get: function() {
+            return sum;
+        }
+    });
+    function add(a, b) {
+        return a + b;
+    }
+    var { EventEmitter: EventEmitter } = require('events');
+    const emitter = new EventEmitter();
+    function sum(values) {
+        return values.reduce(add, 0);
+    }
+});
@@ -1,12 +1,380 @@
-use clap::Parser;
-use swc_core::trace_macro::swc_trace;
+use std::{
+    collections::HashMap,
+    fs,
+    path::{Path, PathBuf},
+    sync::Arc,
+};
 
+use anyhow::Context;
+use clap::{ArgEnum, Parser};
+use swc_core::{
+    atoms::Atom,
+    base::{config::SourceMapsConfig, Compiler, PrintArgs},
+    bundler::{
+        node::loaders::swc::SwcLoader, Bundle, BundleKind, Bundler, Hook, ModuleRecord, ModuleType,
+    },
+    common::{sync::Lazy, FileName, FilePathMapping, Globals, SourceMap, Span, GLOBALS},
+    ecma::{
+        ast::{
+            Bool, Expr, IdentName, KeyValueProp, Lit, MemberExpr, MemberProp, MetaPropExpr,
+            MetaPropKind, PropName, Str,
+        },
+        codegen,
+        loader::{
+            resolve::Resolve,
+            resolvers::{
+                lru::CachingResolver,
+                node::NodeModulesResolver,
+                pnp::{find_manifest, PnpResolver},
+            },
+            TargetEnv, NODE_BUILTINS,
+        },
+    },
+    trace_macro::swc_trace,
+};
+
+use crate::util::trace::init_trace;
+
+/// Configuration option for bundling modules.
 #[derive(Parser)]
-pub struct BundleOptions {}
+pub struct BundleOptions {
+    /// Entry points to bundle. Use `name=path` to specify the name of the
+    /// output bundle, otherwise the file stem of the entry is used.
+    #[clap(required = true)]
+    entries: Vec<String>,
+
+    /// The output directory
+    #[clap(long, default_value = "dist")]
+    out_dir: PathBuf,
+
+    /// Type of the emitted bundles.
+    #[clap(long, arg_enum, default_value = "es")]
+    format: BundleFormat,
+
+    /// Name of the global variable used by umd bundles if neither CommonJS
+    /// nor AMD is available.
+    #[clap(long, required_if_eq("format", "umd"))]
+    umd_name: Option<String>,
+
+    /// Global variable used for an external module by umd bundles if
+    /// neither CommonJS nor AMD is available, e.g. `react=React`.
+    #[clap(long = "umd-global", value_parser = parse_umd_global)]
+    umd_globals: Vec<(String, String)>,
+
+    /// Modules which should not be bundled.
+    #[clap(long = "external")]
+    externals: Vec<String>,
+
+    /// Target runtime environment. Node builtins are treated as externals
+    /// when targeting node.
+    #[clap(long, arg_enum, default_value = "browser")]
+    target: BundleTarget,
+
+    /// Preserve symlinks while resolving modules.
+    #[clap(long)]
+    preserve_symlinks: bool,
+
+    /// Conditions used to resolve `exports` and `imports` of package.json.
+    /// `default` is always matched. Defaults to `node` or `browser` depending
+    /// on the target, and `import` or `require` depending on how a module is
+    /// imported.
+    #[clap(long = "condition")]
+    conditions: Vec<String>,
+
+    /// Enable experimental trace profiling
+    /// generates trace compatible with trace event format.
+    #[clap(group = "experimental_trace", long)]
+    experimental_trace: bool,
+
+    /// Set file name for the trace output. If not specified,
+    /// `trace-{unix epoch time}.json` will be used by default.
+    #[clap(group = "experimental_trace", long)]
+    trace_out_file: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, ArgEnum)]
+enum BundleFormat {
+    Es,
+    Iife,
+    Cjs,
+    Umd,
+}
+
+#[derive(Debug, Clone, Copy, ArgEnum)]
+enum BundleTarget {
+    Browser,
+    Node,
+}
+
+impl From<BundleTarget> for TargetEnv {
+    fn from(target: BundleTarget) -> Self {
+        match target {
+            BundleTarget::Browser => TargetEnv::Browser,
+            BundleTarget::Node => TargetEnv::Node,
+        }
+    }
+}
+
+static COMPILER: Lazy<Arc<Compiler>> = Lazy::new(|| {
+    let cm = Arc::new(SourceMap::new(FilePathMapping::empty()));
+
+    Arc::new(Compiler::new(cm))
+});
+
+/// Parse `module=Global` into the name of the module and the global variable.
+fn parse_umd_global(s: &str) -> Result<(String, String), String> {
+    match s.split_once('=') {
+        Some((module, global)) if !module.is_empty() && !global.is_empty() => {
+            Ok((module.to_string(), global.to_string()))
+        }
+        _ => Err(format!("expected `module=Global`, got `{}`", s)),
+    }
+}
+
+/// Parse `name=path` or `path` into the name of the bundle and the entry file.
+fn parse_entry(entry: &str) -> anyhow::Result<(String, FileName)> {
+    let (name, path) = match entry.split_once('=') {
+        Some((name, path)) => (name.to_string(), PathBuf::from(path)),
+        None => {
+            let path = PathBuf::from(entry);
+            let name = path
+                .file_stem()
+                .with_context(|| format!("failed to get name of entry `{}`", entry))?
+                .to_string_lossy()
+                .to_string();
+
+            (name, path)
+        }
+    };
+
+    let path = path
+        .canonicalize()
+        .with_context(|| format!("failed to find entry `{}`", path.display()))?;
+
+    Ok((name, FileName::Real(path)))
+}
+
+/// Calculate the path of the output file for a bundle, relative to the output
+/// directory.
+fn bundle_file_name(cm: &SourceMap, bundle: &Bundle) -> PathBuf {
+    match &bundle.kind {
+        BundleKind::Named { name } | BundleKind::Lib { name } => {
+            let is_js = [".js", ".mjs", ".cjs"]
+                .iter()
+                .any(|ext| name.ends_with(ext));
+
+            if is_js {
+                PathBuf::from(name)
+            } else {
+                PathBuf::from(format!("{}.js", name))
+            }
+        }
+        BundleKind::Dynamic => {
+            // Dynamic chunks keep their path relative to the current directory, so
+            // that `import()` calls in other bundles still point to them.
+            match &*cm.span_to_filename(bundle.module.span) {
+                FileName::Real(path) => {
+                    let path = std::env::current_dir()
+                        .ok()
+                        .and_then(|cwd| path.strip_prefix(cwd).ok().map(Path::to_path_buf))
+                        .unwrap_or_else(|| PathBuf::from(path.file_name().unwrap_or_default()));
+
+                    path.with_extension("js")
+                }
+                _ => PathBuf::from(format!("dynamic-{}.js", bundle.id)),
+            }
+        }
+    }
+}
+
+#[swc_trace]
+impl BundleOptions {
+    fn module_type(&self) -> ModuleType {
+        match self.format {
+            BundleFormat::Es => ModuleType::Es,
+            BundleFormat::Iife => ModuleType::Iife,
+            BundleFormat::Cjs => ModuleType::Cjs,
+            BundleFormat::Umd => ModuleType::Umd {
+                name: self
+                    .umd_name
+                    .as_deref()
+                    .expect("--umd-name is required for umd bundles")
+                    .into(),
+                globals: self
+                    .umd_globals
+                    .iter()
+                    .map(|(module, global)| (module.as_str().into(), global.as_str().into()))
+                    .collect(),
+            },
+        }
+    }
+
+    fn emit_bundle(&self, compiler: &Compiler, bundle: Bundle) -> anyhow::Result<()> {
+        let output_file_path = self.out_dir.join(bundle_file_name(&compiler.cm, &bundle));
+        let output_dir = output_file_path
+            .parent()
+            .expect("Parent should be available");
+
+        if !output_dir.is_dir() {
+            fs::create_dir_all(output_dir)?;
+        }
+
+        let mut output = compiler
+            .print(
+                &bundle.module,
+                PrintArgs {
+                    output_path: Some(output_file_path.clone()),
+                    inline_sources_content: true,
+                    source_map: SourceMapsConfig::Bool(true),
+                    emit_source_map_columns: true,
+                    codegen_config: codegen::Config::default(),
+                    ..Default::default()
+                },
+            )
+            .with_context(|| format!("failed to print `{}`", output_file_path.display()))?;
+
+        if let Some(ref source_map) = output.map {
+            let source_map_path = output_file_path.with_extension(format!(
+                "{}.map",
+                output_file_path
+                    .extension()
+                    .unwrap_or_default()
+                    .to_string_lossy()
+            ));
+
+            output.code.push_str("\n//# sourceMappingURL=");
+            output
+                .code
+                .push_str(&source_map_path.file_name().unwrap().to_string_lossy());
+
+            fs::write(source_map_path, source_map)?;
+        }
+
+        fs::write(&output_file_path, &output.code)?;
+
+        Ok(())
+    }
+
+    fn execute_inner(&self) -> anyhow::Result<()> {
+        let compiler = COMPILER.clone();
+
+        let entries = self
+            .entries
+            .iter()
+            .map(|entry| parse_entry(entry))
+            .collect::<anyhow::Result<HashMap<_, _>>>()?;
+
+        let target_env = TargetEnv::from(self.target);
+
+        let builtins = if let TargetEnv::Node = target_env {
+            NODE_BUILTINS.iter().copied().map(Atom::from).collect()
+        } else {
+            Vec::new()
+        };
+
+        let loader = SwcLoader::new(
+            compiler.clone(),
+            serde_json::from_str("{}").expect("empty options should be valid"),
+        );
+        let mut resolver =
+            NodeModulesResolver::new(target_env, Default::default(), self.preserve_symlinks);
+        if !self.conditions.is_empty() {
+            resolver = resolver.with_conditions(self.conditions.clone());
+        }
+        // Use Yarn PnP if the project has a manifest.
+        let resolver: Box<dyn Resolve> = match find_manifest(Path::new(".")) {
+            Some(manifest) => Box::new(CachingResolver::new(
+                40,
+                PnpResolver::new(&manifest, resolver)?,
+            )),
+            None => Box::new(CachingResolver::new(40, resolver)),
+        };
+
+        let globals = Globals::default();
+        GLOBALS.set(&globals, || {
+            let mut bundler = Bundler::new(
+                &globals,
+                compiler.cm.clone(),
+                loader,
+                resolver,
+                swc_core::bundler::Config {
+                    require: true,
+                    external_modules: builtins
+                        .into_iter()
+                        .chain(self.externals.iter().map(|v| Atom::from(&**v)))
+                        .collect(),
+                    module: self.module_type(),
+                    ..Default::default()
+                },
+                Box::new(ImportMetaHook),
+            );
+
+            let bundles = bundler.bundle(entries).context("failed to bundle")?;
+
+            for bundle in bundles {
+                self.emit_bundle(&compiler, bundle)?;
+            }
+
+            Ok(())
+        })
+    }
+}
 
 #[swc_trace]
 impl super::CommandRunner for BundleOptions {
     fn execute(&self) -> anyhow::Result<()> {
-        unimplemented!("Bundle command is not yet implemented")
+        let guard = if self.experimental_trace {
+            init_trace(&self.trace_out_file)
+        } else {
+            None
+        };
+
+        let ret = self.execute_inner();
+
+        if let Some(guard) = guard {
+            guard.flush();
+            drop(guard);
+        }
+
+        ret
+    }
+}
+
+/// Provides `import.meta.url` and `import.meta.main` for bundled modules.
+struct ImportMetaHook;
+
+impl Hook for ImportMetaHook {
+    fn get_import_meta_props(
+        &self,
+        span: Span,
+        module_record: &ModuleRecord,
+    ) -> anyhow::Result<Vec<KeyValueProp>> {
+        let file_name = module_record.file_name.to_string();
+
+        Ok(vec![
+            KeyValueProp {
+                key: PropName::Ident(IdentName::new("url".into(), span)),
+                value: Box::new(Expr::Lit(Lit::Str(Str {
+                    span,
+                    raw: None,
+                    value: file_name.into(),
+                }))),
+            },
+            KeyValueProp {
+                key: PropName::Ident(IdentName::new("main".into(), span)),
+                value: Box::new(if module_record.is_entry {
+                    Expr::Member(MemberExpr {
+                        span,
+                        obj: Box::new(Expr::MetaProp(MetaPropExpr {
+                            span,
+                            kind: MetaPropKind::ImportMeta,
+                        })),
+                        prop: MemberProp::Ident(IdentName::new("main".into(), span)),
+                    })
+                } else {
+                    Expr::Lit(Lit::Bool(Bool { span, value: false }))
+                }),
+            },
+        ])
     }
 }
@@ -2,19 +2,24 @@ use std::{
     fs::{self, File},
     io::{self, IsTerminal, Read, Write},
     path::{Component, Path, PathBuf},
-    sync::Arc,
+    sync::{mpsc, Arc},
+    time::Duration,
 };
 
 use anyhow::Context;
+use base64::prelude::{Engine, BASE64_STANDARD};
 use clap::Parser;
 use glob::glob;
+use notify::{RecursiveMode, Watcher};
 use path_absolutize::Absolutize;
 use rayon::prelude::*;
 use relative_path::RelativePath;
 use swc_core::{
     base::{
-        config::{Config, ConfigFile, Options, PluginConfig, SourceMapsConfig},
-        try_with_handler, Compiler, HandlerOpts, TransformOutput,
+        config::{
+            Config, ConfigFile, Options, PluginConfig, SourceMapsConfig, TransformCacheOptions,
+        },
+        try_with_handler, Compiler, HandlerOpts, TransformArtifact, TransformOutput,
     },
     common::{
         errors::ColorConfig, sync::Lazy, FileName, FilePathMapping, SourceFile, SourceMap, GLOBALS,
@@ -93,6 +98,16 @@ pub struct CompileOptions {
     #[clap(long, default_value_t= String::from("js"))]
     out_file_extension: String,
 
+    /// Experimental: cache the output of files in this directory, and reuse
+    /// it when the file and the configuration did not change.
+    #[clap(long)]
+    transform_cache_dir: Option<PathBuf>,
+
+    /// Maximum size of the transform cache in bytes. Least recently used
+    /// entries are removed when it's exceeded.
+    #[clap(long, requires = "transform-cache-dir")]
+    transform_cache_max_size: Option<u64>,
+
     /// Enable experimental trace profiling
     /// generates trace compatible with trace event format.
     #[clap(group = "experimental_trace", long)]
@@ -114,20 +129,53 @@ fn parse_config(s: &str) -> Result<Config, serde_json::Error> {
     serde_json::from_str(s)
 }
 
-static COMPILER: Lazy<Arc<Compiler>> = Lazy::new(|| {
+static COMPILER: Lazy<Arc<Compiler>> = Lazy::new(new_compiler);
+
+fn new_compiler() -> Arc<Compiler> {
     let cm = Arc::new(SourceMap::new(FilePathMapping::empty()));
 
     Arc::new(Compiler::new(cm))
-});
+}
+
+/// Time to wait for more file system events before recompiling in watch mode.
+const WATCH_DEBOUNCE: Duration = Duration::from_millis(100);
+
+/// Collects the paths changed by `first` and the events following it, until no
+/// event arrives for `debounce`. This merges bursts of events, e.g. editors
+/// writing files in multiple steps.
+fn debounce_events(
+    first: notify::Result<notify::Event>,
+    rx: &mpsc::Receiver<notify::Result<notify::Event>>,
+    debounce: Duration,
+) -> Vec<PathBuf> {
+    let mut events = vec![first];
+    while let Ok(event) = rx.recv_timeout(debounce) {
+        events.push(event);
+    }
+
+    let mut changed = Vec::new();
+    for event in events {
+        match event {
+            Ok(event) if !event.kind.is_access() => changed.extend(event.paths),
+            Ok(_) => {}
+            Err(err) => eprintln!("Watch error: {}", err),
+        }
+    }
+    changed.sort();
+    changed.dedup();
+
+    changed
+}
 
 /// List of file extensions supported by default.
-static DEFAULT_EXTENSIONS: &[&str] = &["js", "jsx", "es6", "es", "mjs", "ts", "tsx", "cts", "mts"];
+pub(crate) static DEFAULT_EXTENSIONS: &[&str] =
+    &["js", "jsx", "es6", "es", "mjs", "ts", "tsx", "cts", "mts"];
 
 /// Infer list of files to be transformed from cli arguments.
 /// If given input is a directory, it'll traverse it and collect all supported
 /// files.
 #[tracing::instrument(level = "info", skip_all)]
-fn get_files_list(
+pub(crate) fn get_files_list(
     raw_files_input: &[PathBuf],
     extensions: &[String],
     ignore_pattern: Option<&str>,
@@ -211,6 +259,40 @@ fn resolve_output_file_path(
     Ok(output_path)
 }
 
+/// Writes artifacts emitted by plugins into `dir`, which is the directory of
+/// the output file.
+fn write_artifacts(dir: &Path, artifacts: &[TransformArtifact]) -> anyhow::Result<()> {
+    for artifact in artifacts {
+        let path = Path::new(&artifact.path);
+
+        // Artifacts should not be written outside of the output directory.
+        if !path
+            .components()
+            .all(|c| matches!(c, Component::Normal(..) | Component::CurDir))
+        {
+            anyhow::bail!(
+                "artifact path `{}` should be relative to the output file without `..`",
+                artifact.path
+            );
+        }
+
+        let path = dir.join(path);
+
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+
+        let content = BASE64_STANDARD
+            .decode(&artifact.content)
+            .with_context(|| format!("failed to decode artifact `{}`", artifact.path))?;
+
+        fs::write(&path, content)
+            .with_context(|| format!("failed to write artifact `{}`", path.display()))?;
+    }
+
+    Ok(())
+}
+
 fn emit_output(
     mut output: TransformOutput,
     out_dir: &Option<PathBuf>,
@@ -240,13 +322,26 @@ fn emit_output(
 
         fs::write(&output_file_path, &output.code)?;
 
+        write_artifacts(output_dir, &output.artifacts)?;
+
         if let Some(extra) = &output.output {
             let mut extra: serde_json::Map<String, serde_json::Value> =
                 serde_json::from_str(extra).context("failed to parse extra output")?;
 
             if let Some(dts_code) = extra.remove("__swc_isolated_declarations__") {
                 let dts_file_path = output_file_path.with_extension("d.ts");
-                fs::write(dts_file_path, dts_code.as_str().unwrap())?;
+                let mut dts_code = dts_code.as_str().unwrap().to_string();
+
+                if let Some(dts_map) = extra.remove("__swc_isolated_declarations_map__") {
+                    let dts_map_path = output_file_path.with_extension("d.ts.map");
+
+                    dts_code.push_str("\n//# sourceMappingURL=");
+                    dts_code.push_str(&dts_map_path.file_name().unwrap().to_string_lossy());
+
+                    fs::write(dts_map_path, dts_map.as_str().unwrap())?;
+                }
+
+                fs::write(dts_file_path, dts_code)?;
             }
         }
     } else {
@@ -277,6 +372,29 @@ fn collect_stdin_input() -> Option<String> {
     }
 }
 
+fn execute(
+    compiler: Arc<Compiler>,
+    fm: Arc<SourceFile>,
+    options: Options,
+) -> anyhow::Result<TransformOutput> {
+    let color = ColorConfig::Always;
+    let skip_filename = false;
+
+    try_with_handler(
+        compiler.cm.clone(),
+        HandlerOpts {
+            color,
+            skip_filename,
+        },
+        |handler| {
+            GLOBALS.set(&Default::default(), || {
+                compiler.process_js_file(fm, handler, &options)
+            })
+        },
+    )
+    .map_err(|e| e.to_pretty_error())
+}
+
 struct InputContext {
     options: Options,
     fm: Arc<SourceFile>,
@@ -342,45 +460,62 @@ impl CompileOptions {
             self.source_root.clone_into(&mut options.source_root);
         }
 
+        options.experimental.transform_cache = self.transform_cache_dir.as_ref().map(|dir| {
+            let mut cache = TransformCacheOptions::new(dir.clone());
+            if let Some(max_size) = self.transform_cache_max_size {
+                cache.max_size = max_size;
+            }
+            cache
+        });
+
         Ok(options)
     }
 
+    /// List of files to compile, expanded from the input files and directories.
+    fn get_files_list(&self) -> anyhow::Result<Vec<PathBuf>> {
+        let included_extensions = if let Some(extensions) = &self.extensions {
+            extensions.clone()
+        } else {
+            DEFAULT_EXTENSIONS.iter().map(|v| v.to_string()).collect()
+        };
+
+        get_files_list(
+            &self.files,
+            &included_extensions,
+            self.ignore.as_deref(),
+            false,
+        )
+    }
+
+    fn build_input(
+        &self,
+        compiler: &Arc<Compiler>,
+        file_path: &Path,
+    ) -> anyhow::Result<InputContext> {
+        let options = self.build_transform_options(&Some(file_path))?;
+        let fm = compiler
+            .cm
+            .load_file(file_path)
+            .context(format!("Failed to open file {}", file_path.display()))?;
+
+        Ok(InputContext {
+            options,
+            fm,
+            compiler: compiler.clone(),
+            file_path: file_path.to_path_buf(),
+            file_extension: self.out_file_extension.clone().into(),
+        })
+    }
+
     /// Create canonical list of inputs to be processed across stdin / single
     /// file / multiple files.
-    fn collect_inputs(&self) -> anyhow::Result<Vec<InputContext>> {
-        let compiler = COMPILER.clone();
-
+    fn collect_inputs(&self, compiler: &Arc<Compiler>) -> anyhow::Result<Vec<InputContext>> {
         if !self.files.is_empty() {
-            let included_extensions = if let Some(extensions) = &self.extensions {
-                extensions.clone()
-            } else {
-                DEFAULT_EXTENSIONS.iter().map(|v| v.to_string()).collect()
-            };
-
-            return get_files_list(
-                &self.files,
-                &included_extensions,
-                self.ignore.as_deref(),
-                false,
-            )?
-            .iter()
-            .map(|file_path| {
-                self.build_transform_options(&Some(file_path))
-                    .and_then(|options| {
-                        let fm = compiler
-                            .cm
-                            .load_file(file_path)
-                            .context(format!("Failed to open file {}", file_path.display()));
-                        fm.map(|fm| InputContext {
-                            options,
-                            fm,
-                            compiler: compiler.clone(),
-                            file_path: file_path.to_path_buf(),
-                            file_extension: self.out_file_extension.clone().into(),
-                        })
-                    })
-            })
-            .collect::<anyhow::Result<Vec<InputContext>>>();
+            return self
+                .get_files_list()?
+                .iter()
+                .map(|file_path| self.build_input(compiler, file_path))
+                .collect::<anyhow::Result<Vec<InputContext>>>();
         }
 
         let stdin_input = collect_stdin_input();
@@ -403,7 +538,7 @@ impl CompileOptions {
             return Ok(vec![InputContext {
                 options,
                 fm,
-                compiler,
+                compiler: compiler.clone(),
                 file_path: self
                     .filename
                     .clone()
@@ -415,27 +550,8 @@ impl CompileOptions {
         anyhow::bail!("Input is empty");
     }
 
-    fn execute_inner(&self) -> anyhow::Result<()> {
-        let inputs = self.collect_inputs()?;
-
-        let execute = |compiler: Arc<Compiler>, fm: Arc<SourceFile>, options: Options| {
-            let color = ColorConfig::Always;
-            let skip_filename = false;
-
-            try_with_handler(
-                compiler.cm.clone(),
-                HandlerOpts {
-                    color,
-                    skip_filename,
-                },
-                |handler| {
-                    GLOBALS.set(&Default::default(), || {
-                        compiler.process_js_file(fm, handler, &options)
-                    })
-                },
-            )
-            .map_err(|e| e.to_pretty_error())
-        };
+    fn execute_inner(&self, compiler: &Arc<Compiler>) -> anyhow::Result<()> {
+        let inputs = self.collect_inputs(compiler)?;
 
         if let Some(single_out_file) = self.out_file.as_ref() {
             let result: anyhow::Result<Vec<TransformOutput>> = inputs
@@ -519,6 +635,13 @@ impl CompileOptions {
                 }
 
                 buf.write(r.code.as_bytes()).and(Ok(()))?;
+
+                write_artifacts(
+                    single_out_file
+                        .parent()
+                        .expect("Parent should be available"),
+                    &r.artifacts,
+                )?;
             }
 
             if let Some(source_map_path) = source_map_path {
@@ -549,6 +672,137 @@ impl CompileOptions {
             )
         }
     }
+
+    /// Compiles the changed files again, or every input if the config changed
+    /// or all inputs are written to a single file.
+    ///
+    /// A new [Compiler] is used for each rebuild, so source files loaded by
+    /// previous rebuilds are not kept alive by its [SourceMap].
+    fn recompile(&self, changed: &[PathBuf]) -> anyhow::Result<()> {
+        let config_changed = changed.iter().any(|path| {
+            path.file_name().map(|v| v == ".swcrc").unwrap_or(false)
+                || self
+                    .config_file
+                    .as_ref()
+                    .and_then(|v| v.absolutize().ok())
+                    .map(|v| v == path.as_path())
+                    .unwrap_or(false)
+        });
+
+        let compiler = new_compiler();
+
+        if config_changed {
+            return self.execute_inner(&compiler);
+        }
+
+        let cwd = std::env::current_dir()?;
+
+        let files = self
+            .get_files_list()?
+            .into_iter()
+            .filter(|file_path| {
+                file_path
+                    .absolutize_from(&cwd)
+                    .map(|v| changed.iter().any(|changed| changed == &*v))
+                    .unwrap_or(false)
+            })
+            .collect::<Vec<_>>();
+
+        if files.is_empty() {
+            return Ok(());
+        }
+
+        if self.out_file.is_some() {
+            return self.execute_inner(&compiler);
+        }
+
+        let errors = files
+            .par_iter()
+            .filter_map(|file_path| {
+                let result = self.build_input(&compiler, file_path).and_then(
+                    |InputContext {
+                         compiler,
+                         fm,
+                         options,
+                         file_path,
+                         file_extension,
+                     }| {
+                        let output = execute(compiler, fm, options)?;
+                        emit_output(output, &self.out_dir, &file_path, file_extension)
+                    },
+                );
+
+                match result {
+                    Ok(()) => {
+                        eprintln!("Compiled {}", file_path.display());
+                        None
+                    }
+                    Err(err) => Some(err),
+                }
+            })
+            .collect::<Vec<_>>();
+
+        for err in &errors {
+            eprintln!("{:?}", err);
+        }
+
+        Ok(())
+    }
+
+    /// Compiles the inputs, and recompiles them whenever a file or the
+    /// configuration changes. Errors are reported without exiting.
+    fn watch(&self) -> anyhow::Result<()> {
+        if self.files.is_empty() {
+            anyhow::bail!("--watch requires input files");
+        }
+
+        let (tx, rx) = mpsc::channel();
+        let mut watcher =
+            notify::recommended_watcher(tx).context("failed to create a file watcher")?;
+
+        for path in &self.files {
+            watcher
+                .watch(path, RecursiveMode::Recursive)
+                .with_context(|| format!("failed to watch {}", path.display()))?;
+        }
+
+        // Watch the directories instead of the config files themselves, so
+        // configs created after startup are picked up as well. `.swcrc` files
+        // in the input directories are already covered by the watchers above.
+        let cwd = std::env::current_dir()?;
+        let mut config_dirs = vec![cwd.clone()];
+        if let Some(config_file) = &self.config_file {
+            if let Some(parent) = config_file.absolutize_from(&cwd)?.parent() {
+                config_dirs.push(parent.to_path_buf());
+            }
+        }
+        config_dirs.dedup();
+
+        for dir in &config_dirs {
+            watcher
+                .watch(dir, RecursiveMode::NonRecursive)
+                .with_context(|| format!("failed to watch {}", dir.display()))?;
+        }
+
+        if let Err(err) = self.execute_inner(&new_compiler()) {
+            eprintln!("{:?}", err);
+        }
+        eprintln!("Watching for file changes...");
+
+        while let Ok(event) = rx.recv() {
+            let changed = debounce_events(event, &rx, WATCH_DEBOUNCE);
+
+            if changed.is_empty() {
+                continue;
+            }
+
+            if let Err(err) = self.recompile(&changed) {
+                eprintln!("{:?}", err);
+            }
+        }
+
+        Ok(())
+    }
 }
 
 #[swc_trace]
@@ -560,7 +814,11 @@ impl super::CommandRunner for CompileOptions {
             None
         };
 
-        let ret = self.execute_inner();
+        let ret = if self.watch {
+            self.watch()
+        } else {
+            self.execute_inner(&COMPILER)
+        };
 
         if let Some(guard) = guard {
             guard.flush();
@@ -570,3 +828,71 @@ impl super::CommandRunner for CompileOptions {
         ret
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::{fs, sync::mpsc, time::Duration};
+
+    use assert_fs::TempDir;
+    use clap::Parser;
+    use notify::{
+        event::{AccessKind, ModifyKind},
+        Event, EventKind,
+    };
+
+    use super::{debounce_events, CompileOptions};
+
+    fn modify_event(path: &std::path::Path) -> notify::Result<Event> {
+        Ok(Event::new(EventKind::Modify(ModifyKind::Any)).add_path(path.to_path_buf()))
+    }
+
+    #[test]
+    fn debounce_merges_bursts() {
+        let (tx, rx) = mpsc::channel();
+        let a = std::path::PathBuf::from("/a.js");
+        let b = std::path::PathBuf::from("/b.js");
+
+        tx.send(modify_event(&b)).unwrap();
+        tx.send(modify_event(&a)).unwrap();
+        tx.send(Ok(
+            Event::new(EventKind::Access(AccessKind::Any)).add_path("/c.js".into())
+        ))
+        .unwrap();
+
+        let changed = debounce_events(modify_event(&a), &rx, Duration::from_millis(10));
+        assert_eq!(changed, vec![a.clone(), b]);
+
+        // Events sent after the burst are not merged into it.
+        tx.send(modify_event(&a)).unwrap();
+        let changed = debounce_events(modify_event(&a), &rx, Duration::from_millis(10));
+        assert_eq!(changed, vec![a]);
+    }
+
+    #[test]
+    fn recompile_changed_input() {
+        let sandbox = TempDir::new().unwrap();
+        let input = sandbox.path().join("input.js");
+        let other = sandbox.path().join("other.js");
+        let out_file = sandbox.path().join("out.js");
+        fs::write(&input, "export const a = 1;").unwrap();
+
+        let options = CompileOptions::try_parse_from([
+            "compile".as_ref(),
+            "--out-file".as_ref(),
+            out_file.as_os_str(),
+            input.as_os_str(),
+        ])
+        .unwrap();
+
+        // Changes to files which are not inputs are ignored.
+        options.recompile(&[other]).unwrap();
+        assert!(!out_file.exists());
+
+        options.recompile(&[input.clone()]).unwrap();
+        assert!(fs::read_to_string(&out_file).unwrap().contains("a = 1;"));
+
+        fs::write(&input, "export const b = 2;").unwrap();
+        options.recompile(&[input]).unwrap();
+        assert!(fs::read_to_string(&out_file).unwrap().contains("b = 2;"));
+    }
+}
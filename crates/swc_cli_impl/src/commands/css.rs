@@ -0,0 +1,237 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+
+use anyhow::Context;
+use clap::{Parser, Subcommand};
+use rayon::prelude::*;
+use swc_core::{
+    base::HandlerOpts,
+    common::{
+        errors::{ColorConfig, Diagnostic, Handler, Level, HANDLER},
+        sync::Lazy,
+        FilePathMapping, SourceMap, GLOBALS,
+    },
+    css::{
+        ast::Stylesheet,
+        lints::{get_rules, LintConfig, LintParams, LintRule},
+        parser::parse_file,
+    },
+    trace_macro::swc_trace,
+};
+use swc_error_reporters::{
+    handler::{to_pretty_handler, ThreadSafetyDiagnostics},
+    ErrorEmitter, ToPrettyDiagnostic,
+};
+
+use super::lint::collect_files;
+use crate::util::trace::init_trace;
+
+/// Commandline utilities for css files.
+#[derive(Subcommand)]
+pub enum CssSubcommand {
+    /// Lint css files using stylelint-compatible rules.
+    Lint(CssLintOptions),
+}
+
+/// Configuration option for linting css files.
+#[derive(Parser)]
+pub struct CssLintOptions {
+    /// Path to a stylelint config file to use. If not specified, the nearest
+    /// `.stylelintrc`, `.stylelintrc.json` or `stylelint` field of
+    /// package.json is used.
+    #[clap(long)]
+    config_file: Option<PathBuf>,
+
+    /// List of glob paths to not lint.
+    #[clap(long)]
+    ignore: Option<String>,
+
+    /// Files, directories or glob patterns to lint
+    #[clap(required = true)]
+    files: Vec<String>,
+
+    /// Enable experimental trace profiling
+    /// generates trace compatible with trace event format.
+    #[clap(group = "experimental_trace", long)]
+    experimental_trace: bool,
+
+    /// Set file name for the trace output. If not specified,
+    /// `trace-{unix epoch time}.json` will be used by default.
+    #[clap(group = "experimental_trace", long)]
+    trace_out_file: Option<String>,
+}
+
+static CM: Lazy<Arc<SourceMap>> = Lazy::new(|| Arc::new(SourceMap::new(FilePathMapping::empty())));
+
+/// Search the config of stylelint from `dir` and its ancestors.
+fn find_stylelint_config(dir: &Path) -> anyhow::Result<Option<serde_json::Value>> {
+    for dir in dir.ancestors() {
+        for name in [".stylelintrc", ".stylelintrc.json"] {
+            let path = dir.join(name);
+            if path.is_file() {
+                return read_json(&path).map(Some);
+            }
+        }
+
+        let package_json = dir.join("package.json");
+        if package_json.is_file() {
+            if let Some(config) = read_json(&package_json)?.get_mut("stylelint") {
+                return Ok(Some(config.take()));
+            }
+        }
+    }
+
+    Ok(None)
+}
+
+fn read_json(path: &Path) -> anyhow::Result<serde_json::Value> {
+    let content = fs::read_to_string(path)
+        .with_context(|| format!("Failed to read config file {}", path.display()))?;
+
+    serde_json::from_str(&content)
+        .with_context(|| format!("Failed to parse config file {}", path.display()))
+}
+
+#[swc_trace]
+impl CssLintOptions {
+    fn load_config(&self) -> anyhow::Result<LintConfig> {
+        let config = match &self.config_file {
+            Some(path) => Some(read_json(path)?),
+            None => find_stylelint_config(&std::env::current_dir()?)?,
+        };
+
+        match config {
+            Some(config) => {
+                LintConfig::from_stylelint(config).context("Failed to load stylelint config")
+            }
+            None => Ok(Default::default()),
+        }
+    }
+
+    /// Parses a css file and runs all configured lint rules on it.
+    fn lint_file(
+        &self,
+        lint_config: &LintConfig,
+        file_path: &Path,
+    ) -> anyhow::Result<Vec<Diagnostic>> {
+        let fm = CM
+            .load_file(file_path)
+            .with_context(|| format!("Failed to open file {}", file_path.display()))?;
+
+        let mut diagnostics = ThreadSafetyDiagnostics::default();
+        let handler = Handler::with_emitter(
+            true,
+            false,
+            Box::new(ErrorEmitter {
+                diagnostics: diagnostics.clone(),
+                cm: CM.clone(),
+                opts: HandlerOpts {
+                    color: ColorConfig::Auto,
+                    skip_filename: false,
+                },
+            }),
+        );
+
+        let mut errors = Vec::new();
+        let stylesheet = parse_file::<Stylesheet>(&fm, None, Default::default(), &mut errors);
+
+        for err in errors {
+            err.to_diagnostics(&handler).emit();
+        }
+
+        match stylesheet {
+            Ok(stylesheet) => {
+                let mut rules = get_rules(&LintParams { lint_config })
+                    .context("Failed to create lint rules")?;
+
+                HANDLER.set(&handler, || {
+                    rules.lint_stylesheet(&stylesheet);
+                });
+            }
+            Err(err) => {
+                err.to_diagnostics(&handler).emit();
+            }
+        }
+
+        Ok(diagnostics.take())
+    }
+
+    fn execute_inner(&self) -> anyhow::Result<()> {
+        let lint_config = self.load_config()?;
+        let files = collect_files(&self.files, &["css".to_string()], self.ignore.as_deref())?;
+
+        let results = files
+            .par_iter()
+            .map(|file_path| {
+                let result = GLOBALS.set(&Default::default(), || {
+                    self.lint_file(&lint_config, file_path)
+                });
+
+                (file_path, result)
+            })
+            .collect::<Vec<_>>();
+
+        let report_handler = to_pretty_handler(ColorConfig::Auto);
+        let mut errors = 0;
+        let mut warnings = 0;
+        let mut diagnostics = Vec::new();
+
+        // A file which cannot be read should not prevent the other files from
+        // being linted.
+        for (file_path, result) in results {
+            match result {
+                Ok(file_diagnostics) => diagnostics.extend(file_diagnostics),
+                Err(err) => {
+                    errors += 1;
+                    eprintln!("Failed to lint {}: {:?}", file_path.display(), err);
+                }
+            }
+        }
+
+        for diagnostic in &diagnostics {
+            match diagnostic.level {
+                Level::Bug | Level::Fatal | Level::PhaseFatal | Level::Error => errors += 1,
+                Level::Warning => warnings += 1,
+                _ => {}
+            }
+
+            eprintln!(
+                "{}",
+                diagnostic.to_pretty_string(&CM, false, &report_handler)
+            );
+        }
+
+        if errors > 0 || warnings > 0 {
+            eprintln!("Found {} error(s) and {} warning(s)", errors, warnings);
+        }
+
+        if errors > 0 {
+            anyhow::bail!("Lint failed with {} error(s)", errors);
+        }
+
+        Ok(())
+    }
+}
+
+#[swc_trace]
+impl super::CommandRunner for CssLintOptions {
+    fn execute(&self) -> anyhow::Result<()> {
+        let guard = if self.experimental_trace {
+            init_trace(&self.trace_out_file)
+        } else {
+            None
+        };
+
+        let ret = self.execute_inner();
+
+        if let Some(guard) = guard {
+            guard.flush();
+            drop(guard);
+        }
+
+        ret
+    }
+}
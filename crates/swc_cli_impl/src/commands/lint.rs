@@ -1,12 +1,318 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+
+use anyhow::Context;
 use clap::Parser;
-use swc_core::trace_macro::swc_trace;
+use glob::glob;
+use rayon::prelude::*;
+use swc_core::{
+    base::{
+        config::{Config, ConfigFile, Options},
+        Compiler, HandlerOpts,
+    },
+    common::{
+        errors::{ColorConfig, Diagnostic, Handler, Level, HANDLER},
+        sync::{Lazy, Lrc},
+        FileName, FilePathMapping, Mark, SourceFile, SourceMap, SyntaxContext, GLOBALS,
+    },
+    ecma::{
+        lints::{
+            fix::fix,
+            rules::{self, lint_pass, LintParams},
+        },
+        transforms::base::resolver,
+        visit::VisitMutWith,
+    },
+    trace_macro::swc_trace,
+};
+use swc_error_reporters::{
+    handler::{to_pretty_handler, ThreadSafetyDiagnostics},
+    ErrorEmitter, ToPrettyDiagnostic,
+};
+
+use super::compile::{get_files_list, DEFAULT_EXTENSIONS};
+use crate::util::trace::init_trace;
 
+/// Configuration option for linting files.
 #[derive(Parser)]
-pub struct LintOptions {}
+pub struct LintOptions {
+    /// Path to a .swcrc file to use
+    #[clap(long)]
+    config_file: Option<PathBuf>,
+
+    /// The name of the 'env' to use when loading configs. Defaults to the
+    /// value of SWC_ENV, or else NODE_ENV, or else development.
+    #[clap(long)]
+    env_name: Option<String>,
+
+    /// List of glob paths to not lint.
+    #[clap(long)]
+    ignore: Option<String>,
+
+    /// Specify specific file extensions to lint.
+    #[clap(long)]
+    extensions: Option<Vec<String>>,
+
+    /// Automatically fix problems, if the rules support it.
+    #[clap(long)]
+    fix: bool,
+
+    /// Files, directories or glob patterns to lint
+    #[clap(required = true)]
+    files: Vec<String>,
+
+    /// Enable experimental trace profiling
+    /// generates trace compatible with trace event format.
+    #[clap(group = "experimental_trace", long)]
+    experimental_trace: bool,
+
+    /// Set file name for the trace output. If not specified,
+    /// `trace-{unix epoch time}.json` will be used by default.
+    #[clap(group = "experimental_trace", long)]
+    trace_out_file: Option<String>,
+}
+
+static COMPILER: Lazy<Arc<Compiler>> = Lazy::new(|| {
+    let cm = Arc::new(SourceMap::new(FilePathMapping::empty()));
+
+    Arc::new(Compiler::new(cm))
+});
+
+/// Expands glob patterns and directories into the list of files to lint.
+pub(super) fn collect_files(
+    inputs: &[String],
+    extensions: &[String],
+    ignore: Option<&str>,
+) -> anyhow::Result<Vec<PathBuf>> {
+    let mut files = Vec::new();
+
+    for input in inputs {
+        let paths: Vec<PathBuf> = if Path::new(input).exists() {
+            vec![PathBuf::from(input)]
+        } else {
+            glob(input)
+                .with_context(|| format!("invalid glob pattern `{}`", input))?
+                .filter_map(|p| p.ok())
+                .collect()
+        };
+
+        for path in paths {
+            files.extend(get_files_list(&[path], extensions, ignore, false)?);
+        }
+    }
+
+    files.sort();
+    files.dedup();
+
+    if files.is_empty() {
+        anyhow::bail!("No files to lint");
+    }
+
+    Ok(files)
+}
+
+#[swc_trace]
+impl LintOptions {
+    fn collect_files(&self) -> anyhow::Result<Vec<PathBuf>> {
+        let included_extensions = if let Some(extensions) = &self.extensions {
+            extensions.clone()
+        } else {
+            DEFAULT_EXTENSIONS.iter().map(|v| v.to_string()).collect()
+        };
+
+        collect_files(&self.files, &included_extensions, self.ignore.as_deref())
+    }
+
+    fn build_options(&self, file_path: &Path) -> Options {
+        let mut options = Options {
+            config_file: self.config_file.as_ref().map(|config_file_path| {
+                ConfigFile::Str(config_file_path.to_string_lossy().to_string())
+            }),
+            filename: file_path.to_string_lossy().to_string(),
+            swcrc: true,
+            ..Options::default()
+        };
+
+        if let Some(env_name) = &self.env_name {
+            options.env_name = env_name.to_string();
+        }
+
+        options
+    }
+
+    /// Parses a file and runs all configured lint rules on it. If `--fix` is
+    /// passed, fixes are applied to the file.
+    ///
+    /// Returns [None] if the file is excluded by the configuration.
+    fn lint_file(
+        &self,
+        compiler: &Compiler,
+        file_path: &Path,
+    ) -> anyhow::Result<Option<Vec<Diagnostic>>> {
+        let options = self.build_options(file_path);
+        let config = match compiler.read_config(&options, &FileName::Real(file_path.into()))? {
+            Some(config) => config,
+            None => return Ok(None),
+        };
+
+        let fm = compiler
+            .cm
+            .load_file(file_path)
+            .with_context(|| format!("Failed to open file {}", file_path.display()))?;
+
+        if !self.fix {
+            return Ok(Some(lint_source(compiler, &config, fm)));
+        }
+
+        let output = fix(fm.src.to_string(), |code| {
+            let fm = compiler
+                .cm
+                .new_source_file(FileName::Real(file_path.into()).into(), code);
+            let diagnostics = lint_source(compiler, &config, fm.clone());
+
+            (fm, diagnostics)
+        });
+
+        if output.fixed > 0 {
+            fs::write(file_path, &output.code)
+                .with_context(|| format!("Failed to write file {}", file_path.display()))?;
+        }
+
+        Ok(Some(output.diagnostics))
+    }
+
+    fn execute_inner(&self) -> anyhow::Result<()> {
+        let compiler = COMPILER.clone();
+        let files = self.collect_files()?;
+
+        let results = files
+            .par_iter()
+            .map(|file_path| {
+                let result =
+                    GLOBALS.set(&Default::default(), || self.lint_file(&compiler, file_path));
+
+                (file_path, result)
+            })
+            .collect::<Vec<_>>();
+
+        let report_handler = to_pretty_handler(ColorConfig::Auto);
+        let mut errors = 0;
+        let mut warnings = 0;
+        let mut diagnostics = Vec::new();
+
+        // A file which cannot be read or written should not prevent the other
+        // files from being linted.
+        for (file_path, result) in results {
+            match result {
+                Ok(Some(file_diagnostics)) => diagnostics.extend(file_diagnostics),
+                Ok(None) => {}
+                Err(err) => {
+                    errors += 1;
+                    eprintln!("Failed to lint {}: {:?}", file_path.display(), err);
+                }
+            }
+        }
+
+        for diagnostic in &diagnostics {
+            match diagnostic.level {
+                Level::Bug | Level::Fatal | Level::PhaseFatal | Level::Error => errors += 1,
+                Level::Warning => warnings += 1,
+                _ => {}
+            }
+
+            eprintln!(
+                "{}",
+                diagnostic.to_pretty_string(&compiler.cm, false, &report_handler)
+            );
+        }
+
+        if errors > 0 || warnings > 0 {
+            eprintln!("Found {} error(s) and {} warning(s)", errors, warnings);
+        }
+
+        if errors > 0 {
+            anyhow::bail!("Lint failed with {} error(s)", errors);
+        }
+
+        Ok(())
+    }
+}
+
+/// Runs all lint rules configured by `config` on `fm`.
+fn lint_source(compiler: &Compiler, config: &Config, fm: Lrc<SourceFile>) -> Vec<Diagnostic> {
+    let mut diagnostics = ThreadSafetyDiagnostics::default();
+    let handler = Handler::with_emitter(
+        true,
+        false,
+        Box::new(ErrorEmitter {
+            diagnostics: diagnostics.clone(),
+            cm: compiler.cm.clone(),
+            opts: HandlerOpts {
+                color: ColorConfig::Auto,
+                skip_filename: false,
+            },
+        }),
+    );
+
+    let syntax = config.jsc.syntax.unwrap_or_default();
+    let es_version = config.jsc.target.unwrap_or_default();
+
+    HANDLER.set(&handler, || {
+        let program = compiler.parse_js(
+            fm,
+            &handler,
+            es_version,
+            syntax,
+            config.is_module.unwrap_or_default(),
+            None,
+        );
+
+        // Parse errors are already reported to the handler.
+        if let Ok(mut program) = program {
+            let unresolved_mark = Mark::new();
+            let top_level_mark = Mark::new();
+
+            program.visit_mut_with(&mut resolver(
+                unresolved_mark,
+                top_level_mark,
+                syntax.typescript(),
+            ));
+
+            let rules = rules::all(LintParams {
+                program: &program,
+                lint_config: &config.jsc.lints,
+                unresolved_ctxt: SyntaxContext::empty().apply_mark(unresolved_mark),
+                top_level_ctxt: SyntaxContext::empty().apply_mark(top_level_mark),
+                es_version,
+                source_map: compiler.cm.clone(),
+            });
+
+            program.mutate(lint_pass(rules));
+        }
+    });
+
+    diagnostics.take()
+}
 
 #[swc_trace]
 impl super::CommandRunner for LintOptions {
     fn execute(&self) -> anyhow::Result<()> {
-        unimplemented!("Lint command is not yet implemented")
+        let guard = if self.experimental_trace {
+            init_trace(&self.trace_out_file)
+        } else {
+            None
+        };
+
+        let ret = self.execute_inner();
+
+        if let Some(guard) = guard {
+            guard.flush();
+            drop(guard);
+        }
+
+        ret
     }
 }
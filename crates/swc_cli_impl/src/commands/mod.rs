@@ -2,12 +2,14 @@ use clap::{Parser, Subcommand};
 
 mod bundle;
 mod compile;
+mod css;
 mod lint;
 mod minify;
 mod plugin;
 
 pub use bundle::*;
 pub use compile::*;
+pub use css::CssSubcommand;
 pub use lint::*;
 pub use minify::*;
 pub use plugin::PluginSubcommand;
@@ -23,6 +25,9 @@ pub enum Command {
     Bundle(BundleOptions),
     Minify(MinifyOptions),
     Lint(LintOptions),
+    /// Commandline utilities for css files.
+    #[clap(subcommand)]
+    Css(CssSubcommand),
 }
 
 #[derive(Parser)]
@@ -1,5 +1,5 @@
 use clap::Parser;
-use commands::{Command, CommandRunner, PluginSubcommand, SwcCliOptions};
+use commands::{Command, CommandRunner, CssSubcommand, PluginSubcommand, SwcCliOptions};
 
 mod commands;
 mod util;
@@ -13,5 +13,6 @@ pub fn run() -> anyhow::Result<()> {
         Command::Minify(options) => options.execute(),
         Command::Bundle(options) => options.execute(),
         Command::Lint(options) => options.execute(),
+        Command::Css(CssSubcommand::Lint(options)) => options.execute(),
     }
 }
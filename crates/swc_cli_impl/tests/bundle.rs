@@ -0,0 +1,263 @@
+use std::{
+    fs,
+    io::Write,
+    process::{Command, Stdio},
+};
+
+use anyhow::{Context, Result};
+use assert_cmd::prelude::*;
+use assert_fs::TempDir;
+
+fn cli() -> Result<Command> {
+    let mut cmd = Command::cargo_bin("swc").context("Failed to get swc binary")?;
+    cmd.stderr(Stdio::inherit());
+    Ok(cmd)
+}
+
+#[test]
+fn bundle_named_entries() -> Result<()> {
+    let sandbox = TempDir::new()?;
+    fs::create_dir(sandbox.path().join("src"))?;
+    fs::write(
+        sandbox.path().join("src/math.ts"),
+        "export function add(a: number, b: number) { return a + b; }",
+    )?;
+    fs::write(
+        sandbox.path().join("src/index.ts"),
+        "import { add } from './math';\nimport fs from 'fs';\nconsole.log(add(1, 2), fs);",
+    )?;
+    fs::write(
+        sandbox.path().join("src/other.ts"),
+        "import { add } from './math';\nexport const value = add(3, 4);",
+    )?;
+
+    let mut cmd = cli()?;
+    cmd.current_dir(&sandbox)
+        .arg("bundle")
+        .arg("--target")
+        .arg("node")
+        .arg("--out-dir")
+        .arg("out")
+        .arg("src/index.ts")
+        .arg("main=src/other.ts");
+
+    cmd.assert().success();
+
+    let index = fs::read_to_string(sandbox.path().join("out/index.js"))?;
+    assert!(index.contains("function add"), "{}", index);
+    assert!(index.contains("from 'fs'"), "{}", index);
+    assert!(
+        index.contains("//# sourceMappingURL=index.js.map"),
+        "{}",
+        index
+    );
+
+    let map = fs::read_to_string(sandbox.path().join("out/index.js.map"))?;
+    assert!(map.contains("math.ts"), "{}", map);
+
+    let main = fs::read_to_string(sandbox.path().join("out/main.js"))?;
+    assert!(main.contains("export {"), "{}", main);
+
+    Ok(())
+}
+
+#[test]
+fn bundle_iife() -> Result<()> {
+    let sandbox = TempDir::new()?;
+    fs::write(sandbox.path().join("a.js"), "export const a = 1;")?;
+    fs::write(
+        sandbox.path().join("index.js"),
+        "import { a } from './a';\nexport const b = a + 1;",
+    )?;
+
+    let mut cmd = cli()?;
+    cmd.current_dir(&sandbox)
+        .arg("bundle")
+        .arg("--format")
+        .arg("iife")
+        .arg("index.js");
+
+    cmd.assert().success();
+
+    let output = fs::read_to_string(sandbox.path().join("dist/index.js"))?;
+    assert!(output.starts_with("(function()"), "{}", output);
+
+    Ok(())
+}
+
+#[test]
+fn bundle_cjs() -> Result<()> {
+    let sandbox = TempDir::new()?;
+    fs::write(sandbox.path().join("a.js"), "export const a = 1;")?;
+    fs::write(
+        sandbox.path().join("index.js"),
+        "import { a } from './a';\nimport { join } from 'path';\nexport const b = join(String(a));",
+    )?;
+
+    let mut cmd = cli()?;
+    cmd.current_dir(&sandbox)
+        .arg("bundle")
+        .arg("--target")
+        .arg("node")
+        .arg("--format")
+        .arg("cjs")
+        .arg("index.js");
+
+    cmd.assert().success();
+
+    let output = fs::read_to_string(sandbox.path().join("dist/index.js"))?;
+    assert!(output.contains("require('path')"), "{}", output);
+    assert!(
+        output.contains("Object.defineProperty(exports, \"b\""),
+        "{}",
+        output
+    );
+    assert!(!output.contains("import "), "{}", output);
+
+    Ok(())
+}
+
+#[test]
+fn bundle_umd_requires_name() -> Result<()> {
+    let sandbox = TempDir::new()?;
+    fs::write(sandbox.path().join("index.js"), "export const a = 1;")?;
+
+    let mut cmd = cli()?;
+    cmd.current_dir(&sandbox)
+        .arg("bundle")
+        .arg("--format")
+        .arg("umd")
+        .arg("index.js");
+
+    cmd.assert().failure();
+
+    let mut cmd = cli()?;
+    cmd.current_dir(&sandbox)
+        .arg("bundle")
+        .arg("--format")
+        .arg("umd")
+        .arg("--umd-name")
+        .arg("myLib")
+        .arg("--umd-global")
+        .arg("react=React")
+        .arg("index.js");
+
+    cmd.assert().success();
+
+    let output = fs::read_to_string(sandbox.path().join("dist/index.js"))?;
+    assert!(output.contains("global[\"myLib\"]"), "{}", output);
+    assert!(output.contains("\"react\": \"React\""), "{}", output);
+
+    Ok(())
+}
+
+#[test]
+fn bundle_package_exports() -> Result<()> {
+    let sandbox = TempDir::new()?;
+    let pkg = sandbox.path().join("node_modules/pkg");
+    fs::create_dir_all(&pkg)?;
+    fs::write(
+        pkg.join("package.json"),
+        r#"{ "name": "pkg", "main": "./main.js", "exports": { "custom": "./custom.js", "import": "./esm.js" } }"#,
+    )?;
+    fs::write(pkg.join("main.js"), "export const from = 'main';")?;
+    fs::write(pkg.join("esm.js"), "export const from = 'esm';")?;
+    fs::write(pkg.join("custom.js"), "export const from = 'custom';")?;
+    fs::write(
+        sandbox.path().join("index.js"),
+        "import { from } from 'pkg';\nconsole.log(from);",
+    )?;
+
+    let mut cmd = cli()?;
+    cmd.current_dir(&sandbox).arg("bundle").arg("index.js");
+
+    cmd.assert().success();
+
+    let output = fs::read_to_string(sandbox.path().join("dist/index.js"))?;
+    assert!(output.contains("'esm'"), "{}", output);
+
+    let mut cmd = cli()?;
+    cmd.current_dir(&sandbox)
+        .arg("bundle")
+        .arg("--condition")
+        .arg("custom")
+        .arg("index.js");
+
+    cmd.assert().success();
+
+    let output = fs::read_to_string(sandbox.path().join("dist/index.js"))?;
+    assert!(output.contains("'custom'"), "{}", output);
+
+    Ok(())
+}
+
+#[test]
+fn bundle_package_exports_require() -> Result<()> {
+    let sandbox = TempDir::new()?;
+    let pkg = sandbox.path().join("node_modules/pkg");
+    fs::create_dir_all(&pkg)?;
+    fs::write(
+        pkg.join("package.json"),
+        r#"{ "name": "pkg", "exports": { "import": "./esm.js", "require": "./cjs.js" } }"#,
+    )?;
+    fs::write(pkg.join("esm.js"), "export const from = 'esm';")?;
+    fs::write(pkg.join("cjs.js"), "exports.from = 'cjs';")?;
+    fs::write(
+        sandbox.path().join("index.js"),
+        "const { from } = require('pkg');\nconsole.log(from);",
+    )?;
+
+    let mut cmd = cli()?;
+    cmd.current_dir(&sandbox).arg("bundle").arg("index.js");
+
+    cmd.assert().success();
+
+    let output = fs::read_to_string(sandbox.path().join("dist/index.js"))?;
+    assert!(output.contains("'cjs'"), "{}", output);
+    assert!(!output.contains("'esm'"), "{}", output);
+
+    Ok(())
+}
+
+#[test]
+fn bundle_yarn_pnp() -> Result<()> {
+    let sandbox = TempDir::new()?;
+    fs::create_dir_all(sandbox.path().join(".yarn/cache"))?;
+
+    let archive = fs::File::create(sandbox.path().join(".yarn/cache/math-npm-1.0.0-abc.zip"))?;
+    let mut zip = zip::ZipWriter::new(archive);
+    let options =
+        zip::write::SimpleFileOptions::default().compression_method(zip::CompressionMethod::Stored);
+    zip.start_file("node_modules/math/package.json", options)?;
+    zip.write_all(br#"{ "name": "math", "main": "./index.js" }"#)?;
+    zip.start_file("node_modules/math/index.js", options)?;
+    zip.write_all(b"export function add(a, b) { return a + b; }")?;
+    zip.finish()?;
+
+    fs::write(
+        sandbox.path().join(".pnp.data.json"),
+        r#"{
+  "enableTopLevelFallback": false,
+  "fallbackExclusionList": [],
+  "fallbackPool": [],
+  "packageRegistryData": [
+    [null, [[null, { "packageLocation": "./", "packageDependencies": [["math", "npm:1.0.0"]] }]]],
+    ["math", [["npm:1.0.0", { "packageLocation": "./.yarn/cache/math-npm-1.0.0-abc.zip/node_modules/math/", "packageDependencies": [] }]]]
+  ]
+}"#,
+    )?;
+    fs::write(
+        sandbox.path().join("index.js"),
+        "import { add } from 'math';\nconsole.log(add(1, 2));",
+    )?;
+
+    let mut cmd = cli()?;
+    cmd.current_dir(&sandbox).arg("bundle").arg("index.js");
+
+    cmd.assert().success();
+
+    let output = fs::read_to_string(sandbox.path().join("dist/index.js"))?;
+    assert!(output.contains("function add"), "{}", output);
+
+    Ok(())
+}
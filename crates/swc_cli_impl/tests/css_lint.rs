@@ -0,0 +1,141 @@
+use std::{
+    fs,
+    process::{Command, Stdio},
+};
+
+use anyhow::{Context, Result};
+use assert_cmd::prelude::*;
+use assert_fs::TempDir;
+
+fn cli() -> Result<Command> {
+    let mut cmd = Command::cargo_bin("swc").context("Failed to get swc binary")?;
+    cmd.stderr(Stdio::inherit());
+    Ok(cmd)
+}
+
+fn write_stylelintrc(sandbox: &TempDir, severity: &str) -> Result<()> {
+    fs::write(
+        sandbox.path().join(".stylelintrc.json"),
+        format!(
+            r#"{{ "defaultSeverity": "{}", "rules": {{ "block-no-empty": true, "color-hex-length": "short" }} }}"#,
+            severity
+        ),
+    )?;
+    Ok(())
+}
+
+#[test]
+fn css_lint_error_fails() -> Result<()> {
+    let sandbox = TempDir::new()?;
+    write_stylelintrc(&sandbox, "error")?;
+    fs::create_dir(sandbox.path().join("src"))?;
+    fs::write(sandbox.path().join("src/a.css"), "a {}")?;
+    fs::write(sandbox.path().join("src/b.css"), "a { color: red; }")?;
+
+    let mut cmd = cli()?;
+    cmd.current_dir(&sandbox)
+        .arg("css")
+        .arg("lint")
+        .arg("src/*.css");
+
+    cmd.assert().failure();
+
+    Ok(())
+}
+
+#[test]
+fn css_lint_warning_succeeds() -> Result<()> {
+    let sandbox = TempDir::new()?;
+    write_stylelintrc(&sandbox, "warning")?;
+    fs::write(sandbox.path().join("index.css"), "a { color: #ffffff; }")?;
+
+    let mut cmd = cli()?;
+    cmd.current_dir(&sandbox)
+        .arg("css")
+        .arg("lint")
+        .arg("index.css");
+
+    cmd.assert().success();
+
+    Ok(())
+}
+
+#[test]
+fn css_lint_clean_succeeds() -> Result<()> {
+    let sandbox = TempDir::new()?;
+    write_stylelintrc(&sandbox, "error")?;
+    fs::write(sandbox.path().join("index.css"), "a { color: #fff; }")?;
+
+    let mut cmd = cli()?;
+    cmd.current_dir(&sandbox)
+        .arg("css")
+        .arg("lint")
+        .arg(sandbox.path());
+
+    cmd.assert().success();
+
+    Ok(())
+}
+
+#[test]
+fn css_lint_package_json_config() -> Result<()> {
+    let sandbox = TempDir::new()?;
+    fs::write(
+        sandbox.path().join("package.json"),
+        r#"{ "name": "app", "stylelint": { "rules": { "declaration-no-important": true } } }"#,
+    )?;
+    fs::write(
+        sandbox.path().join("index.css"),
+        "a { color: red !important; }",
+    )?;
+
+    let mut cmd = cli()?;
+    cmd.current_dir(&sandbox)
+        .arg("css")
+        .arg("lint")
+        .arg("index.css");
+
+    cmd.assert().failure();
+
+    Ok(())
+}
+
+#[test]
+fn css_lint_parse_error_fails() -> Result<()> {
+    let sandbox = TempDir::new()?;
+    fs::write(sandbox.path().join("index.css"), "a { color: red;")?;
+
+    let mut cmd = cli()?;
+    cmd.current_dir(&sandbox)
+        .arg("css")
+        .arg("lint")
+        .arg("index.css");
+
+    cmd.assert().failure();
+
+    Ok(())
+}
+
+#[test]
+fn css_lint_continues_after_unreadable_file() -> Result<()> {
+    let sandbox = TempDir::new()?;
+    write_stylelintrc(&sandbox, "error")?;
+    fs::create_dir(sandbox.path().join("src"))?;
+    fs::write(sandbox.path().join("src/a.css"), [0xff, 0xfe, 0xfd])?;
+    fs::write(sandbox.path().join("src/b.css"), "a {}")?;
+
+    let mut cmd = Command::cargo_bin("swc")?;
+    cmd.current_dir(&sandbox)
+        .arg("css")
+        .arg("lint")
+        .arg("src/*.css");
+
+    let output = cmd.assert().failure().get_output().stderr.clone();
+    let stderr = String::from_utf8(output)?;
+
+    assert!(stderr.contains("Failed to lint"), "{}", stderr);
+    assert!(stderr.contains("b.css"), "{}", stderr);
+    assert!(stderr.contains("Found 2 error(s)"), "{}", stderr);
+
+    Ok(())
+}
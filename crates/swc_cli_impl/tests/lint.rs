@@ -0,0 +1,119 @@
+use std::{
+    fs,
+    process::{Command, Stdio},
+};
+
+use anyhow::{Context, Result};
+use assert_cmd::prelude::*;
+use assert_fs::TempDir;
+
+fn cli() -> Result<Command> {
+    let mut cmd = Command::cargo_bin("swc").context("Failed to get swc binary")?;
+    cmd.stderr(Stdio::inherit());
+    Ok(cmd)
+}
+
+fn write_swcrc(sandbox: &TempDir, level: &str) -> Result<()> {
+    fs::write(
+        sandbox.path().join(".swcrc"),
+        format!(
+            r#"{{ "jsc": {{ "lints": {{ "noDebugger": ["{}"] }} }} }}"#,
+            level
+        ),
+    )?;
+    Ok(())
+}
+
+#[test]
+fn lint_error_fails() -> Result<()> {
+    let sandbox = TempDir::new()?;
+    write_swcrc(&sandbox, "error")?;
+    fs::create_dir(sandbox.path().join("src"))?;
+    fs::write(sandbox.path().join("src/a.js"), "debugger;")?;
+    fs::write(sandbox.path().join("src/b.js"), "console.log(1);")?;
+
+    let mut cmd = cli()?;
+    cmd.current_dir(&sandbox).arg("lint").arg("src/*.js");
+
+    cmd.assert().failure();
+
+    Ok(())
+}
+
+#[test]
+fn lint_warning_succeeds() -> Result<()> {
+    let sandbox = TempDir::new()?;
+    write_swcrc(&sandbox, "warning")?;
+    fs::write(sandbox.path().join("index.js"), "debugger;")?;
+
+    let mut cmd = cli()?;
+    cmd.current_dir(&sandbox).arg("lint").arg("index.js");
+
+    cmd.assert().success();
+
+    Ok(())
+}
+
+#[test]
+fn lint_clean_succeeds() -> Result<()> {
+    let sandbox = TempDir::new()?;
+    write_swcrc(&sandbox, "error")?;
+    fs::write(sandbox.path().join("index.js"), "console.log(1);")?;
+
+    let mut cmd = cli()?;
+    cmd.current_dir(&sandbox).arg("lint").arg(sandbox.path());
+
+    cmd.assert().success();
+
+    Ok(())
+}
+
+#[test]
+fn lint_fix() -> Result<()> {
+    let sandbox = TempDir::new()?;
+    fs::write(
+        sandbox.path().join(".swcrc"),
+        r#"{ "jsc": { "lints": { "preferConst": ["error"], "eqeqeq": ["error"], "noDebugger": ["warning"] } } }"#,
+    )?;
+    fs::write(
+        sandbox.path().join("index.js"),
+        "let a = 1;\nif (typeof a == 'number') {\n    debugger;\n}\n",
+    )?;
+
+    let mut cmd = cli()?;
+    cmd.current_dir(&sandbox)
+        .arg("lint")
+        .arg("--fix")
+        .arg("index.js");
+
+    cmd.assert().success();
+
+    let output = fs::read_to_string(sandbox.path().join("index.js"))?;
+    assert_eq!(
+        output,
+        "const a = 1;\nif (typeof a === 'number') {\n    debugger;\n}\n"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn lint_continues_after_unreadable_file() -> Result<()> {
+    let sandbox = TempDir::new()?;
+    write_swcrc(&sandbox, "error")?;
+    fs::create_dir(sandbox.path().join("src"))?;
+    fs::write(sandbox.path().join("src/a.js"), [0xff, 0xfe, 0xfd])?;
+    fs::write(sandbox.path().join("src/b.js"), "debugger;")?;
+
+    let mut cmd = Command::cargo_bin("swc")?;
+    cmd.current_dir(&sandbox).arg("lint").arg("src/*.js");
+
+    let output = cmd.assert().failure().get_output().stderr.clone();
+    let stderr = String::from_utf8(output)?;
+
+    assert!(stderr.contains("Failed to lint"), "{}", stderr);
+    assert!(stderr.contains("b.js"), "{}", stderr);
+    assert!(stderr.contains("Found 2 error(s)"), "{}", stderr);
+
+    Ok(())
+}
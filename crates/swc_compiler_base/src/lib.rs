@@ -37,6 +37,11 @@ pub struct TransformOutput {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub output: Option<String>,
 
+    /// Files emitted by transforms, like Wasm plugins, to be written next to
+    /// the output file.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub artifacts: std::vec::Vec<TransformArtifact>,
+
     pub diagnostics: std::vec::Vec<String>,
 }
 
@@ -51,9 +56,23 @@ pub struct TransformOutput {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub output: Option<String>,
 
+    /// Files emitted by transforms, like Wasm plugins, to be written next to
+    /// the output file.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub artifacts: std::vec::Vec<TransformArtifact>,
+
     pub diagnostics: std::vec::Vec<String>,
 }
 
+#[cfg_attr(feature = "node", napi_derive::napi(object))]
+#[derive(Debug, Clone, Serialize)]
+pub struct TransformArtifact {
+    /// The path of the file, relative to the directory of the output file.
+    pub path: String,
+    /// The content of the file, base64 encoded.
+    pub content: String,
+}
+
 /// This method parses a javascript / typescript file
 ///
 /// This should be called in a scope of [swc_common::GLOBALS].
@@ -193,6 +212,7 @@ where
                     None
                 },
             );
+            w.set_indent_str(codegen_config.indent.as_str());
             w.preamble(preamble).unwrap();
             let mut wr = Box::new(w) as Box<dyn WriteJs>;
 
@@ -285,6 +305,7 @@ where
         output: output
             .map(|v| serde_json::to_string(&v).context("failed to serilaize output"))
             .transpose()?,
+        artifacts: Default::default(),
         diagnostics: Default::default(),
     })
 }
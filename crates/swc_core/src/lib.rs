@@ -253,6 +253,12 @@ pub mod css {
         pub use swc_css_compat::*;
     }
 
+    #[cfg(feature = "css_lints")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "css_lints")))]
+    pub mod lints {
+        pub use swc_css_lints::*;
+    }
+
     #[cfg(feature = "css_minifier")]
     #[cfg_attr(docsrs, doc(cfg(feature = "css_minifier")))]
     pub mod minifier {
@@ -284,6 +290,40 @@ pub mod css {
     }
 }
 
+#[cfg(feature = "__html")]
+#[cfg_attr(docsrs, doc(cfg(feature = "__html")))]
+pub mod html {
+    #[cfg(feature = "html_ast")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "html_ast")))]
+    pub mod ast {
+        pub use swc_html_ast::*;
+    }
+
+    #[cfg(feature = "html_codegen")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "html_codegen")))]
+    pub mod codegen {
+        pub use swc_html_codegen::*;
+    }
+
+    #[cfg(feature = "html_minifier")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "html_minifier")))]
+    pub mod minifier {
+        pub use swc_html_minifier::*;
+    }
+
+    #[cfg(feature = "html_parser")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "html_parser")))]
+    pub mod parser {
+        pub use swc_html_parser::*;
+    }
+
+    #[cfg(feature = "html_visit")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "html_visit")))]
+    pub mod visit {
+        pub use swc_html_visit::*;
+    }
+}
+
 #[cfg(feature = "__cached")]
 #[cfg_attr(docsrs, doc(cfg(feature = "__cached")))]
 pub mod cached {
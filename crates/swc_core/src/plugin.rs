@@ -18,25 +18,47 @@
     )))
 )]
 pub use swc_plugin_macro::css_plugin_transform;
+#[cfg(any(
+    all(
+        docsrs,
+        any(
+            feature = "__common_plugin_transform",
+            feature = "__html_plugin_transform"
+        )
+    ),
+    feature = "__common_plugin_transform",
+    feature = "__html_plugin_transform",
+))]
+#[cfg_attr(
+    docsrs,
+    doc(cfg(any(
+        feature = "__common_plugin_transform",
+        feature = "__html_plugin_transform"
+    )))
+)]
+pub use swc_plugin_macro::html_plugin_transform;
 #[cfg(any(
     all(
         docsrs,
         any(
             feature = "__common_plugin_transform",
             feature = "__css_plugin_transform",
-            feature = "__ecma_plugin_transform"
+            feature = "__ecma_plugin_transform",
+            feature = "__html_plugin_transform"
         )
     ),
     feature = "__common_plugin_transform",
     feature = "__css_plugin_transform",
-    feature = "__ecma_plugin_transform"
+    feature = "__ecma_plugin_transform",
+    feature = "__html_plugin_transform"
 ))]
 #[cfg_attr(
     docsrs,
     doc(cfg(any(
         feature = "__common_plugin_transform",
         feature = "__css_plugin_transform",
-        feature = "__ecma_plugin_transform"
+        feature = "__ecma_plugin_transform",
+        feature = "__html_plugin_transform"
     )))
 )]
 pub use swc_plugin_macro::plugin_transform;
@@ -57,19 +79,22 @@ pub mod memory {
         any(
             feature = "__common_plugin_transform",
             feature = "__css_plugin_transform",
-            feature = "__ecma_plugin_transform"
+            feature = "__ecma_plugin_transform",
+            feature = "__html_plugin_transform"
         )
     ),
     feature = "__common_plugin_transform",
     feature = "__css_plugin_transform",
-    feature = "__ecma_plugin_transform"
+    feature = "__ecma_plugin_transform",
+    feature = "__html_plugin_transform"
 ))]
 #[cfg_attr(
     docsrs,
     doc(cfg(any(
         feature = "__common_plugin_transform",
         feature = "__css_plugin_transform",
-        feature = "__ecma_plugin_transform"
+        feature = "__ecma_plugin_transform",
+        feature = "__html_plugin_transform"
     )))
 )]
 pub mod errors {
@@ -83,19 +108,22 @@ pub mod errors {
         any(
             feature = "__common_plugin_transform",
             feature = "__css_plugin_transform",
-            feature = "__ecma_plugin_transform"
+            feature = "__ecma_plugin_transform",
+            feature = "__html_plugin_transform"
         )
     ),
     feature = "__common_plugin_transform",
     feature = "__css_plugin_transform",
-    feature = "__ecma_plugin_transform"
+    feature = "__ecma_plugin_transform",
+    feature = "__html_plugin_transform"
 ))]
 #[cfg_attr(
     docsrs,
     doc(cfg(any(
         feature = "__common_plugin_transform",
         feature = "__css_plugin_transform",
-        feature = "__ecma_plugin_transform"
+        feature = "__ecma_plugin_transform",
+        feature = "__html_plugin_transform"
     )))
 )]
 pub mod metadata {
@@ -15,3 +15,7 @@ pub extern crate swc_css_parser as parser;
 pub extern crate swc_css_prefixer as prefixer;
 pub extern crate swc_css_utils as utils;
 pub extern crate swc_css_visit as visit;
+#[cfg(feature = "plugin")]
+mod plugin;
+pub use sourcemap;
+pub mod transform;
@@ -0,0 +1,101 @@
+//! Runs Wasm plugins on stylesheets.
+
+use std::{env, path::Path, sync::Arc};
+
+use anyhow::{Context, Error};
+use parking_lot::Mutex;
+use rustc_hash::FxHashMap;
+use swc_common::{
+    plugin::metadata::TransformPluginMetadataContext,
+    sync::{Lazy, Lrc},
+    FileName, Mark, SourceFile, SourceMap,
+};
+use swc_css_ast::Stylesheet;
+use swc_plugin_runner::plugin_module_bytes::{
+    CompiledPluginModuleBytes, PluginModuleBytes, RawPluginModuleBytes,
+};
+
+use crate::transform::PluginOptions;
+
+/// Compiled modules of plugins, keyed by their paths.
+static MODULES: Lazy<Mutex<FxHashMap<String, CompiledPluginModuleBytes>>> =
+    Lazy::new(Default::default);
+
+pub(crate) fn apply(
+    cm: &Lrc<SourceMap>,
+    fm: &SourceFile,
+    stylesheet: Stylesheet,
+    plugins: &[PluginOptions],
+) -> Result<Stylesheet, Error> {
+    let filename = match &*fm.name {
+        FileName::Real(path) => path.to_str().map(String::from),
+        FileName::Custom(filename) => Some(filename.clone()),
+        _ => None,
+    };
+    let metadata_context = Arc::new(TransformPluginMetadataContext::new(
+        filename,
+        env_name(),
+        None,
+    ));
+
+    let fut = async move {
+        let mut stylesheet = stylesheet;
+
+        for plugin in plugins {
+            let name = plugin.path.to_string_lossy();
+            let module = load(&name, &plugin.path)?;
+
+            // Stylesheets have no marks, but the executor requires one.
+            let mut executor = swc_plugin_runner::create_plugin_transform_executor(
+                cm,
+                &Mark::root(),
+                &metadata_context,
+                None,
+                Box::new(module),
+                Some(plugin.config.clone()),
+                None,
+            );
+
+            stylesheet = executor
+                .transform_stylesheet(stylesheet)
+                .with_context(|| format!("failed to invoke `{name}` as css transform plugin"))?;
+        }
+
+        Ok(stylesheet)
+    };
+
+    // The Wasm runtime of plugins requires a tokio runtime.
+    if let Ok(handle) = tokio::runtime::Handle::try_current() {
+        handle.block_on(fut)
+    } else {
+        tokio::runtime::Runtime::new()
+            .context("failed to create a runtime for plugins")?
+            .block_on(fut)
+    }
+}
+
+fn load(name: &str, path: &Path) -> Result<CompiledPluginModuleBytes, Error> {
+    let mut modules = MODULES.lock();
+    if let Some(module) = modules.get(name) {
+        return Ok(module.clone());
+    }
+
+    let bytes = std::fs::read(path).with_context(|| format!("failed to read plugin `{name}`"))?;
+    let (store, module) = RawPluginModuleBytes::new(name.to_string(), bytes)
+        .compile_module()
+        .with_context(|| format!("failed to compile plugin `{name}`"))?;
+    let module = CompiledPluginModuleBytes::new(name.to_string(), module, store);
+
+    modules.insert(name.to_string(), module.clone());
+
+    Ok(module)
+}
+
+/// The `env` of the metadata passed to plugins, like `swc` does.
+fn env_name() -> String {
+    if let Ok(v) = env::var("SWC_ENV") {
+        return v;
+    }
+
+    env::var("NODE_ENV").unwrap_or_else(|_| "development".into())
+}
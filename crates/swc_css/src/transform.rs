@@ -0,0 +1,331 @@
+//! Parses, transforms and prints a stylesheet with a source map.
+
+#[cfg(feature = "modules")]
+use std::hash::{Hash, Hasher};
+#[cfg(any(feature = "modules", feature = "plugin"))]
+use std::path::PathBuf;
+
+#[cfg(feature = "modules")]
+use rustc_hash::FxHasher;
+use sourcemap::SourceMapBuilder;
+#[cfg(feature = "modules")]
+use swc_atoms::Atom;
+use swc_common::{
+    source_map::SourceMapGenConfig, sync::Lrc, BytePos, FileName, LineCol, SourceFile, SourceMap,
+};
+use swc_css_ast::Stylesheet;
+use swc_css_codegen::{
+    writer::basic::{BasicCssWriter, BasicCssWriterConfig},
+    CodeGenerator, CodegenConfig, Emit,
+};
+use swc_css_parser::{error::Error, parse_file, parser::ParserConfig};
+#[cfg(any(feature = "compat", feature = "prefixer"))]
+use swc_css_visit::VisitMutWith;
+
+#[derive(Debug, Default)]
+pub struct TransformOptions {
+    pub parser: ParserConfig,
+
+    /// Wasm plugins to run in order. They run right after parsing, so the
+    /// other transforms apply to their output.
+    #[cfg(feature = "plugin")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "plugin")))]
+    pub plugins: Vec<PluginOptions>,
+
+    /// Renames class names, ids and keyframes to be local to the file. This
+    /// runs before [TransformOptions::compat].
+    #[cfg(feature = "modules")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "modules")))]
+    pub css_modules: Option<CssModulesOptions>,
+
+    /// Lowers syntax which is not supported by the targets of the config.
+    #[cfg(feature = "compat")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "compat")))]
+    pub compat: Option<swc_css_compat::compiler::Config>,
+
+    /// Adds vendor prefixes. This runs after [TransformOptions::compat].
+    #[cfg(feature = "prefixer")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "prefixer")))]
+    pub prefixer: Option<swc_css_prefixer::options::Options>,
+
+    #[cfg(feature = "minifier")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "minifier")))]
+    pub minify: Option<swc_css_minifier::options::MinifyOptions>,
+
+    pub codegen: CodegenConfig,
+
+    /// Generates a source map if set.
+    pub source_map: Option<SourceMapOptions>,
+}
+
+#[cfg(feature = "plugin")]
+#[cfg_attr(docsrs, doc(cfg(feature = "plugin")))]
+#[derive(Debug, Clone)]
+pub struct PluginOptions {
+    /// The path to the Wasm module of the plugin, which is authored with
+    /// `#[css_plugin_transform]`. Compiled modules are cached by the path.
+    pub path: PathBuf,
+
+    /// The config of the plugin, which is passed to it as a JSON string.
+    pub config: serde_json::Value,
+}
+
+#[derive(Debug, Default)]
+pub struct SourceMapOptions {
+    /// The source map of the input, e.g. one generated by a SCSS compiler.
+    ///
+    /// If set, the output map is composed with it, so it points to the
+    /// original sources instead of the input.
+    pub input: Option<sourcemap::SourceMap>,
+
+    /// Stores the content of the input in `sourcesContent`. This has no
+    /// effect on sources of [SourceMapOptions::input].
+    pub inline_sources_content: bool,
+}
+
+#[cfg(feature = "modules")]
+#[cfg_attr(docsrs, doc(cfg(feature = "modules")))]
+#[derive(Debug, Clone)]
+pub struct CssModulesOptions {
+    /// The pattern of the renamed names. `[name]` is replaced with the name of
+    /// the file, `[local]` with the original name and `[hash]` with a hash of
+    /// the path of the file relative to [CssModulesOptions::root], or of the
+    /// content if the file is not on disk.
+    pub pattern: String,
+
+    /// The directory to hash paths relative to, e.g. the root of the project,
+    /// so that names do not depend on where the project is.
+    pub root: PathBuf,
+}
+
+#[derive(Debug)]
+pub struct TransformOutput {
+    pub code: String,
+    pub map: Option<sourcemap::SourceMap>,
+    /// Names to use from JavaScript for the local names of the stylesheet, if
+    /// [TransformOptions::css_modules] is set.
+    #[cfg(feature = "modules")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "modules")))]
+    pub css_modules: Option<swc_css_modules::TransformResult>,
+    /// Recoverable errors of the parser.
+    pub errors: Vec<Error>,
+}
+
+#[derive(Debug)]
+pub enum TransformError {
+    /// The input could not be parsed.
+    Parse(Error),
+    /// A transform failed, e.g. because the targets of
+    /// [TransformOptions::minify] could not be resolved or a plugin failed.
+    Transform(anyhow::Error),
+}
+
+impl From<Error> for TransformError {
+    fn from(err: Error) -> Self {
+        TransformError::Parse(err)
+    }
+}
+
+/// Parses `fm`, runs the transforms enabled in `options` and prints the
+/// result.
+///
+/// Nodes created by the transforms get the position of the closest original
+/// node, so every rule and declaration in the output has a mapping.
+pub fn transform(
+    cm: &Lrc<SourceMap>,
+    fm: &SourceFile,
+    options: TransformOptions,
+) -> Result<TransformOutput, TransformError> {
+    #[allow(unused_mut)]
+    let mut parser = options.parser;
+    #[cfg(feature = "modules")]
+    {
+        parser.css_modules |= options.css_modules.is_some();
+    }
+
+    let mut errors = Vec::new();
+    let mut stylesheet: Stylesheet = parse_file(fm, None, parser, &mut errors)?;
+
+    #[cfg(feature = "plugin")]
+    if !options.plugins.is_empty() {
+        stylesheet = crate::plugin::apply(cm, fm, stylesheet, &options.plugins)
+            .map_err(TransformError::Transform)?;
+    }
+
+    #[cfg(feature = "modules")]
+    let css_modules = options.css_modules.map(|css_modules| {
+        swc_css_modules::compile(&mut stylesheet, CssModulesConfig::new(css_modules, fm))
+    });
+
+    #[cfg(feature = "compat")]
+    if let Some(config) = options.compat {
+        stylesheet.visit_mut_with(&mut swc_css_compat::compiler::Compiler::new(config));
+    }
+
+    #[cfg(feature = "prefixer")]
+    if let Some(prefixer) = options.prefixer {
+        stylesheet.visit_mut_with(&mut swc_css_prefixer::prefixer(prefixer));
+    }
+
+    #[cfg(feature = "minifier")]
+    if let Some(minify) = options.minify {
+        swc_css_minifier::minify(&mut stylesheet, minify).map_err(TransformError::Transform)?;
+    }
+
+    let mut code = String::new();
+    let mut mappings = Vec::new();
+
+    if options.source_map.is_some() {
+        swc_css_utils::fill_dummy_spans(&mut stylesheet);
+    }
+
+    {
+        let wr = BasicCssWriter::new(
+            &mut code,
+            options.source_map.as_ref().map(|_| &mut mappings),
+            BasicCssWriterConfig::default(),
+        );
+        let mut gen = CodeGenerator::new(wr, options.codegen);
+
+        gen.emit(&stylesheet)
+            .expect("writing to a string should not fail");
+    }
+
+    let map = options
+        .source_map
+        .map(|source_map| build_source_map(cm, &mappings, source_map));
+
+    Ok(TransformOutput {
+        code,
+        map,
+        #[cfg(feature = "modules")]
+        css_modules,
+        errors,
+    })
+}
+
+#[cfg(feature = "modules")]
+struct CssModulesConfig {
+    pattern: String,
+    name: String,
+    hash: String,
+}
+
+#[cfg(feature = "modules")]
+impl CssModulesConfig {
+    fn new(options: CssModulesOptions, fm: &SourceFile) -> Self {
+        let name = match &*fm.name {
+            FileName::Real(path) => path
+                .file_stem()
+                .and_then(|stem| stem.to_str())
+                .map(|stem| stem.split('.').next().unwrap_or(stem))
+                .unwrap_or_default()
+                .to_string(),
+            _ => String::new(),
+        };
+
+        // Separators are normalized, so the hash is the same on all platforms.
+        let mut hasher = FxHasher::default();
+        match &*fm.name {
+            FileName::Real(path) => path
+                .strip_prefix(&options.root)
+                .unwrap_or(path)
+                .components()
+                .map(|component| component.as_os_str().to_string_lossy())
+                .collect::<Vec<_>>()
+                .join("/")
+                .hash(&mut hasher),
+            _ => fm.src.hash(&mut hasher),
+        }
+        let hash = format!("{:08x}", hasher.finish() as u32);
+
+        Self {
+            pattern: options.pattern,
+            name,
+            hash,
+        }
+    }
+}
+
+#[cfg(feature = "modules")]
+impl swc_css_modules::TransformConfig for CssModulesConfig {
+    fn new_name_for(&self, local: &Atom) -> Atom {
+        self.pattern
+            .replace("[name]", &self.name)
+            .replace("[local]", local)
+            .replace("[hash]", &self.hash)
+            .into()
+    }
+}
+
+fn build_source_map(
+    cm: &SourceMap,
+    mappings: &[(BytePos, LineCol)],
+    options: SourceMapOptions,
+) -> sourcemap::SourceMap {
+    struct Config {
+        inline_sources_content: bool,
+    }
+
+    impl SourceMapGenConfig for Config {
+        fn file_name_to_source(&self, f: &FileName) -> String {
+            f.to_string()
+        }
+
+        fn inline_sources_content(&self, _: &FileName) -> bool {
+            self.inline_sources_content
+        }
+    }
+
+    let map = cm.build_source_map_with_config(
+        mappings,
+        None,
+        Config {
+            inline_sources_content: options.inline_sources_content,
+        },
+    );
+
+    match &options.input {
+        Some(input) => compose(&map, input),
+        None => map,
+    }
+}
+
+/// Maps tokens of `map`, whose sources are the input, to the sources of
+/// `input`.
+///
+/// [sourcemap::SourceMap::adjust_mappings] is not used because it drops all
+/// but one of tokens pointing to the same position of the input, which is
+/// common as transforms copy nodes.
+fn compose(map: &sourcemap::SourceMap, input: &sourcemap::SourceMap) -> sourcemap::SourceMap {
+    let mut builder = SourceMapBuilder::new(None);
+
+    builder.set_source_root(input.get_source_root());
+
+    for token in map.tokens() {
+        let Some(orig) = input.lookup_token(token.get_src_line(), token.get_src_col()) else {
+            continue;
+        };
+
+        // `lookup_token` returns the closest token, which may be on a previous line.
+        if orig.get_dst_line() != token.get_src_line() || orig.get_source().is_none() {
+            continue;
+        }
+
+        let raw = builder.add(
+            token.get_dst_line(),
+            token.get_dst_col(),
+            orig.get_src_line(),
+            orig.get_src_col(),
+            orig.get_source(),
+            orig.get_name(),
+            false,
+        );
+
+        if builder.get_source_contents(raw.src_id).is_none() {
+            builder.set_source_contents(raw.src_id, input.get_source_contents(orig.get_src_id()));
+        }
+    }
+
+    builder.into_sourcemap()
+}
@@ -0,0 +1,216 @@
+use swc_common::{sync::Lrc, FileName, SourceMap};
+use swc_css::transform::{transform, SourceMapOptions, TransformOptions, TransformOutput};
+
+/// A source map of `input` which maps line `n` to line `n + 10` of
+/// `style.scss`.
+fn input_map(input: &str) -> sourcemap::SourceMap {
+    let mut builder = sourcemap::SourceMapBuilder::new(Some("style.css"));
+    let src_id = builder.add_source("style.scss");
+
+    for (line, content) in input.lines().enumerate() {
+        let indent = (content.len() - content.trim_start().len()) as u32;
+
+        builder.add_raw(
+            line as u32,
+            indent,
+            line as u32 + 10,
+            indent,
+            Some(src_id),
+            None,
+            false,
+        );
+    }
+
+    builder.into_sourcemap()
+}
+
+fn run(input: &str, options: TransformOptions) -> TransformOutput {
+    let cm: Lrc<SourceMap> = Default::default();
+    let fm = cm.new_source_file(FileName::Real("style.css".into()).into(), input.to_string());
+
+    transform(
+        &cm,
+        &fm,
+        TransformOptions {
+            source_map: Some(SourceMapOptions {
+                input: Some(input_map(input)),
+                ..Default::default()
+            }),
+            ..options
+        },
+    )
+    .expect("failed to transform")
+}
+
+/// Returns the original line of the first token on `line` of the output.
+fn original_line(output: &TransformOutput, line: usize) -> u32 {
+    let map = output.map.as_ref().unwrap();
+    let col = output
+        .code
+        .lines()
+        .nth(line)
+        .unwrap()
+        .find(|c: char| c != ' ');
+    let token = map
+        .lookup_token(line as u32, col.unwrap() as u32)
+        .expect("should have a mapping");
+
+    assert_eq!(token.get_source(), Some("style.scss"));
+    assert_eq!(token.get_dst_line(), line as u32);
+
+    token.get_src_line()
+}
+
+#[test]
+fn compose_with_input_map() {
+    let output = run(
+        ".a {\n  color: red;\n}\n.b {\n  color: blue;\n}\n",
+        Default::default(),
+    );
+
+    assert_eq!(
+        output.code,
+        ".a {\n  color: red;\n}\n.b {\n  color: blue;\n}"
+    );
+    assert_eq!(original_line(&output, 0), 10);
+    assert_eq!(original_line(&output, 1), 11);
+    assert_eq!(original_line(&output, 3), 13);
+    assert_eq!(original_line(&output, 4), 14);
+
+    let map = output.map.as_ref().unwrap();
+
+    assert!(map.sources().all(|source| source == "style.scss"));
+}
+
+#[cfg(feature = "compat")]
+#[test]
+fn lowered_nesting() {
+    use swc_css::compat::{compiler::Config, feature::Features};
+
+    let output = run(
+        ".a {\n  color: red;\n  @media screen {\n    color: blue;\n  }\n}\n",
+        TransformOptions {
+            compat: Some(Config {
+                process: Features::NESTING,
+            }),
+            ..Default::default()
+        },
+    );
+
+    assert_eq!(
+        output.code,
+        ".a {\n  color: red;\n}\n@media screen {\n  .a {\n    color: blue;\n  }\n}"
+    );
+    assert_eq!(original_line(&output, 0), 10);
+    assert_eq!(original_line(&output, 1), 11);
+    assert_eq!(original_line(&output, 3), 12);
+    assert_eq!(original_line(&output, 4), 10);
+    assert_eq!(original_line(&output, 5), 13);
+}
+
+#[cfg(feature = "minifier")]
+#[test]
+fn minified() {
+    let output = run(
+        ".a {\n  color: red;\n}\n.b {\n  color: blue;\n}\n",
+        TransformOptions {
+            minify: Some(Default::default()),
+            codegen: swc_css::codegen::CodegenConfig { minify: true },
+            ..Default::default()
+        },
+    );
+
+    assert_eq!(output.code, ".a{color:red}.b{color:blue}");
+
+    let map = output.map.as_ref().unwrap();
+    // The end of `.a{...}` is mapped to the same column as the start of `.b`.
+    let token = map.lookup_token(0, output.code.find(".b").unwrap() as u32 + 1);
+
+    assert_eq!(token.map(|token| token.get_src_line()), Some(13));
+}
+
+#[cfg(feature = "minifier")]
+#[test]
+fn minify_invalid_targets() {
+    use preset_env_base::query::{Query, Targets};
+    use swc_css::{minifier::options::MinifyOptions, transform::TransformError};
+
+    let cm: Lrc<SourceMap> = Default::default();
+    let fm = cm.new_source_file(FileName::Anon.into(), ".a { color: red; }".into());
+
+    let output = transform(
+        &cm,
+        &fm,
+        TransformOptions {
+            minify: Some(MinifyOptions {
+                targets: Some(Targets::Query(Query::Single("not a browser".into()))),
+                ..Default::default()
+            }),
+            ..Default::default()
+        },
+    );
+
+    assert!(matches!(output, Err(TransformError::Transform(..))));
+}
+
+#[cfg(feature = "plugin")]
+#[test]
+fn missing_plugin() {
+    use swc_css::transform::{PluginOptions, TransformError};
+
+    let cm: Lrc<SourceMap> = Default::default();
+    let fm = cm.new_source_file(FileName::Anon.into(), ".a { color: red; }".into());
+
+    let output = transform(
+        &cm,
+        &fm,
+        TransformOptions {
+            plugins: vec![PluginOptions {
+                path: "missing.wasm".into(),
+                config: Default::default(),
+            }],
+            ..Default::default()
+        },
+    );
+
+    assert!(matches!(output, Err(TransformError::Transform(..))));
+}
+
+#[cfg(feature = "modules")]
+#[test]
+fn css_modules_hash_relative_path() {
+    use swc_css::transform::CssModulesOptions;
+
+    let transform_in = |root: &str| {
+        let cm: Lrc<SourceMap> = Default::default();
+        let fm = cm.new_source_file(
+            FileName::Real(format!("{root}/src/button.module.css").into()).into(),
+            ".a {\n  color: red;\n}\n".to_string(),
+        );
+
+        transform(
+            &cm,
+            &fm,
+            TransformOptions {
+                css_modules: Some(CssModulesOptions {
+                    pattern: "[name]_[local]_[hash]".into(),
+                    root: root.into(),
+                }),
+                ..Default::default()
+            },
+        )
+        .expect("failed to transform")
+    };
+
+    let output = transform_in("/home/a/project");
+    let renamed = output.css_modules.as_ref().unwrap().renamed.values().next();
+    assert!(output.code.starts_with(".button_a_"));
+
+    // The hash does not depend on the location of the project.
+    let other = transform_in("/home/b/checkout");
+    assert_eq!(output.code, other.code);
+    assert_eq!(
+        renamed,
+        other.css_modules.as_ref().unwrap().renamed.values().next()
+    );
+}
@@ -6,7 +6,10 @@ pub use std::fmt::Result;
 use std::{borrow::Cow, str, str::from_utf8};
 
 use serde::{Deserialize, Serialize};
-use swc_common::{BytePos, Span, Spanned, DUMMY_SP};
+use swc_common::{
+    comments::{CommentKind, SingleThreadedComments},
+    BytePos, Span, Spanned, DUMMY_SP,
+};
 use swc_css_ast::*;
 use swc_css_codegen_macros::emitter;
 use swc_css_utils::serialize_ident;
@@ -37,6 +40,7 @@ where
     wr: W,
     config: CodegenConfig,
     ctx: Ctx,
+    comments: Option<SingleThreadedComments>,
 }
 
 impl<W> CodeGenerator<W>
@@ -48,19 +52,94 @@ where
             wr,
             config,
             ctx: Default::default(),
+            comments: None,
         }
     }
 
+    /// Emits block comments placed between top-level rules, e.g. license
+    /// comments. Comments inside of rules are not emitted.
+    pub fn with_comments(mut self, comments: SingleThreadedComments) -> Self {
+        self.comments = Some(comments);
+        self
+    }
+
     #[emitter]
     fn emit_stylesheet(&mut self, n: &Stylesheet) -> Result {
-        self.emit_list(
-            &n.rules,
-            if self.config.minify {
-                ListFormat::NotDelimited
-            } else {
-                ListFormat::NotDelimited | ListFormat::MultiLine
-            },
-        )?;
+        let format = if self.config.minify {
+            ListFormat::NotDelimited
+        } else {
+            ListFormat::NotDelimited | ListFormat::MultiLine
+        };
+
+        if self.comments.is_some() {
+            self.emit_rules_with_comments(&n.rules)?;
+        } else {
+            self.emit_list(&n.rules, format)?;
+        }
+    }
+
+    fn emit_rules_with_comments(&mut self, rules: &[Rule]) -> Result {
+        let mut comments = match &self.comments {
+            Some(comments) => {
+                let (leading, trailing) = comments.borrow_all();
+
+                leading
+                    .values()
+                    .chain(trailing.values())
+                    .flatten()
+                    .filter(|comment| comment.kind == CommentKind::Block)
+                    .cloned()
+                    .collect::<Vec<_>>()
+            }
+            None => Vec::new(),
+        };
+
+        // The lexer starts the span of a comment at the start of the previous token,
+        // so only the end of it can be used to find the position.
+        comments.sort_by_key(|comment| comment.span.hi);
+
+        let mut comments = comments.into_iter().peekable();
+        let mut is_first = true;
+
+        for rule in rules {
+            let span = rule.span();
+
+            while let Some(comment) = comments.next_if(|comment| comment.span.hi <= span.lo) {
+                if !is_first {
+                    formatting_newline!(self);
+                }
+
+                write_str!(self, comment.span, &comment.text);
+
+                is_first = false;
+            }
+
+            // Skip comments inside of the rule
+            while comments
+                .next_if(|comment| comment.span.hi <= span.hi)
+                .is_some()
+            {}
+
+            if !is_first {
+                formatting_newline!(self);
+            }
+
+            emit!(self, rule);
+
+            is_first = false;
+        }
+
+        for comment in comments {
+            if !is_first {
+                formatting_newline!(self);
+            }
+
+            write_str!(self, comment.span, &comment.text);
+
+            is_first = false;
+        }
+
+        Ok(())
     }
 
     #[emitter]
@@ -0,0 +1,274 @@
+use swc_atoms::Atom;
+use swc_common::util::take::Take;
+use swc_css_ast::{
+    AtRule, AtRulePrelude, ComponentValue, LayerPrelude, Rule, SimpleBlock, Stylesheet,
+};
+
+use crate::compiler::Compiler;
+
+#[derive(Debug, Default)]
+struct Layer {
+    /// [None] for anonymous layers.
+    name: Option<Atom>,
+    sublayers: Vec<Layer>,
+    rules: Vec<Rule>,
+}
+
+impl Layer {
+    fn get_or_insert(&mut self, path: &[Atom]) -> &mut Layer {
+        let Some((name, rest)) = path.split_first() else {
+            return self;
+        };
+
+        let index = match self
+            .sublayers
+            .iter()
+            .position(|layer| layer.name.as_ref() == Some(name))
+        {
+            Some(index) => index,
+            None => {
+                self.sublayers.push(Layer {
+                    name: Some(name.clone()),
+                    ..Default::default()
+                });
+
+                self.sublayers.len() - 1
+            }
+        };
+
+        self.sublayers[index].get_or_insert(rest)
+    }
+
+    fn add_anonymous(&mut self) -> &mut Layer {
+        self.sublayers.push(Default::default());
+        self.sublayers.last_mut().unwrap()
+    }
+
+    fn add_rule(&mut self, rule: Rule) {
+        let at_rule = match rule {
+            Rule::AtRule(at_rule) if at_rule.name == *"layer" => at_rule,
+            Rule::AtRule(mut at_rule) if at_rule.block.is_some() => {
+                let mut hoisted = Vec::new();
+                hoist_layers(&mut at_rule, &mut hoisted);
+
+                if at_rule
+                    .block
+                    .as_ref()
+                    .map_or(false, |block| !block.value.is_empty())
+                {
+                    self.rules.push(Rule::AtRule(at_rule));
+                }
+
+                for hoisted in hoisted {
+                    match hoisted {
+                        Hoisted::Declaration(path) => {
+                            self.get_or_insert(&path);
+                        }
+                        Hoisted::Block(path, rule) => {
+                            let layer = match path {
+                                Some(path) => self.get_or_insert(&path),
+                                None => self.add_anonymous(),
+                            };
+                            layer.add_rule(rule);
+                        }
+                    }
+                }
+                return;
+            }
+            rule => {
+                self.rules.push(rule);
+                return;
+            }
+        };
+
+        let AtRule { prelude, block, .. } = *at_rule;
+        let paths = layer_paths(prelude.as_deref());
+
+        match block {
+            Some(block) => {
+                let layer = match paths.first() {
+                    Some(path) => self.get_or_insert(path),
+                    None => self.add_anonymous(),
+                };
+
+                for value in block.value {
+                    if let Some(rule) = component_value_to_rule(value) {
+                        layer.add_rule(rule);
+                    }
+                }
+            }
+            None => {
+                // `@layer a, b;` only declares the order of layers.
+                for path in &paths {
+                    self.get_or_insert(path);
+                }
+            }
+        }
+    }
+
+    fn flatten(self, rules: &mut Vec<Rule>) {
+        for layer in self.sublayers {
+            layer.flatten(rules);
+        }
+
+        rules.extend(self.rules);
+    }
+}
+
+/// A layer found in a conditional at-rule, e.g. `@media`.
+enum Hoisted {
+    /// `@layer a;`, which only declares the order of layers.
+    Declaration(Vec<Atom>),
+    /// A layer block and its rules wrapped in the conditional at-rules. The
+    /// path is [None] for anonymous layers.
+    Block(Option<Vec<Atom>>, Rule),
+}
+
+fn layer_paths(prelude: Option<&AtRulePrelude>) -> Vec<Vec<Atom>> {
+    let names = match prelude {
+        Some(AtRulePrelude::LayerPrelude(LayerPrelude::Name(name))) => vec![name],
+        Some(AtRulePrelude::LayerPrelude(LayerPrelude::NameList(list))) => {
+            list.name_list.iter().collect()
+        }
+        _ => Vec::new(),
+    };
+
+    names
+        .into_iter()
+        .map(|name| {
+            name.name
+                .iter()
+                .map(|ident| ident.value.clone())
+                .collect::<Vec<_>>()
+        })
+        .collect()
+}
+
+/// Creates a copy of `at_rule` which contains `value`.
+fn wrap_in(at_rule: &AtRule, block: &SimpleBlock, value: Vec<ComponentValue>) -> Rule {
+    Rule::AtRule(Box::new(AtRule {
+        span: at_rule.span,
+        name: at_rule.name.clone(),
+        prelude: at_rule.prelude.clone(),
+        block: Some(SimpleBlock {
+            span: block.span,
+            name: block.name.clone(),
+            value,
+        }),
+    }))
+}
+
+/// Moves `@layer` out of a conditional at-rule like `@media`, so the rules of
+/// the layer can be placed in the slot of the layer.
+///
+/// The rules of each layer are wrapped in a copy of the at-rule, so they are
+/// still applied only if the condition matches.
+fn hoist_layers(at_rule: &mut AtRule, hoisted: &mut Vec<Hoisted>) {
+    let Some(mut block) = at_rule.block.take() else {
+        return;
+    };
+
+    let values = block.value.take();
+    let mut value = Vec::with_capacity(values.len());
+
+    for v in values {
+        match v {
+            ComponentValue::AtRule(layer) if layer.name == *"layer" => {
+                let AtRule {
+                    prelude,
+                    block: layer_block,
+                    ..
+                } = *layer;
+                let mut paths = layer_paths(prelude.as_deref());
+
+                match layer_block {
+                    Some(layer_block) => {
+                        let rule = wrap_in(at_rule, &block, layer_block.value);
+                        let path = if paths.is_empty() {
+                            None
+                        } else {
+                            Some(paths.remove(0))
+                        };
+
+                        hoisted.push(Hoisted::Block(path, rule));
+                    }
+                    None => {
+                        hoisted.extend(paths.into_iter().map(Hoisted::Declaration));
+                    }
+                }
+            }
+            ComponentValue::AtRule(mut nested) if nested.block.is_some() => {
+                let mut nested_hoisted = Vec::new();
+                hoist_layers(&mut nested, &mut nested_hoisted);
+
+                hoisted.extend(nested_hoisted.into_iter().map(|h| match h {
+                    Hoisted::Block(path, rule) => Hoisted::Block(
+                        path,
+                        wrap_in(at_rule, &block, vec![rule_to_component_value(rule)]),
+                    ),
+                    h => h,
+                }));
+
+                if nested
+                    .block
+                    .as_ref()
+                    .map_or(false, |block| !block.value.is_empty())
+                {
+                    value.push(ComponentValue::AtRule(nested));
+                }
+            }
+            v => value.push(v),
+        }
+    }
+
+    block.value = value;
+    at_rule.block = Some(block);
+}
+
+fn rule_to_component_value(rule: Rule) -> ComponentValue {
+    match rule {
+        Rule::QualifiedRule(rule) => ComponentValue::QualifiedRule(rule),
+        Rule::AtRule(rule) => ComponentValue::AtRule(rule),
+        Rule::ListOfComponentValues(list) => ComponentValue::ListOfComponentValues(list),
+    }
+}
+
+fn component_value_to_rule(value: ComponentValue) -> Option<Rule> {
+    match value {
+        ComponentValue::QualifiedRule(rule) => Some(Rule::QualifiedRule(rule)),
+        ComponentValue::AtRule(rule) => Some(Rule::AtRule(rule)),
+        ComponentValue::ListOfComponentValues(list) => Some(Rule::ListOfComponentValues(list)),
+        _ => None,
+    }
+}
+
+impl Compiler {
+    /// Removes `@layer` by reordering the rules, which emulates the order of
+    /// precedence of cascade layers.
+    ///
+    /// Rules in layers are moved to the top of the stylesheet in the order of
+    /// layers, followed by the rules not in any layer. The specificity of
+    /// selectors still takes precedence over the order of layers, which is
+    /// not emulated.
+    pub(crate) fn process_cascade_layers(&mut self, n: &mut Stylesheet) {
+        let mut leading = Vec::new();
+        let mut root = Layer::default();
+
+        for rule in n.rules.drain(..) {
+            match &rule {
+                Rule::AtRule(at_rule)
+                    if at_rule.name == *"charset"
+                        || at_rule.name == *"import"
+                        || at_rule.name == *"namespace" =>
+                {
+                    leading.push(rule);
+                }
+                _ => root.add_rule(rule),
+            }
+        }
+
+        root.flatten(&mut leading);
+
+        n.rules = leading;
+    }
+}
@@ -0,0 +1,69 @@
+use swc_common::DUMMY_SP;
+use swc_css_ast::{
+    CalcProduct, CalcProductOrOperator, CalcSum, CalcValue, CalcValueOrOperator, ComponentValue,
+    Delimiter, DelimiterValue, Function, FunctionName, Ident,
+};
+
+use crate::compiler::Compiler;
+
+fn comma() -> ComponentValue {
+    ComponentValue::Delimiter(Box::new(Delimiter {
+        span: DUMMY_SP,
+        value: DelimiterValue::Comma,
+    }))
+}
+
+fn function(name: &str, value: Vec<ComponentValue>) -> Function {
+    Function {
+        span: DUMMY_SP,
+        name: FunctionName::Ident(Ident {
+            span: DUMMY_SP,
+            value: name.into(),
+            raw: None,
+        }),
+        value,
+    }
+}
+
+impl Compiler {
+    /// Converts `clamp(MIN, VAL, MAX)` to `max(MIN, min(VAL, MAX))`, which
+    /// is supported by more browsers.
+    pub(crate) fn process_clamp(&mut self, n: &mut Function) {
+        if !n.name.as_str().eq_ignore_ascii_case("clamp") {
+            return;
+        }
+
+        let [ComponentValue::CalcSum(_), ComponentValue::Delimiter(_), ComponentValue::CalcSum(_), ComponentValue::Delimiter(_), ComponentValue::CalcSum(_)] =
+            &*n.value
+        else {
+            return;
+        };
+
+        let values = n.value.drain(..).step_by(2).collect::<Vec<_>>();
+        let Ok([min, val, max]) = <[ComponentValue; 3]>::try_from(values) else {
+            unreachable!();
+        };
+
+        let inner = function("min", vec![val, comma(), max]);
+
+        *n = Function {
+            span: n.span,
+            ..function(
+                "max",
+                vec![
+                    min,
+                    comma(),
+                    ComponentValue::CalcSum(Box::new(CalcSum {
+                        span: DUMMY_SP,
+                        expressions: vec![CalcProductOrOperator::Product(CalcProduct {
+                            span: DUMMY_SP,
+                            expressions: vec![CalcValueOrOperator::Value(CalcValue::Function(
+                                inner,
+                            ))],
+                        })],
+                    })),
+                ],
+            )
+        };
+    }
+}
@@ -0,0 +1,267 @@
+use swc_css_ast::{
+    AbsoluteColorBase, AlphaValue, ComponentValue, Delimiter, DelimiterValue, Function,
+    FunctionName, Ident, Number, SimpleBlock,
+};
+use swc_css_utils::{
+    lab_to_lch, lab_to_rgb, lch_to_lab, oklab_to_rgb, rgb_to_oklab, round_alpha, to_rgb255,
+};
+use swc_css_visit::{Visit, VisitWith};
+
+use crate::{compiler::Compiler, feature::Features};
+
+const GAMUT_EPSILON: f64 = 0.000_01;
+
+fn is_in_gamut(rgb: [f64; 3]) -> bool {
+    rgb.iter()
+        .all(|c| (-GAMUT_EPSILON..=1.0 + GAMUT_EPSILON).contains(c))
+}
+
+/// Maps a color to the sRGB gamut by reducing its chroma in OKLCh, which
+/// preserves the lightness and the hue.
+pub(super) fn gamut_map(rgb: [f64; 3]) -> [f64; 3] {
+    let mut rgb = rgb;
+
+    if !is_in_gamut(rgb) {
+        let [l, c, h] = lab_to_lch(rgb_to_oklab(rgb));
+
+        if l >= 1.0 {
+            return [1.0, 1.0, 1.0];
+        } else if l <= 0.0 {
+            return [0.0, 0.0, 0.0];
+        }
+
+        let mut low = 0.0;
+        let mut high = c;
+
+        while high - low > 0.0001 {
+            let mid = (low + high) / 2.0;
+
+            if is_in_gamut(oklab_to_rgb(lch_to_lab([l, mid, h]))) {
+                low = mid;
+            } else {
+                high = mid;
+            }
+        }
+
+        rgb = oklab_to_rgb(lch_to_lab([l, low, h]));
+    }
+
+    rgb.map(|c| c.clamp(0.0, 1.0))
+}
+
+/// Creates `rgb()` or `rgba()` from a sRGB value in the `0..=1` range.
+pub(super) fn to_rgb_function(rgb: [f64; 3], alpha: f64) -> AbsoluteColorBase {
+    let rgb = to_rgb255(rgb);
+    let comma = || {
+        ComponentValue::Delimiter(Box::new(Delimiter {
+            value: DelimiterValue::Comma,
+            span: Default::default(),
+        }))
+    };
+    let number = |value: f64| {
+        ComponentValue::Number(Box::new(Number {
+            value,
+            span: Default::default(),
+            raw: None,
+        }))
+    };
+
+    let mut value = vec![
+        number(rgb[0].round()),
+        comma(),
+        number(rgb[1].round()),
+        comma(),
+        number(rgb[2].round()),
+    ];
+
+    if alpha != 1.0 {
+        value.push(comma());
+        value.push(ComponentValue::AlphaValue(Box::new(AlphaValue::Number(
+            Number {
+                value: round_alpha(alpha),
+                span: Default::default(),
+                raw: None,
+            },
+        ))));
+    }
+
+    AbsoluteColorBase::Function(Function {
+        name: FunctionName::Ident(Ident {
+            value: if alpha == 1.0 { "rgb" } else { "rgba" }.into(),
+            span: Default::default(),
+            raw: None,
+        }),
+        value,
+        span: Default::default(),
+    })
+}
+
+impl Compiler {
+    fn get_lab_component(
+        &self,
+        value: Option<&ComponentValue>,
+        percentage_reference: f64,
+    ) -> Option<f64> {
+        match value {
+            Some(ComponentValue::Number(number)) => Some(number.value),
+            Some(ComponentValue::Percentage(percentage)) => {
+                Some(percentage.value.value / 100.0 * percentage_reference)
+            }
+            Some(ComponentValue::Ident(ident)) if ident.value.eq_ignore_ascii_case("none") => {
+                Some(0.0)
+            }
+            _ => None,
+        }
+    }
+
+    /// Returns the sRGB value and the alpha of `lab()`, `lch()`, `oklab()` or
+    /// `oklch()`. Colors outside of the sRGB gamut are mapped into it.
+    pub(super) fn get_lab_rgba(&self, function: &Function) -> Option<([f64; 3], f64)> {
+        self.get_unmapped_lab_rgba(function)
+            .map(|(rgb, alpha)| (gamut_map(rgb), alpha))
+    }
+
+    /// Same as [Compiler::get_lab_rgba], but the sRGB value may be outside of
+    /// the `0..=1` range.
+    fn get_unmapped_lab_rgba(&self, function: &Function) -> Option<([f64; 3], f64)> {
+        let name = function.name.as_str().to_ascii_lowercase();
+
+        // https://drafts.csswg.org/css-color-4/#specifying-lab-lch
+        // https://drafts.csswg.org/css-color-4/#specifying-oklab-oklch
+        let (is_ok, is_polar) = match &*name {
+            "lab" => (false, false),
+            "lch" => (false, true),
+            "oklab" => (true, false),
+            "oklch" => (true, true),
+            _ => return None,
+        };
+        let (max_lightness, chroma_reference) = match (is_ok, is_polar) {
+            (false, false) => (100.0, 125.0),
+            (false, true) => (100.0, 150.0),
+            (true, _) => (1.0, 0.4),
+        };
+
+        if function.value.len() > 5 {
+            return None;
+        }
+
+        match function.value.get(3) {
+            None => {}
+            Some(ComponentValue::Delimiter(delimiter))
+                if matches!(delimiter.value, DelimiterValue::Solidus) => {}
+            _ => return None,
+        }
+
+        let l = self
+            .get_lab_component(function.value.first(), max_lightness)?
+            .clamp(0.0, max_lightness);
+        let lab = if is_polar {
+            let c = self.get_lab_component(function.value.get(1), chroma_reference)?;
+            let h = self.get_hue(function.value.get(2))?;
+
+            lch_to_lab([l, c.max(0.0), h])
+        } else {
+            [
+                l,
+                self.get_lab_component(function.value.get(1), chroma_reference)?,
+                self.get_lab_component(function.value.get(2), chroma_reference)?,
+            ]
+        };
+        let alpha = self.get_alpha_value(function.value.get(4))?;
+
+        let rgb = if is_ok {
+            oklab_to_rgb(lab)
+        } else {
+            lab_to_rgb(lab)
+        };
+
+        Some((rgb, alpha))
+    }
+
+    fn process_lab_function(&self, n: &mut AbsoluteColorBase, names: [&str; 2]) {
+        if let AbsoluteColorBase::Function(function) = n {
+            if !names
+                .iter()
+                .any(|name| function.name.as_str().eq_ignore_ascii_case(name))
+            {
+                return;
+            }
+
+            if let Some((rgb, alpha)) = self.get_lab_rgba(function) {
+                *n = to_rgb_function(rgb, alpha);
+            }
+        }
+    }
+
+    /// Returns the declarations of `n` which have colors outside of the sRGB
+    /// gamut, with their indices.
+    ///
+    /// Those colors are only approximated by `rgb()`, so the original
+    /// declarations should be kept after the lowered ones for browsers which
+    /// support them.
+    pub(crate) fn find_wide_gamut_declarations(
+        &self,
+        n: &SimpleBlock,
+    ) -> Vec<(usize, ComponentValue)> {
+        let mut names = Vec::new();
+        if self.c.process.contains(Features::COLOR_LAB) {
+            names.extend(["lab", "lch"]);
+        }
+        if self.c.process.contains(Features::COLOR_OKLAB) {
+            names.extend(["oklab", "oklch"]);
+        }
+        if names.is_empty() {
+            return Vec::new();
+        }
+
+        n.value
+            .iter()
+            .enumerate()
+            .filter(|(_, value)| {
+                let ComponentValue::Declaration(declaration) = value else {
+                    return false;
+                };
+                let mut finder = WideGamutFinder {
+                    compiler: self,
+                    names: &names,
+                    found: false,
+                };
+                declaration.visit_with(&mut finder);
+                finder.found
+            })
+            .map(|(i, value)| (i, value.clone()))
+            .collect()
+    }
+
+    pub(crate) fn process_color_lab(&mut self, n: &mut AbsoluteColorBase) {
+        self.process_lab_function(n, ["lab", "lch"]);
+    }
+
+    pub(crate) fn process_color_oklab(&mut self, n: &mut AbsoluteColorBase) {
+        self.process_lab_function(n, ["oklab", "oklch"]);
+    }
+}
+
+struct WideGamutFinder<'a> {
+    compiler: &'a Compiler,
+    names: &'a [&'a str],
+    found: bool,
+}
+
+impl Visit for WideGamutFinder<'_> {
+    fn visit_absolute_color_base(&mut self, n: &AbsoluteColorBase) {
+        n.visit_children_with(self);
+
+        if let AbsoluteColorBase::Function(function) = n {
+            if self
+                .names
+                .iter()
+                .any(|name| function.name.as_str().eq_ignore_ascii_case(name))
+            {
+                if let Some((rgb, _)) = self.compiler.get_unmapped_lab_rgba(function) {
+                    self.found |= !is_in_gamut(rgb);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,259 @@
+use swc_atoms::Atom;
+use swc_css_ast::{AbsoluteColorBase, Color, ComponentValue, DelimiterValue, Function};
+use swc_css_utils::{
+    hsl_to_rgb, hwb_to_rgb, linear_to_srgb, oklab_to_rgb, rgb_to_oklab, srgb_to_linear,
+    NAMED_COLORS,
+};
+
+use crate::compiler::{
+    color_lab::{gamut_map, to_rgb_function},
+    Compiler,
+};
+
+#[derive(Debug, Clone, Copy)]
+enum ColorSpace {
+    Srgb,
+    SrgbLinear,
+    Oklab,
+}
+
+impl ColorSpace {
+    fn convert_from_rgb(self, rgb: [f64; 3]) -> [f64; 3] {
+        match self {
+            ColorSpace::Srgb => rgb,
+            ColorSpace::SrgbLinear => rgb.map(srgb_to_linear),
+            ColorSpace::Oklab => rgb_to_oklab(rgb),
+        }
+    }
+
+    fn convert_to_rgb(self, coords: [f64; 3]) -> [f64; 3] {
+        match self {
+            ColorSpace::Srgb => coords,
+            ColorSpace::SrgbLinear => coords.map(linear_to_srgb),
+            ColorSpace::Oklab => gamut_map(oklab_to_rgb(coords)),
+        }
+    }
+}
+
+fn get_named_color(name: &Atom) -> Option<([f64; 3], f64)> {
+    let name = name.to_ascii_lowercase();
+
+    if *name == *"transparent" {
+        return Some(([0.0, 0.0, 0.0], 0.0));
+    }
+
+    NAMED_COLORS.get(&name).map(|color| {
+        (
+            [
+                color.rgb[0] as f64 / 255.0,
+                color.rgb[1] as f64 / 255.0,
+                color.rgb[2] as f64 / 255.0,
+            ],
+            1.0,
+        )
+    })
+}
+
+fn get_hex_color(hex: &str) -> Option<([f64; 3], f64)> {
+    if !hex.bytes().all(|c| c.is_ascii_hexdigit()) {
+        return None;
+    }
+
+    let digits = match hex.len() {
+        3 | 4 => hex
+            .chars()
+            .map(|c| u8::from_str_radix(&format!("{c}{c}"), 16).ok())
+            .collect::<Option<Vec<_>>>()?,
+        6 | 8 => (0..hex.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&hex[i..i + 2], 16).ok())
+            .collect::<Option<Vec<_>>>()?,
+        _ => return None,
+    };
+
+    Some((
+        [
+            digits[0] as f64 / 255.0,
+            digits[1] as f64 / 255.0,
+            digits[2] as f64 / 255.0,
+        ],
+        digits.get(3).map_or(1.0, |&a| a as f64 / 255.0),
+    ))
+}
+
+impl Compiler {
+    fn get_rgb_component(&self, value: Option<&ComponentValue>, reference: f64) -> Option<f64> {
+        let value = match value? {
+            ComponentValue::Number(number) => number.value / reference,
+            ComponentValue::Integer(integer) => integer.value as f64 / reference,
+            ComponentValue::Percentage(percentage) => percentage.value.value / 100.0,
+            ComponentValue::Ident(ident) if ident.value.eq_ignore_ascii_case("none") => 0.0,
+            _ => return None,
+        };
+
+        Some(value.clamp(0.0, 1.0))
+    }
+
+    /// Returns the sRGB value and the alpha of a color function which
+    /// doesn't depend on anything else, like `var()` or `currentcolor`.
+    fn get_function_rgba(&self, function: &Function) -> Option<([f64; 3], f64)> {
+        let name = function.name.as_str().to_ascii_lowercase();
+
+        if matches!(&*name, "lab" | "lch" | "oklab" | "oklch") {
+            return self.get_lab_rgba(function);
+        }
+
+        let values = function
+            .value
+            .iter()
+            .filter(|value| !matches!(value, ComponentValue::Delimiter(_)))
+            .collect::<Vec<_>>();
+
+        if values.len() > 4 {
+            return None;
+        }
+
+        let alpha = self.get_alpha_value(values.get(3).copied())?;
+
+        let rgb = match &*name {
+            "rgb" | "rgba" => [
+                self.get_rgb_component(values.first().copied(), 255.0)?,
+                self.get_rgb_component(values.get(1).copied(), 255.0)?,
+                self.get_rgb_component(values.get(2).copied(), 255.0)?,
+            ],
+            "hsl" | "hsla" | "hwb" => {
+                let hsl_or_hwb = [
+                    self.get_hue(values.first().copied())?,
+                    self.get_rgb_component(values.get(1).copied(), 100.0)?,
+                    self.get_rgb_component(values.get(2).copied(), 100.0)?,
+                ];
+
+                if name == "hwb" {
+                    hwb_to_rgb(hsl_or_hwb)
+                } else {
+                    hsl_to_rgb(hsl_or_hwb)
+                }
+            }
+            _ => return None,
+        };
+
+        Some((rgb, alpha))
+    }
+
+    fn get_color_rgba(&self, value: &ComponentValue) -> Option<([f64; 3], f64)> {
+        match value {
+            ComponentValue::Ident(ident) => get_named_color(&ident.value),
+            ComponentValue::Color(color) => match &**color {
+                Color::AbsoluteColorBase(AbsoluteColorBase::HexColor(hex)) => {
+                    get_hex_color(&hex.value)
+                }
+                Color::AbsoluteColorBase(AbsoluteColorBase::NamedColorOrTransparent(ident)) => {
+                    get_named_color(&ident.value)
+                }
+                Color::AbsoluteColorBase(AbsoluteColorBase::Function(function)) => {
+                    self.get_function_rgba(function)
+                }
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+
+    /// Parses `<color> && <percentage [0,100]>?`.
+    fn get_mix_component(
+        &self,
+        values: &[ComponentValue],
+    ) -> Option<(([f64; 3], f64), Option<f64>)> {
+        let (color, percentage) = match values {
+            [color] => (color, None),
+            [ComponentValue::Percentage(percentage), color]
+            | [color, ComponentValue::Percentage(percentage)] => {
+                (color, Some(percentage.value.value))
+            }
+            _ => return None,
+        };
+
+        if let Some(percentage) = percentage {
+            if !(0.0..=100.0).contains(&percentage) {
+                return None;
+            }
+        }
+
+        Some((self.get_color_rgba(color)?, percentage))
+    }
+
+    pub(crate) fn process_color_mix(&mut self, n: &mut AbsoluteColorBase) {
+        if let AbsoluteColorBase::Function(function) = n {
+            if !function.name.as_str().eq_ignore_ascii_case("color-mix") {
+                return;
+            }
+
+            // https://drafts.csswg.org/css-color-5/#color-mix
+            let args = function
+                .value
+                .split(|value| {
+                    matches!(value, ComponentValue::Delimiter(delimiter) if matches!(delimiter.value, DelimiterValue::Comma))
+                })
+                .collect::<Vec<_>>();
+
+            let [method, first, second] = &*args else {
+                return;
+            };
+
+            let space = match method {
+                [ComponentValue::Ident(keyword), ComponentValue::Ident(space)]
+                    if keyword.value.eq_ignore_ascii_case("in") =>
+                {
+                    match &*space.value.to_ascii_lowercase() {
+                        "srgb" => ColorSpace::Srgb,
+                        "srgb-linear" => ColorSpace::SrgbLinear,
+                        "oklab" => ColorSpace::Oklab,
+                        _ => return,
+                    }
+                }
+                _ => return,
+            };
+
+            let Some(((rgb1, alpha1), p1)) = self.get_mix_component(first) else {
+                return;
+            };
+            let Some(((rgb2, alpha2), p2)) = self.get_mix_component(second) else {
+                return;
+            };
+
+            let (p1, p2) = match (p1, p2) {
+                (None, None) => (50.0, 50.0),
+                (Some(p1), None) => (p1, 100.0 - p1),
+                (None, Some(p2)) => (100.0 - p2, p2),
+                (Some(p1), Some(p2)) => (p1, p2),
+            };
+            let sum = p1 + p2;
+
+            if sum == 0.0 {
+                return;
+            }
+
+            let alpha_multiplier = if sum < 100.0 { sum / 100.0 } else { 1.0 };
+            let (p1, p2) = (p1 / sum, p2 / sum);
+
+            let c1 = space.convert_from_rgb(rgb1);
+            let c2 = space.convert_from_rgb(rgb2);
+            let alpha = alpha1 * p1 + alpha2 * p2;
+
+            // Interpolate with premultiplied alpha
+            let mut coords = [0.0; 3];
+
+            for i in 0..3 {
+                coords[i] = if alpha == 0.0 {
+                    c1[i] * p1 + c2[i] * p2
+                } else {
+                    (c1[i] * alpha1 * p1 + c2[i] * alpha2 * p2) / alpha
+                };
+            }
+
+            let rgb = space.convert_to_rgb(coords).map(|c| c.clamp(0.0, 1.0));
+
+            *n = to_rgb_function(rgb, alpha * alpha_multiplier);
+        }
+    }
+}
@@ -0,0 +1,225 @@
+use swc_css_ast::{ComponentValue, Declaration, DeclarationName, Ident, SimpleBlock};
+
+use crate::compiler::Compiler;
+
+// The physical sides assume `writing-mode: horizontal-tb` and `direction:
+// ltr`, as the writing mode can't be known at compile time.
+
+fn get_physical_side(axis: &str, side: &str) -> &'static str {
+    match (axis, side) {
+        ("inline", "start") => "left",
+        ("inline", "end") => "right",
+        ("block", "start") => "top",
+        _ => "bottom",
+    }
+}
+
+/// A physical replacement of a logical property.
+enum Physical {
+    /// A longhand property with the same value.
+    Longhand(String),
+    /// Two properties for the start and the end, which take one or two
+    /// values like `margin-inline`.
+    StartEnd([String; 2]),
+    /// Two properties with the same value, like `border-inline`.
+    Both([String; 2]),
+    /// Properties of the `inset` shorthand, which takes up to four values
+    /// like `margin`.
+    Inset,
+}
+
+fn get_physical(name: &str) -> Option<Physical> {
+    let physical = match name {
+        "inline-size" => "width",
+        "block-size" => "height",
+        "min-inline-size" => "min-width",
+        "min-block-size" => "min-height",
+        "max-inline-size" => "max-width",
+        "max-block-size" => "max-height",
+        "border-start-start-radius" => "border-top-left-radius",
+        "border-start-end-radius" => "border-top-right-radius",
+        "border-end-start-radius" => "border-bottom-left-radius",
+        "border-end-end-radius" => "border-bottom-right-radius",
+        "inset" => return Some(Physical::Inset),
+        _ => "",
+    };
+
+    if !physical.is_empty() {
+        return Some(Physical::Longhand(physical.to_string()));
+    }
+
+    for (prefix, physical_prefix) in [
+        ("margin-", "margin-"),
+        ("padding-", "padding-"),
+        ("inset-", ""),
+        ("border-", "border-"),
+    ] {
+        let Some(rest) = name.strip_prefix(prefix) else {
+            continue;
+        };
+        let Some((axis, rest)) = ["inline", "block"]
+            .into_iter()
+            .find_map(|axis| Some((axis, rest.strip_prefix(axis)?)))
+        else {
+            continue;
+        };
+        let (side, suffix) = match rest.strip_prefix("-start") {
+            Some(suffix) => (Some("start"), suffix),
+            None => match rest.strip_prefix("-end") {
+                Some(suffix) => (Some("end"), suffix),
+                None => (None, rest),
+            },
+        };
+
+        let is_valid_suffix = match prefix {
+            "border-" => matches!(suffix, "" | "-width" | "-style" | "-color"),
+            _ => suffix.is_empty(),
+        };
+
+        if !is_valid_suffix {
+            return None;
+        }
+
+        let to_physical = |side: &str| {
+            format!(
+                "{}{}{}",
+                physical_prefix,
+                get_physical_side(axis, side),
+                suffix
+            )
+        };
+
+        return Some(match side {
+            Some(side) => Physical::Longhand(to_physical(side)),
+            None if prefix == "border-" && suffix.is_empty() => {
+                Physical::Both([to_physical("start"), to_physical("end")])
+            }
+            None => Physical::StartEnd([to_physical("start"), to_physical("end")]),
+        });
+    }
+
+    None
+}
+
+/// Returns true if the value may be expanded to multiple values.
+fn has_var(value: &[ComponentValue]) -> bool {
+    value.iter().any(|value| {
+        matches!(value, ComponentValue::Function(function) if function.name.as_str().eq_ignore_ascii_case("var") || function.name.as_str().eq_ignore_ascii_case("env"))
+    })
+}
+
+fn with_name(declaration: &Declaration, name: &str, value: Vec<ComponentValue>) -> ComponentValue {
+    ComponentValue::Declaration(Box::new(Declaration {
+        span: declaration.span,
+        name: DeclarationName::Ident(Ident {
+            span: Default::default(),
+            value: name.into(),
+            raw: None,
+        }),
+        value,
+        important: declaration.important.clone(),
+    }))
+}
+
+impl Compiler {
+    fn get_physical_declarations(&self, declaration: &Declaration) -> Option<Vec<ComponentValue>> {
+        let DeclarationName::Ident(name) = &declaration.name else {
+            return None;
+        };
+        let name = name.value.to_ascii_lowercase();
+        let value = &declaration.value;
+
+        if matches!(&*name, "float" | "clear") {
+            let [ComponentValue::Ident(ident)] = &**value else {
+                return None;
+            };
+            let keyword = match &*ident.value.to_ascii_lowercase() {
+                "inline-start" => "left",
+                "inline-end" => "right",
+                _ => return None,
+            };
+
+            return Some(vec![with_name(
+                declaration,
+                &name,
+                vec![ComponentValue::Ident(Box::new(Ident {
+                    span: ident.span,
+                    value: keyword.into(),
+                    raw: None,
+                }))],
+            )]);
+        }
+
+        let declarations = match get_physical(&name)? {
+            Physical::Longhand(physical) => vec![with_name(declaration, &physical, value.clone())],
+            Physical::Both([start, end]) => vec![
+                with_name(declaration, &start, value.clone()),
+                with_name(declaration, &end, value.clone()),
+            ],
+            Physical::StartEnd([start, end]) => {
+                if has_var(value) {
+                    return None;
+                }
+
+                let (start_value, end_value) = match &**value {
+                    [both] => (both, both),
+                    [start, end] => (start, end),
+                    _ => return None,
+                };
+
+                vec![
+                    with_name(declaration, &start, vec![start_value.clone()]),
+                    with_name(declaration, &end, vec![end_value.clone()]),
+                ]
+            }
+            Physical::Inset => {
+                if has_var(value) {
+                    return None;
+                }
+
+                let [top, right, bottom, left] = match &**value {
+                    [all] => [all, all, all, all],
+                    [vertical, horizontal] => [vertical, horizontal, vertical, horizontal],
+                    [top, horizontal, bottom] => [top, horizontal, bottom, horizontal],
+                    [top, right, bottom, left] => [top, right, bottom, left],
+                    _ => return None,
+                };
+
+                vec![
+                    with_name(declaration, "top", vec![top.clone()]),
+                    with_name(declaration, "right", vec![right.clone()]),
+                    with_name(declaration, "bottom", vec![bottom.clone()]),
+                    with_name(declaration, "left", vec![left.clone()]),
+                ]
+            }
+        };
+
+        Some(declarations)
+    }
+
+    pub(crate) fn process_logical_properties(&mut self, n: &mut SimpleBlock) {
+        if !n
+            .value
+            .iter()
+            .any(|value| matches!(value, ComponentValue::Declaration(_)))
+        {
+            return;
+        }
+
+        let mut new_value = Vec::with_capacity(n.value.len());
+
+        for value in n.value.drain(..) {
+            match &value {
+                ComponentValue::Declaration(declaration) => {
+                    match self.get_physical_declarations(declaration) {
+                        Some(declarations) => new_value.extend(declarations),
+                        None => new_value.push(value),
+                    }
+                }
+                _ => new_value.push(value),
+            }
+        }
+
+        n.value = new_value;
+    }
+}
@@ -1,22 +1,30 @@
+use preset_env_base::query::targets_to_versions;
+pub use preset_env_base::query::Targets;
 use swc_common::{util::take::Take, Spanned, DUMMY_SP};
 use swc_css_ast::{
-    AbsoluteColorBase, AtRule, ComponentValue, CompoundSelector, MediaAnd, MediaCondition,
-    MediaConditionAllType, MediaConditionWithoutOr, MediaInParens, MediaQuery, Rule,
-    SupportsCondition,
+    AbsoluteColorBase, AtRule, ComponentValue, CompoundSelector, Function, MediaAnd,
+    MediaCondition, MediaConditionAllType, MediaConditionWithoutOr, MediaInParens, MediaQuery,
+    Rule, SelectorList, SimpleBlock, Stylesheet, SupportsCondition,
 };
 use swc_css_visit::{VisitMut, VisitMutWith};
 
 use self::custom_media::CustomMediaHandler;
 use crate::feature::Features;
 
+mod cascade_layers;
+mod clamp;
 mod color_alpha_parameter;
 mod color_hex_alpha;
 mod color_hwb;
+mod color_lab;
+mod color_mix;
 mod color_space_separated_parameters;
 mod custom_media;
 mod legacy_rgb_and_hsl;
+mod logical_properties;
 mod media_query_ranges;
 mod nesting;
+mod selector_is;
 mod selector_not;
 
 /// Compiles a modern CSS file to a CSS file which works with old browsers.
@@ -34,6 +42,18 @@ pub struct Config {
     pub process: Features,
 }
 
+impl Config {
+    /// Creates a config which processes the features not supported by
+    /// `targets`.
+    pub fn from_targets(targets: Targets) -> Result<Self, anyhow::Error> {
+        let versions = targets_to_versions(Some(targets), None)?;
+
+        Ok(Self {
+            process: Features::from_versions(&versions),
+        })
+    }
+}
+
 impl Compiler {
     pub fn new(config: Config) -> Self {
         Self {
@@ -45,6 +65,14 @@ impl Compiler {
 }
 
 impl VisitMut for Compiler {
+    fn visit_mut_stylesheet(&mut self, n: &mut Stylesheet) {
+        n.visit_mut_children_with(self);
+
+        if self.c.process.contains(Features::CASCADE_LAYERS) {
+            self.process_cascade_layers(n);
+        }
+    }
+
     fn visit_mut_at_rule(&mut self, n: &mut AtRule) {
         n.visit_mut_children_with(self);
 
@@ -96,12 +124,15 @@ impl VisitMut for Compiler {
                     Rule::QualifiedRule(mut n) => {
                         let mut rules = self.extract_nested_rules(&mut n);
 
+                        n.visit_mut_with(self);
                         rules.visit_mut_with(self);
 
                         new.push(Rule::QualifiedRule(n));
                         new.extend(rules);
                     }
-                    _ => {
+                    mut n => {
+                        n.visit_mut_with(self);
+
                         new.push(n);
                     }
                 }
@@ -148,6 +179,18 @@ impl VisitMut for Compiler {
         }
     }
 
+    fn visit_mut_selector_list(&mut self, n: &mut SelectorList) {
+        n.visit_mut_children_with(self);
+
+        if self.in_supports_condition {
+            return;
+        }
+
+        if self.c.process.contains(Features::SELECTOR_IS) {
+            self.process_selector_is(&mut n.children);
+        }
+    }
+
     fn visit_mut_compound_selector(&mut self, n: &mut CompoundSelector) {
         n.visit_mut_children_with(self);
 
@@ -172,6 +215,32 @@ impl VisitMut for Compiler {
         }
     }
 
+    fn visit_mut_function(&mut self, n: &mut Function) {
+        n.visit_mut_children_with(self);
+
+        if self.in_supports_condition {
+            return;
+        }
+
+        if self.c.process.contains(Features::CLAMP) {
+            self.process_clamp(n);
+        }
+    }
+
+    fn visit_mut_simple_block(&mut self, n: &mut SimpleBlock) {
+        let wide_gamut_declarations = self.find_wide_gamut_declarations(n);
+
+        n.visit_mut_children_with(self);
+
+        for (i, declaration) in wide_gamut_declarations.into_iter().rev() {
+            n.value.insert(i + 1, declaration);
+        }
+
+        if self.c.process.contains(Features::LOGICAL_PROPERTIES) {
+            self.process_logical_properties(n);
+        }
+    }
+
     fn visit_mut_absolute_color_base(&mut self, n: &mut AbsoluteColorBase) {
         n.visit_mut_children_with(self);
 
@@ -199,5 +268,17 @@ impl VisitMut for Compiler {
         if process.contains(Features::COLOR_HWB) {
             self.process_color_hwb(n);
         }
+
+        if process.contains(Features::COLOR_LAB) {
+            self.process_color_lab(n);
+        }
+
+        if process.contains(Features::COLOR_OKLAB) {
+            self.process_color_oklab(n);
+        }
+
+        if process.contains(Features::COLOR_MIX) {
+            self.process_color_mix(n);
+        }
     }
 }
@@ -0,0 +1,139 @@
+use swc_common::EqIgnoreSpan;
+use swc_css_ast::{
+    ComplexSelector, ComplexSelectorChildren, CompoundSelector, ForgivingComplexSelector,
+    PseudoClassSelector, PseudoClassSelectorChildren, SubclassSelector, TypeSelector,
+};
+
+use crate::compiler::Compiler;
+
+/// Returns the arguments of `:is()` or `:where()` if all of them are
+/// compound selectors, which can be merged into the selector containing it.
+fn get_compound_arguments(selector: &SubclassSelector) -> Option<Vec<&CompoundSelector>> {
+    let SubclassSelector::PseudoClass(PseudoClassSelector {
+        name,
+        children: Some(children),
+        ..
+    }) = selector
+    else {
+        return None;
+    };
+
+    if !name.value.eq_ignore_ascii_case("is") && !name.value.eq_ignore_ascii_case("where") {
+        return None;
+    }
+
+    let [PseudoClassSelectorChildren::ForgivingSelectorList(list)] = &**children else {
+        return None;
+    };
+
+    list.children
+        .iter()
+        .map(|child| match child {
+            ForgivingComplexSelector::ComplexSelector(ComplexSelector { children, .. }) => {
+                match &**children {
+                    [ComplexSelectorChildren::CompoundSelector(compound)]
+                        if compound.nesting_selector.is_none() =>
+                    {
+                        Some(compound)
+                    }
+                    _ => None,
+                }
+            }
+            _ => None,
+        })
+        .collect()
+}
+
+/// Merges `argument` into `compound` in place of the subclass selector at
+/// `index`. Returns [None] if the result can't match anything.
+fn merge_compound(
+    compound: &CompoundSelector,
+    index: usize,
+    argument: &CompoundSelector,
+) -> Option<CompoundSelector> {
+    let type_selector = match (&compound.type_selector, &argument.type_selector) {
+        (None, type_selector) | (type_selector, None) => type_selector.clone(),
+        (Some(outer), Some(inner)) => match (&**outer, &**inner) {
+            (TypeSelector::Universal(_), _) => Some(inner.clone()),
+            (_, TypeSelector::Universal(_)) => Some(outer.clone()),
+            _ if outer.eq_ignore_span(inner) => Some(outer.clone()),
+            _ => return None,
+        },
+    };
+
+    let mut subclass_selectors = compound.subclass_selectors.clone();
+
+    subclass_selectors.splice(
+        index..index + 1,
+        argument.subclass_selectors.iter().cloned(),
+    );
+
+    Some(CompoundSelector {
+        span: compound.span,
+        nesting_selector: compound.nesting_selector.clone(),
+        type_selector,
+        subclass_selectors,
+    })
+}
+
+/// Expands `:is()` and `:where()` in `selector` to a list of selectors.
+/// Returns [None] if there's nothing to expand.
+fn expand_complex_selector(selector: &ComplexSelector) -> Option<Vec<ComplexSelector>> {
+    for (i, child) in selector.children.iter().enumerate() {
+        let ComplexSelectorChildren::CompoundSelector(compound) = child else {
+            continue;
+        };
+
+        for (j, subclass_selector) in compound.subclass_selectors.iter().enumerate() {
+            let Some(arguments) = get_compound_arguments(subclass_selector) else {
+                continue;
+            };
+
+            let mut expanded = Vec::new();
+
+            for argument in arguments {
+                let Some(merged) = merge_compound(compound, j, argument) else {
+                    continue;
+                };
+
+                let mut new_selector = selector.clone();
+
+                new_selector.children[i] = ComplexSelectorChildren::CompoundSelector(merged);
+
+                match expand_complex_selector(&new_selector) {
+                    Some(selectors) => expanded.extend(selectors),
+                    None => expanded.push(new_selector),
+                }
+            }
+
+            if expanded.is_empty() {
+                return None;
+            }
+
+            return Some(expanded);
+        }
+    }
+
+    None
+}
+
+impl Compiler {
+    /// Expands `:is()` and `:where()` whose arguments are compound selectors,
+    /// e.g. `:is(.a, .b) > .c` to `.a > .c, .b > .c`.
+    ///
+    /// Note that it increases the specificity of `:where()`, and the
+    /// specificity of `:is()` becomes the one of each argument instead of the
+    /// most specific one.
+    pub(crate) fn process_selector_is(&mut self, n: &mut Vec<ComplexSelector>) {
+        let mut new = Vec::with_capacity(n.len());
+
+        for selector in n.drain(..) {
+            match expand_complex_selector(&selector) {
+                Some(selectors) => new.extend(selectors),
+                None => new.push(selector),
+            }
+        }
+
+        *n = new;
+    }
+}
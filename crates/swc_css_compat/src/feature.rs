@@ -1,7 +1,13 @@
 use bitflags::bitflags;
+use once_cell::sync::Lazy;
+use preset_env_base::{
+    version::{should_enable, Version},
+    BrowserData, Versions,
+};
+use rustc_hash::FxHashMap;
 
 bitflags! {
-    #[derive(Debug, Clone, Copy)]
+    #[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
     pub struct Features: u64 {
         const NESTING = 1 << 0;
         const CUSTOM_MEDIA = 1 << 1;
@@ -12,5 +18,79 @@ bitflags! {
         const COLOR_LEGACY_RGB_AND_HSL = 1 << 6;
         const COLOR_HWB = 1 << 7;
         const SELECTOR_NOT = 1 << 8;
+        const COLOR_LAB = 1 << 9;
+        const COLOR_OKLAB = 1 << 10;
+        const COLOR_MIX = 1 << 11;
+        const CLAMP = 1 << 12;
+        const LOGICAL_PROPERTIES = 1 << 13;
+        const SELECTOR_IS = 1 << 14;
+        const CASCADE_LAYERS = 1 << 15;
+        /// The `Q` unit. This is not lowered, but minifiers use it.
+        const LENGTH_Q_UNIT = 1 << 16;
+    }
+}
+
+/// Minimum versions of browsers which support each feature natively.
+static FEATURES: Lazy<FxHashMap<String, Versions>> = Lazy::new(|| {
+    let map: FxHashMap<String, BrowserData<Option<String>>> =
+        serde_json::from_str(include_str!("../data/features.json")).expect("failed to parse json");
+
+    map.into_iter()
+        .map(|(feature, versions)| {
+            (
+                feature,
+                versions.map_value(|version| {
+                    version.map(|v| {
+                        v.parse::<Version>().unwrap_or_else(|err| {
+                            panic!("failed to parse `{v}` as a version: {err:?}")
+                        })
+                    })
+                }),
+            )
+        })
+        .collect()
+});
+
+impl Features {
+    /// Returns the features which should be processed because they are not
+    /// supported by some of `targets`.
+    pub fn from_versions(targets: &Versions) -> Self {
+        let mut features = Features::empty();
+
+        for (name, feature) in [
+            ("nesting", Features::NESTING),
+            ("custom-media", Features::CUSTOM_MEDIA),
+            ("media-query-ranges", Features::MEDIA_QUERY_RANGES),
+            ("color-hex-alpha", Features::COLOR_HEX_ALPHA),
+            ("color-alpha-parameter", Features::COLOR_ALPHA_PARAMETER),
+            (
+                "color-space-separated-parameters",
+                Features::COLOR_SPACE_SEPARATED_PARAMETERS,
+            ),
+            (
+                "color-legacy-rgb-and-hsl",
+                Features::COLOR_LEGACY_RGB_AND_HSL,
+            ),
+            ("color-hwb", Features::COLOR_HWB),
+            ("selector-not", Features::SELECTOR_NOT),
+            ("color-lab", Features::COLOR_LAB),
+            ("color-oklab", Features::COLOR_OKLAB),
+            ("color-mix", Features::COLOR_MIX),
+            ("clamp", Features::CLAMP),
+            ("logical-properties", Features::LOGICAL_PROPERTIES),
+            ("selector-is", Features::SELECTOR_IS),
+            ("cascade-layers", Features::CASCADE_LAYERS),
+            ("length-q-unit", Features::LENGTH_Q_UNIT),
+        ] {
+            let versions = FEATURES
+                .get(name)
+                .unwrap_or_else(|| panic!("unknown feature: {}", name));
+
+            if should_enable(targets, versions, false) {
+                features |= feature;
+            }
+        }
+
+        features
     }
 }
@@ -1,6 +1,7 @@
 use std::fmt::Debug;
 
 use serde::{Deserialize, Serialize};
+use serde_json::{json, Map, Value};
 
 use crate::rules::{
     at_rule_no_unknown::AtRuleNoUnknownConfig, color_hex_alpha::ColorHexAlphaConfig,
@@ -124,3 +125,82 @@ pub struct LintConfig {
     #[serde(default)]
     pub rules: RulesConfig,
 }
+
+/// Rules whose primary option of stylelint is the config of the rule.
+const RULES_WITH_PRIMARY_OPTION: &[&str] = &[
+    "color-hex-alpha",
+    "color-hex-length",
+    "selector-max-class",
+    "selector-max-combinators",
+];
+
+/// Rules whose secondary options of stylelint are the config of the rule.
+const RULES_WITH_SECONDARY_OPTIONS: &[&str] = &[
+    "at-rule-no-unknown",
+    "font-family-no-duplicate-names",
+    "no-invalid-position-at-import-rule",
+    "unit-no-unknown",
+];
+
+impl LintConfig {
+    /// Parse a config of stylelint, e.g. the content of `.stylelintrc.json`.
+    ///
+    /// Rules which are not supported by swc are ignored. Rules in the format
+    /// of swc, like `["error", { ... }]`, are accepted as well.
+    pub fn from_stylelint(config: Value) -> Result<Self, serde_json::Error> {
+        let default_severity = config
+            .get("defaultSeverity")
+            .and_then(Value::as_str)
+            .unwrap_or("error")
+            .to_string();
+
+        let rules = match config.get("rules") {
+            Some(Value::Object(rules)) => rules
+                .iter()
+                .map(|(name, value)| (name.clone(), convert_rule(name, value, &default_severity)))
+                .collect::<Map<_, _>>(),
+            _ => Default::default(),
+        };
+
+        serde_json::from_value(json!({ "rules": rules }))
+    }
+}
+
+/// Convert a rule of stylelint into `[level, config]`.
+fn convert_rule(name: &str, value: &Value, default_severity: &str) -> Value {
+    let (primary, secondary) = match value {
+        Value::Null | Value::Bool(false) => return json!(["off"]),
+
+        Value::Array(items) => match items.first() {
+            // Format of swc
+            Some(Value::String(level)) if matches!(&**level, "off" | "warning" | "error") => {
+                return value.clone()
+            }
+            Some(primary) => (primary, items.get(1)),
+            None => return json!(["off"]),
+        },
+
+        _ => (value, None),
+    };
+
+    if primary.is_null() {
+        return json!(["off"]);
+    }
+
+    let mut options = match secondary {
+        Some(Value::Object(options)) => options.clone(),
+        _ => Default::default(),
+    };
+    let severity = match options.remove("severity") {
+        Some(Value::String(severity)) => severity,
+        _ => default_severity.to_string(),
+    };
+
+    if RULES_WITH_PRIMARY_OPTION.contains(&name) {
+        json!([severity, primary])
+    } else if RULES_WITH_SECONDARY_OPTIONS.contains(&name) {
+        json!([severity, options])
+    } else {
+        json!([severity])
+    }
+}
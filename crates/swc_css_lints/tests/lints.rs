@@ -86,3 +86,43 @@ fn fail(input: PathBuf) {
 
     stderr.compare_to_file(stderr_path).unwrap();
 }
+
+#[testing::fixture("tests/stylelint/**/input.css")]
+fn stylelint(input: PathBuf) {
+    let stderr_path = input.parent().unwrap().join("output.swc-stderr");
+    let config_path = input.parent().unwrap().join(".stylelintrc.json");
+    let lint_config = LintConfig::from_stylelint(
+        serde_json::from_str(&fs::read_to_string(config_path).unwrap()).unwrap(),
+    )
+    .unwrap();
+
+    let stderr = testing::run_test2(false, |cm, handler| -> Result<(), _> {
+        let config = Default::default();
+
+        let fm = cm.load_file(&input).unwrap();
+        let lexer = Lexer::new(SourceFileInput::from(&*fm), None, config);
+        let mut parser = Parser::new(lexer, config);
+
+        let stylesheet = match parser.parse_all() {
+            Ok(stylesheet) => stylesheet,
+            Err(err) => {
+                err.to_diagnostics(&handler).emit();
+                panic!();
+            }
+        };
+
+        let mut rules = get_rules(&LintParams {
+            lint_config: &lint_config,
+        })
+        .unwrap();
+
+        HANDLER.set(&handler, || {
+            rules.lint_stylesheet(&stylesheet);
+        });
+
+        Err(())
+    })
+    .unwrap_err();
+
+    stderr.compare_to_file(stderr_path).unwrap();
+}
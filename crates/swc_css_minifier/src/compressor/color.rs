@@ -1,6 +1,7 @@
 use swc_atoms::Atom;
 use swc_common::DUMMY_SP;
 use swc_css_ast::*;
+use swc_css_compat::feature::Features;
 use swc_css_utils::{angle_to_deg, hsl_to_rgb, hwb_to_rgb, to_rgb255, NAMED_COLORS};
 
 use super::Compressor;
@@ -79,8 +80,12 @@ fn get_named_color_by_hex(v: u32) -> Option<&'static str> {
     Some(s)
 }
 
+fn is_integer(value: f64) -> bool {
+    (value - value.round()).abs() < 1e-6
+}
+
 macro_rules! make_color {
-    ($span:expr,$r:expr,$g:expr,$b:expr, $a:expr) => {{
+    ($compressor:expr, $span:expr,$r:expr,$g:expr,$b:expr, $a:expr) => {{
         let need_alpha_value = $a != 1.0;
 
         let r = $r.round();
@@ -88,10 +93,11 @@ macro_rules! make_color {
         let b = $b.round();
 
         if need_alpha_value {
-            // TODO improve when we will have browserslist
-            let is_alpha_hex_supported = false;
-
-            if is_alpha_hex_supported {
+            if $compressor
+                .supported_features
+                .contains(Features::COLOR_HEX_ALPHA)
+                && (!$compressor.safe || is_integer($a * 255.0))
+            {
                 let alpha = (($a * 255.0) as f64).round().max(0.0).min(255.0) as u8;
                 let hex: u32 =
                     ((r as u32) << 24) | ((g as u32) << 16) | ((b as u32) << 8) | (alpha as u32);
@@ -117,11 +123,21 @@ macro_rules! make_color {
 
                 compress_alpha_value(&mut alpha_value);
 
+                // `rgb()` accepts an alpha value in newer browsers
+                let name = if $compressor
+                    .supported_features
+                    .contains(Features::COLOR_ALPHA_PARAMETER)
+                {
+                    "rgb"
+                } else {
+                    "rgba"
+                };
+
                 Color::AbsoluteColorBase(AbsoluteColorBase::Function(Function {
                     span: $span,
                     name: FunctionName::Ident(Ident {
                         span: DUMMY_SP,
-                        value: "rgba".into(),
+                        value: name.into(),
                         raw: None,
                     }),
                     value: vec![
@@ -343,11 +359,12 @@ impl Compressor {
                 ..
             })) => match value.to_ascii_lowercase() {
                 ref s if *s == "transparent" => {
-                    *color = make_color!(*span, 0.0_f64, 0.0_f64, 0.0_f64, 0.0_f64);
+                    *color = make_color!(self, *span, 0.0_f64, 0.0_f64, 0.0_f64, 0.0_f64);
                 }
                 name => {
                     if let Some(value) = NAMED_COLORS.get(&name) {
                         *color = make_color!(
+                            self,
                             *span,
                             value.rgb[0] as f64,
                             value.rgb[1] as f64,
@@ -405,7 +422,11 @@ impl Compressor {
                     _ => return,
                 };
 
-                *color = make_color!(*span, r, g, b, a);
+                if self.safe && ![r, g, b].into_iter().all(is_integer) {
+                    return;
+                }
+
+                *color = make_color!(self, *span, r, g, b, a);
             }
             Color::AbsoluteColorBase(AbsoluteColorBase::Function(Function {
                 span,
@@ -442,7 +463,11 @@ impl Compressor {
 
                 let rgb = to_rgb255(hsl_to_rgb([h, s, l]));
 
-                *color = make_color!(*span, rgb[0], rgb[1], rgb[2], a);
+                if self.safe && !rgb.into_iter().all(is_integer) {
+                    return;
+                }
+
+                *color = make_color!(self, *span, rgb[0], rgb[1], rgb[2], a);
             }
             Color::AbsoluteColorBase(AbsoluteColorBase::Function(Function {
                 span,
@@ -469,7 +494,11 @@ impl Compressor {
 
                 let rgb = to_rgb255(hwb_to_rgb([h, w, b]));
 
-                *color = make_color!(*span, rgb[0], rgb[1], rgb[2], a);
+                if self.safe && !rgb.into_iter().all(is_integer) {
+                    return;
+                }
+
+                *color = make_color!(self, *span, rgb[0], rgb[1], rgb[2], a);
             }
             _ => {}
         }
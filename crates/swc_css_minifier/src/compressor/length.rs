@@ -1,4 +1,5 @@
 use swc_css_ast::*;
+use swc_css_compat::feature::Features;
 
 use super::Compressor;
 
@@ -137,6 +138,19 @@ impl Compressor {
     }
 
     pub(super) fn compress_length(&mut self, length: &mut Length) {
+        // Converted values may have rounding errors unless they are integers
+        let original = self.safe.then(|| length.clone());
+
+        self.convert_length_unit(length);
+
+        if let Some(original) = original {
+            if length.value.value.fract() != 0.0 {
+                *length = original;
+            }
+        }
+    }
+
+    fn convert_length_unit(&mut self, length: &mut Length) {
         let value = length.value.value;
 
         match &*length.unit.value {
@@ -196,6 +210,22 @@ impl Compressor {
                         value: "cm".into(),
                         raw: None,
                     };
+                } else if value.fract() != 0.0
+                    && (4.0 * value).fract() == 0.0
+                    && self.supported_features.contains(Features::LENGTH_Q_UNIT)
+                {
+                    let new_value = self.convert_length(value, &length.unit.value, "q");
+
+                    length.value = Number {
+                        span: length.value.span,
+                        value: new_value,
+                        raw: None,
+                    };
+                    length.unit = Ident {
+                        span: length.unit.span,
+                        value: "q".into(),
+                        raw: None,
+                    };
                 }
             }
             "q" => {
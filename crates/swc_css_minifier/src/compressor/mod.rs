@@ -1,8 +1,11 @@
+use preset_env_base::query::targets_to_versions;
 use swc_css_ast::*;
+use swc_css_compat::feature::Features;
 use swc_css_utils::serialize_ident;
 use swc_css_visit::{VisitMut, VisitMutWith};
 
 use self::ctx::Ctx;
+use crate::options::MinifyOptions;
 
 mod alpha_value;
 mod angle;
@@ -26,8 +29,22 @@ mod transform_function;
 mod unicode_range;
 mod url;
 
-pub fn compressor() -> impl VisitMut {
-    Compressor::default()
+pub fn compressor(options: &MinifyOptions) -> Result<impl VisitMut, anyhow::Error> {
+    let supported_features = match options.targets.clone() {
+        Some(targets) => {
+            let versions = targets_to_versions(Some(targets), None)?;
+
+            Features::from_versions(&versions).complement()
+        }
+        None => Features::empty(),
+    };
+
+    Ok(Compressor {
+        merge_rules: options.merge_rules,
+        safe: options.safe,
+        supported_features,
+        ..Default::default()
+    })
 }
 
 #[derive(Default)]
@@ -35,6 +52,10 @@ struct Compressor {
     ctx: Ctx,
     need_utf8_at_rule: bool,
     in_supports_condition: bool,
+    merge_rules: bool,
+    safe: bool,
+    /// Features supported by all of the targets.
+    supported_features: Features,
 }
 
 impl Compressor {
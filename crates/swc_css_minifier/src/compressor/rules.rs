@@ -266,7 +266,7 @@ impl Compressor {
         left: &mut QualifiedRule,
         right: &mut QualifiedRule,
     ) -> Option<QualifiedRule> {
-        if !self.can_merge_qualified_rules(left, right) {
+        if !self.merge_rules || !self.can_merge_qualified_rules(left, right) {
             return None;
         }
 
@@ -347,6 +347,10 @@ impl Compressor {
     }
 
     fn try_merge_at_rule(&mut self, left: &mut AtRule, right: &mut AtRule) -> Option<AtRule> {
+        if !self.merge_rules {
+            return None;
+        }
+
         // Merge when both at-rule's prelude is exactly equal
         // e.g.
         // @media print { .color { color: red; } }
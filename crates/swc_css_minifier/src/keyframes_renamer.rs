@@ -0,0 +1,202 @@
+use rustc_hash::{FxHashMap, FxHashSet};
+use swc_atoms::Atom;
+use swc_common::Spanned;
+use swc_css_ast::*;
+use swc_css_visit::{Visit, VisitMut, VisitMutWith, VisitWith};
+
+use crate::is_css_wide_keyword;
+
+/// Renames `@keyframes` to short names like `a`, `b`, ..., `aa`.
+pub(crate) fn keyframes_renamer(stylesheet: &Stylesheet) -> impl VisitMut {
+    let mut collector = KeyframesCollector::default();
+
+    stylesheet.visit_with(&mut collector);
+
+    let renamed = collector
+        .keyframes
+        .into_iter()
+        .filter(|name| !collector.used_elsewhere.contains(name))
+        .collect::<Vec<_>>();
+    let mut names = FxHashMap::default();
+    let mut index = 0;
+
+    for name in &renamed {
+        let new_name = loop {
+            let new_name: Atom = generate_name(index).into();
+
+            index += 1;
+
+            // Identifiers in `animation` are either names of `@keyframes` to be
+            // renamed or other ones which we should not conflict with
+            if !collector.used_elsewhere.contains(&new_name)
+                && (!collector.idents.contains(&new_name) || renamed.contains(&new_name))
+                && !is_reserved(&new_name)
+            {
+                break new_name;
+            }
+        };
+
+        names.insert(name.clone(), new_name);
+    }
+
+    KeyframesRenamer { names }
+}
+
+/// Returns `a`, `b`, ..., `z`, `aa`, `ab`, ... for `0`, `1`, ...
+fn generate_name(mut index: usize) -> String {
+    let mut name = Vec::new();
+
+    loop {
+        name.push(b'a' + (index % 26) as u8);
+
+        if index < 26 {
+            break;
+        }
+
+        index = index / 26 - 1;
+    }
+
+    name.reverse();
+
+    String::from_utf8(name).unwrap()
+}
+
+fn is_reserved(name: &Atom) -> bool {
+    is_css_wide_keyword(name) || name.eq_ignore_ascii_case("none")
+}
+
+fn is_animation_property(name: &Atom) -> bool {
+    let name = match name.strip_prefix('-') {
+        Some(name) => match name.split_once('-') {
+            Some((_vendor, name)) => name,
+            None => return false,
+        },
+        None => name,
+    };
+
+    name.eq_ignore_ascii_case("animation") || name.eq_ignore_ascii_case("animation-name")
+}
+
+fn get_keyframes_name(at_rule: &AtRule) -> Option<&Atom> {
+    match at_rule.prelude.as_deref()? {
+        AtRulePrelude::KeyframesPrelude(KeyframesName::CustomIdent(ident)) => Some(&ident.value),
+        AtRulePrelude::KeyframesPrelude(KeyframesName::Str(string)) => Some(&string.value),
+        _ => None,
+    }
+}
+
+#[derive(Default)]
+struct KeyframesCollector {
+    /// Names of `@keyframes` in the order of appearance.
+    keyframes: Vec<Atom>,
+    /// Identifiers in `animation` and `animation-name`.
+    idents: FxHashSet<Atom>,
+    /// Identifiers which may be used as a name of `@keyframes` in a way we
+    /// can't track, e.g. in values of custom properties.
+    used_elsewhere: FxHashSet<Atom>,
+}
+
+impl Visit for KeyframesCollector {
+    fn visit_at_rule(&mut self, n: &AtRule) {
+        n.visit_children_with(self);
+
+        if let Some(name) = get_keyframes_name(n) {
+            if !is_reserved(name) && !self.keyframes.contains(name) {
+                self.keyframes.push(name.clone());
+            }
+        }
+    }
+
+    fn visit_declaration(&mut self, n: &Declaration) {
+        match &n.name {
+            DeclarationName::Ident(name) if is_animation_property(&name.value) => {
+                for value in &n.value {
+                    match value {
+                        ComponentValue::Ident(ident) => {
+                            self.idents.insert(ident.value.clone());
+                        }
+                        ComponentValue::Str(string) => {
+                            self.idents.insert(string.value.clone());
+                        }
+                        _ => {}
+                    }
+                }
+            }
+            DeclarationName::DashedIdent(_) => {
+                let mut collector = IdentCollector {
+                    idents: &mut self.used_elsewhere,
+                };
+
+                n.value.visit_with(&mut collector);
+            }
+            _ => {}
+        }
+    }
+}
+
+struct IdentCollector<'a> {
+    idents: &'a mut FxHashSet<Atom>,
+}
+
+impl Visit for IdentCollector<'_> {
+    fn visit_ident(&mut self, n: &Ident) {
+        self.idents.insert(n.value.clone());
+    }
+
+    fn visit_str(&mut self, n: &Str) {
+        self.idents.insert(n.value.clone());
+    }
+
+    fn visit_token(&mut self, n: &Token) {
+        if let Token::Ident { value, .. } | Token::String { value, .. } = n {
+            self.idents.insert(value.clone());
+        }
+    }
+}
+
+struct KeyframesRenamer {
+    names: FxHashMap<Atom, Atom>,
+}
+
+impl VisitMut for KeyframesRenamer {
+    fn visit_mut_at_rule(&mut self, n: &mut AtRule) {
+        n.visit_mut_children_with(self);
+
+        let Some(new_name) = get_keyframes_name(n).and_then(|name| self.names.get(name)) else {
+            return;
+        };
+
+        if let Some(AtRulePrelude::KeyframesPrelude(name)) = n.prelude.as_deref_mut() {
+            *name = KeyframesName::CustomIdent(Box::new(CustomIdent {
+                span: name.span(),
+                value: new_name.clone(),
+                raw: None,
+            }));
+        }
+    }
+
+    fn visit_mut_declaration(&mut self, n: &mut Declaration) {
+        n.visit_mut_children_with(self);
+
+        match &n.name {
+            DeclarationName::Ident(name) if is_animation_property(&name.value) => {}
+            _ => return,
+        }
+
+        for value in &mut n.value {
+            let (span, name) = match value {
+                ComponentValue::Ident(ident) => (ident.span, &ident.value),
+                ComponentValue::Str(string) => (string.span, &string.value),
+                _ => continue,
+            };
+
+            if let Some(new_name) = self.names.get(name).cloned() {
+                *value = ComponentValue::Ident(Box::new(Ident {
+                    span,
+                    value: new_name,
+                    raw: None,
+                }));
+            }
+        }
+    }
+}
@@ -4,17 +4,74 @@
 #![allow(clippy::mutable_key_type)]
 
 use swc_atoms::Atom;
+use swc_common::{
+    comments::{Comment, CommentKind, SingleThreadedComments},
+    BytePos,
+};
 use swc_css_ast::*;
 use swc_css_visit::VisitMutWith;
 
-use self::{compressor::compressor, options::MinifyOptions};
+use self::{
+    compressor::compressor,
+    keyframes_renamer::keyframes_renamer,
+    options::{MinifyOptions, PreserveComments},
+    z_index::z_index_rebaser,
+};
 
 mod compressor;
+mod keyframes_renamer;
 pub mod options;
 pub(crate) mod util;
+mod z_index;
 
-pub fn minify(stylesheet: &mut Stylesheet, _options: MinifyOptions) {
-    stylesheet.visit_mut_with(&mut compressor());
+/// Minifies `stylesheet`.
+///
+/// Returns an error if [MinifyOptions::targets] cannot be resolved. The
+/// stylesheet is not modified in that case.
+pub fn minify(stylesheet: &mut Stylesheet, options: MinifyOptions) -> Result<(), anyhow::Error> {
+    let mut compressor = compressor(&options)?;
+
+    if options.rename_keyframes {
+        stylesheet.visit_mut_with(&mut keyframes_renamer(stylesheet));
+    }
+
+    if options.rebase_z_index {
+        stylesheet.visit_mut_with(&mut z_index_rebaser(stylesheet));
+    }
+
+    stylesheet.visit_mut_with(&mut compressor);
+
+    Ok(())
+}
+
+/// Removes comments which should not be kept according to
+/// [MinifyOptions::preserve_comments].
+pub fn minify_comments(comments: &SingleThreadedComments, options: &MinifyOptions) {
+    match options.preserve_comments {
+        PreserveComments::All => {}
+        PreserveComments::Some => {
+            let preserve = |_: &BytePos, comments: &mut Vec<Comment>| -> bool {
+                comments.retain(|c| {
+                    (c.kind == CommentKind::Block && c.text.starts_with("/*!"))
+                        || c.text.contains("@license")
+                        || c.text.contains("@preserve")
+                        || c.text.contains("@copyright")
+                });
+
+                !comments.is_empty()
+            };
+            let (mut leading, mut trailing) = comments.borrow_all_mut();
+
+            leading.retain(preserve);
+            trailing.retain(preserve);
+        }
+        PreserveComments::None => {
+            let (mut leading, mut trailing) = comments.borrow_all_mut();
+
+            leading.clear();
+            trailing.clear();
+        }
+    }
 }
 
 #[inline]
@@ -1,6 +1,95 @@
+pub use preset_env_base::query::Targets;
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 #[serde(deny_unknown_fields)]
-pub struct MinifyOptions {}
+pub struct MinifyOptions {
+    /// Merges adjacent rules with the same selectors, the same declarations
+    /// or the same prelude of at-rules.
+    ///
+    /// Defaults to `true`.
+    #[serde(default = "true_by_default")]
+    pub merge_rules: bool,
+
+    /// Renames `@keyframes` to short names and updates `animation` and
+    /// `animation-name` referring to them.
+    ///
+    /// This is not safe if the names are used outside of the stylesheet,
+    /// e.g. from JavaScript or from other stylesheets.
+    ///
+    /// Defaults to `false`.
+    #[serde(default)]
+    pub rename_keyframes: bool,
+
+    /// Rebases positive values of `z-index` to `1`, `2`, `3`, ... keeping
+    /// the order of them.
+    ///
+    /// This is not safe if the stylesheet is used with other stylesheets or
+    /// `z-index` is set from JavaScript.
+    ///
+    /// Defaults to `false`.
+    #[serde(default)]
+    pub rebase_z_index: bool,
+
+    /// Comments to keep. Use [crate::minify_comments] to remove the other
+    /// comments from the comments of the stylesheet.
+    ///
+    /// Only comments between top-level rules are emitted by the code
+    /// generator, so comments inside of rules are removed even if they match.
+    ///
+    /// Defaults to [PreserveComments::None].
+    #[serde(default)]
+    pub preserve_comments: PreserveComments,
+
+    /// Skips transforms which may change the computed value because of
+    /// rounding, e.g. converting `hsl(210, 50%, 33%)` to a hex color or
+    /// converting fractional lengths to other units.
+    ///
+    /// Defaults to `false`.
+    #[serde(default)]
+    pub safe: bool,
+
+    /// Browsers to support. Shorter syntax is used only if all of them
+    /// support it:
+    ///
+    /// - colors with an alpha channel are emitted as `#rrggbbaa` or `#rgba`, or
+    ///   as `rgb()` instead of `rgba()` if they can't be written as hex.
+    /// - lengths in `mm` are converted to `q` if that removes the fraction.
+    ///
+    /// If not specified, the syntax supported by all browsers is used.
+    /// [crate::minify] fails if the query cannot be resolved.
+    #[serde(default, skip_serializing)]
+    pub targets: Option<Targets>,
+}
+
+impl Default for MinifyOptions {
+    fn default() -> Self {
+        Self {
+            merge_rules: true,
+            rename_keyframes: false,
+            rebase_z_index: false,
+            preserve_comments: Default::default(),
+            safe: false,
+            targets: None,
+        }
+    }
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum PreserveComments {
+    /// Removes all comments.
+    #[default]
+    None,
+    /// Keeps comments starting with `!`, like `/*! ... */`, and comments
+    /// containing `@license`, `@preserve` or `@copyright`, if they are placed
+    /// between top-level rules.
+    Some,
+    /// Keeps all comments.
+    All,
+}
+
+const fn true_by_default() -> bool {
+    true
+}
@@ -0,0 +1,79 @@
+use rustc_hash::FxHashMap;
+use swc_css_ast::*;
+use swc_css_visit::{Visit, VisitMut, VisitMutWith, VisitWith};
+
+/// Rebases positive values of `z-index` to `1`, `2`, `3`, ... keeping the
+/// order of them.
+///
+/// Nothing is changed if there's a negative value or a value which can't be
+/// known at compile time, e.g. `var(--z)`, as the order of them can't be
+/// preserved.
+pub(crate) fn z_index_rebaser(stylesheet: &Stylesheet) -> impl VisitMut {
+    let mut collector = ZIndexCollector::default();
+
+    stylesheet.visit_with(&mut collector);
+
+    let mut values = FxHashMap::default();
+
+    if !collector.abort {
+        collector.values.sort_unstable();
+        collector.values.dedup();
+
+        for (i, value) in collector.values.into_iter().enumerate() {
+            values.insert(value, i as i64 + 1);
+        }
+    }
+
+    ZIndexRebaser { values }
+}
+
+fn is_z_index(n: &Declaration) -> bool {
+    matches!(&n.name, DeclarationName::Ident(name) if name.value.eq_ignore_ascii_case("z-index"))
+}
+
+#[derive(Default)]
+struct ZIndexCollector {
+    values: Vec<i64>,
+    abort: bool,
+}
+
+impl Visit for ZIndexCollector {
+    fn visit_declaration(&mut self, n: &Declaration) {
+        if !is_z_index(n) {
+            return;
+        }
+
+        match &*n.value {
+            [ComponentValue::Integer(integer)] if integer.value > 0 => {
+                self.values.push(integer.value);
+            }
+            [ComponentValue::Integer(integer)] if integer.value == 0 => {}
+            // `auto` and CSS-wide keywords
+            [ComponentValue::Ident(_)] => {}
+            _ => {
+                self.abort = true;
+            }
+        }
+    }
+}
+
+struct ZIndexRebaser {
+    values: FxHashMap<i64, i64>,
+}
+
+impl VisitMut for ZIndexRebaser {
+    fn visit_mut_declaration(&mut self, n: &mut Declaration) {
+        n.visit_mut_children_with(self);
+
+        if !is_z_index(n) {
+            return;
+        }
+
+        if let [ComponentValue::Integer(integer)] = &mut *n.value {
+            if let Some(value) = self.values.get(&integer.value) {
+                integer.value = *value;
+                integer.raw = None;
+            }
+        }
+    }
+}
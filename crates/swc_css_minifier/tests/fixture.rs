@@ -1,11 +1,12 @@
-use std::path::PathBuf;
+use std::{fs, path::PathBuf};
 
+use swc_common::comments::SingleThreadedComments;
 use swc_css_ast::Stylesheet;
 use swc_css_codegen::{
     writer::basic::{BasicCssWriter, BasicCssWriterConfig},
     CodeGenerator, CodegenConfig, Emit,
 };
-use swc_css_minifier::minify;
+use swc_css_minifier::{minify, minify_comments, options::MinifyOptions};
 use swc_css_parser::parse_file;
 use testing::NormalizedOutput;
 
@@ -34,7 +35,7 @@ fn minify_fixtures(input: PathBuf) {
         let mut ss = res.unwrap();
 
         // Apply transforms
-        minify(&mut ss, Default::default());
+        minify(&mut ss, Default::default()).unwrap();
 
         let mut css_str = String::new();
         {
@@ -52,3 +53,62 @@ fn minify_fixtures(input: PathBuf) {
     })
     .unwrap();
 }
+
+#[testing::fixture("tests/options/**/input.css")]
+fn minify_with_options(input: PathBuf) {
+    let dir = input.parent().unwrap();
+    let output = dir.join("output.min.css");
+    let options: MinifyOptions =
+        serde_json::from_str(&fs::read_to_string(dir.join("options.json")).unwrap()).unwrap();
+
+    testing::run_test(false, |cm, handler| {
+        let fm = cm.load_file(&input).unwrap();
+        let comments = SingleThreadedComments::default();
+
+        let mut errors = Vec::new();
+        let res: Result<Stylesheet, _> =
+            parse_file(&fm, Some(&comments), Default::default(), &mut errors);
+
+        for err in errors {
+            err.to_diagnostics(handler).emit();
+        }
+
+        if handler.has_errors() {
+            return Err(());
+        }
+
+        let mut ss = res.unwrap();
+
+        // Apply transforms
+        minify_comments(&comments, &options);
+        minify(&mut ss, options).unwrap();
+
+        let mut css_str = String::new();
+        {
+            let wr = BasicCssWriter::new(&mut css_str, None, BasicCssWriterConfig::default());
+            let mut gen =
+                CodeGenerator::new(wr, CodegenConfig { minify: true }).with_comments(comments);
+
+            gen.emit(&ss).unwrap();
+        }
+
+        NormalizedOutput::from(css_str)
+            .compare_to_file(&output)
+            .unwrap();
+
+        Ok(())
+    })
+    .unwrap();
+}
+
+#[test]
+fn invalid_targets() {
+    let options: MinifyOptions = serde_json::from_str(r#"{ "targets": "not a browser" }"#).unwrap();
+
+    let mut ss = Stylesheet {
+        span: Default::default(),
+        rules: Vec::new(),
+    };
+
+    assert!(minify(&mut ss, options).is_err());
+}
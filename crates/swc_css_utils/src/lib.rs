@@ -6,8 +6,9 @@ use once_cell::sync::Lazy;
 use rustc_hash::FxHashMap;
 use serde::{Deserialize, Serialize};
 use swc_atoms::{Atom, StaticString};
+use swc_common::Span;
 use swc_css_ast::*;
-use swc_css_visit::{VisitMut, VisitMutWith};
+use swc_css_visit::{Visit, VisitMut, VisitMutWith, VisitWith};
 
 pub struct IdentReplacer<'a> {
     from: &'a str,
@@ -146,6 +147,78 @@ pub fn replace_pseudo_class_selector_on_pseudo_element_selector<N>(
     node.visit_mut_with(&mut PseudoElementOnPseudoClassReplacer { from, to });
 }
 
+/// Replaces dummy spans of nodes created by transforms with an empty span.
+///
+/// Rules, blocks, declarations, functions and selectors get the position of
+/// their first descendant which has a span, like a cloned selector, and the
+/// others get the position of the closest ancestor which has a span.
+#[derive(Default)]
+pub struct DummySpanFiller {
+    parent: Span,
+}
+
+macro_rules! fill_container {
+    ($name:ident, $T:ty) => {
+        fn $name(&mut self, n: &mut $T) {
+            if n.span.is_dummy() {
+                let mut finder = FirstSpanFinder::default();
+                n.visit_children_with(&mut finder);
+
+                n.span = finder.span.unwrap_or(self.parent).shrink_to_lo();
+            }
+
+            let old = std::mem::replace(&mut self.parent, n.span);
+            n.visit_mut_children_with(self);
+            self.parent = old;
+        }
+    };
+}
+
+impl VisitMut for DummySpanFiller {
+    fill_container!(visit_mut_qualified_rule, QualifiedRule);
+
+    fill_container!(visit_mut_at_rule, AtRule);
+
+    fill_container!(visit_mut_simple_block, SimpleBlock);
+
+    fill_container!(visit_mut_keyframe_block, KeyframeBlock);
+
+    fill_container!(visit_mut_declaration, Declaration);
+
+    fill_container!(visit_mut_function, Function);
+
+    fill_container!(visit_mut_complex_selector, ComplexSelector);
+
+    fn visit_mut_span(&mut self, n: &mut Span) {
+        if n.is_dummy() {
+            *n = self.parent.shrink_to_lo();
+        }
+    }
+}
+
+#[derive(Default)]
+struct FirstSpanFinder {
+    span: Option<Span>,
+}
+
+impl Visit for FirstSpanFinder {
+    fn visit_span(&mut self, n: &Span) {
+        if self.span.is_none() && !n.is_dummy() {
+            self.span = Some(*n);
+        }
+    }
+}
+
+/// Fills dummy spans of nodes created by transforms, like prefixed
+/// declarations or lowered rules, so the generated source map has mappings
+/// for them.
+pub fn fill_dummy_spans<N>(node: &mut N)
+where
+    N: VisitMutWith<DummySpanFiller>,
+{
+    node.visit_mut_with(&mut DummySpanFiller::default());
+}
+
 #[derive(Serialize, Deserialize, Debug)]
 pub struct NamedColor {
     pub hex: String,
@@ -328,6 +401,176 @@ pub fn hsl_to_rgb(hsl: [f64; 3]) -> [f64; 3] {
     [r, g, b]
 }
 
+/// Converts a gamma-encoded sRGB component to a linear-light one.
+pub fn srgb_to_linear(c: f64) -> f64 {
+    let abs = c.abs();
+
+    if abs <= 0.04045 {
+        c / 12.92
+    } else {
+        c.signum() * ((abs + 0.055) / 1.055).powf(2.4)
+    }
+}
+
+/// Converts a linear-light sRGB component to a gamma-encoded one.
+pub fn linear_to_srgb(c: f64) -> f64 {
+    let abs = c.abs();
+
+    if abs > 0.0031308 {
+        c.signum() * (1.055 * abs.powf(1.0 / 2.4) - 0.055)
+    } else {
+        12.92 * c
+    }
+}
+
+fn multiply_matrix(m: [[f64; 3]; 3], v: [f64; 3]) -> [f64; 3] {
+    [
+        m[0][0] * v[0] + m[0][1] * v[1] + m[0][2] * v[2],
+        m[1][0] * v[0] + m[1][1] * v[1] + m[1][2] * v[2],
+        m[2][0] * v[0] + m[2][1] * v[1] + m[2][2] * v[2],
+    ]
+}
+
+/// Converts polar coordinates (`lch()` and `oklch()`) to rectangular ones
+/// (`lab()` and `oklab()`).
+pub fn lch_to_lab(lch: [f64; 3]) -> [f64; 3] {
+    let [l, c, h] = lch;
+    let h = h.to_radians();
+
+    [l, c * h.cos(), c * h.sin()]
+}
+
+/// Converts rectangular coordinates (`lab()` and `oklab()`) to polar ones
+/// (`lch()` and `oklch()`).
+pub fn lab_to_lch(lab: [f64; 3]) -> [f64; 3] {
+    let [l, a, b] = lab;
+    let mut h = b.atan2(a).to_degrees();
+
+    if h < 0.0 {
+        h += 360.0;
+    }
+
+    [l, (a * a + b * b).sqrt(), h]
+}
+
+/// Converts CIE Lab (D50) to sRGB. The result may be out of the `0..=1`
+/// range if the color is not in the sRGB gamut.
+pub fn lab_to_rgb(lab: [f64; 3]) -> [f64; 3] {
+    // https://drafts.csswg.org/css-color-4/#color-conversion-code
+    const KAPPA: f64 = 24389.0 / 27.0;
+    const EPSILON: f64 = 216.0 / 24389.0;
+    const D50: [f64; 3] = [0.3457 / 0.3585, 1.0, (1.0 - 0.3457 - 0.3585) / 0.3585];
+    const D50_TO_D65: [[f64; 3]; 3] = [
+        [
+            0.955_473_452_704_218_2,
+            -0.023_098_536_874_261_423,
+            0.063_259_308_661_021_7,
+        ],
+        [
+            -0.028_369_706_963_208_136,
+            1.009_995_458_005_822_6,
+            0.021_041_398_966_943_008,
+        ],
+        [
+            0.012_314_001_688_319_899,
+            -0.020_507_696_433_477_912,
+            1.330_365_936_608_075_3,
+        ],
+    ];
+    const XYZ_TO_LINEAR_SRGB: [[f64; 3]; 3] = [
+        [
+            3.240_969_941_904_522_6,
+            -1.537_383_177_570_094,
+            -0.498_610_760_293_003_4,
+        ],
+        [
+            -0.969_243_636_280_879_6,
+            1.875_967_501_507_720_2,
+            0.041_555_057_407_175_59,
+        ],
+        [
+            0.055_630_079_696_993_66,
+            -0.203_976_958_888_976_52,
+            1.056_971_514_242_878_6,
+        ],
+    ];
+
+    let [l, a, b] = lab;
+
+    let f1 = (l + 16.0) / 116.0;
+    let f0 = a / 500.0 + f1;
+    let f2 = f1 - b / 200.0;
+
+    let x = if f0.powi(3) > EPSILON {
+        f0.powi(3)
+    } else {
+        (116.0 * f0 - 16.0) / KAPPA
+    };
+    let y = if l > KAPPA * EPSILON {
+        f1.powi(3)
+    } else {
+        l / KAPPA
+    };
+    let z = if f2.powi(3) > EPSILON {
+        f2.powi(3)
+    } else {
+        (116.0 * f2 - 16.0) / KAPPA
+    };
+
+    let xyz = multiply_matrix(D50_TO_D65, [x * D50[0], y * D50[1], z * D50[2]]);
+    let [r, g, b] = multiply_matrix(XYZ_TO_LINEAR_SRGB, xyz);
+
+    [linear_to_srgb(r), linear_to_srgb(g), linear_to_srgb(b)]
+}
+
+/// Converts OKLab to sRGB. The result may be out of the `0..=1` range if the
+/// color is not in the sRGB gamut.
+pub fn oklab_to_rgb(oklab: [f64; 3]) -> [f64; 3] {
+    // https://bottosson.github.io/posts/oklab/
+    let [l, a, b] = oklab;
+
+    let l_ = l + 0.396_337_777_4 * a + 0.215_803_757_3 * b;
+    let m_ = l - 0.105_561_345_8 * a - 0.063_854_172_8 * b;
+    let s_ = l - 0.089_484_177_5 * a - 1.291_485_548 * b;
+
+    let [r, g, b] = multiply_matrix(
+        [
+            [4.076_741_662_1, -3.307_711_591_3, 0.230_969_929_2],
+            [-1.268_438_004_6, 2.609_757_401_1, -0.341_319_396_5],
+            [-0.004_196_086_3, -0.703_418_614_7, 1.707_614_701],
+        ],
+        [l_.powi(3), m_.powi(3), s_.powi(3)],
+    );
+
+    [linear_to_srgb(r), linear_to_srgb(g), linear_to_srgb(b)]
+}
+
+/// Converts sRGB to OKLab.
+pub fn rgb_to_oklab(rgb: [f64; 3]) -> [f64; 3] {
+    // https://bottosson.github.io/posts/oklab/
+    let [l, m, s] = multiply_matrix(
+        [
+            [0.412_221_470_8, 0.536_332_536_3, 0.051_445_992_9],
+            [0.211_903_498_2, 0.680_699_545_1, 0.107_396_956_6],
+            [0.088_302_461_9, 0.281_718_837_6, 0.629_978_700_5],
+        ],
+        [
+            srgb_to_linear(rgb[0]),
+            srgb_to_linear(rgb[1]),
+            srgb_to_linear(rgb[2]),
+        ],
+    );
+
+    multiply_matrix(
+        [
+            [0.210_454_255_3, 0.793_617_785, -0.004_072_046_8],
+            [1.977_998_495_1, -2.428_592_205, 0.450_593_709_9],
+            [0.025_904_037_1, 0.782_771_766_2, -0.808_675_766],
+        ],
+        [l.cbrt(), m.cbrt(), s.cbrt()],
+    )
+}
+
 pub fn to_rgb255(abc: [f64; 3]) -> [f64; 3] {
     let mut abc255 = abc;
 
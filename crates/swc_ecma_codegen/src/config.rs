@@ -51,6 +51,37 @@ pub struct Config {
     /// Defaults to `true`
     #[cfg_attr(feature = "serde-impl", serde(default = "true_by_default"))]
     pub reduce_escaped_newline: bool,
+
+    /// Quotes to use for string literals.
+    ///
+    /// Quotes of JSX attributes are kept as-is when not minifying, as they
+    /// can't contain escapes.
+    ///
+    /// Defaults to [QuoteStyle::Auto].
+    #[cfg_attr(feature = "serde-impl", serde(default))]
+    pub quote_style: QuoteStyle,
+
+    /// Where to emit trailing commas in lists printed on multiple lines.
+    ///
+    /// Defaults to [TrailingComma::None].
+    #[cfg_attr(feature = "serde-impl", serde(default))]
+    pub trailing_comma: TrailingComma,
+
+    /// If set, arguments of calls are printed on multiple lines if the line
+    /// would be longer than this.
+    ///
+    /// This requires a writer which tracks the current column, like
+    /// [crate::text_writer::JsWriter].
+    #[cfg_attr(feature = "serde-impl", serde(default))]
+    pub print_width: Option<usize>,
+
+    /// Indentation of lines. Indentation is written by the writer, so this
+    /// should be passed to it, e.g. with
+    /// [crate::text_writer::JsWriter::set_indent_str].
+    ///
+    /// Defaults to four spaces.
+    #[cfg_attr(feature = "serde-impl", serde(default))]
+    pub indent: Indent,
 }
 
 impl Default for Config {
@@ -63,6 +94,10 @@ impl Default for Config {
             emit_assert_for_import_attributes: false,
             inline_script: false,
             reduce_escaped_newline: true,
+            quote_style: Default::default(),
+            trailing_comma: Default::default(),
+            print_width: None,
+            indent: Default::default(),
         }
     }
 }
@@ -109,4 +144,85 @@ impl Config {
         self.reduce_escaped_newline = reduce_escaped_newline;
         self
     }
+
+    pub fn with_quote_style(mut self, quote_style: QuoteStyle) -> Self {
+        self.quote_style = quote_style;
+        self
+    }
+
+    pub fn with_trailing_comma(mut self, trailing_comma: TrailingComma) -> Self {
+        self.trailing_comma = trailing_comma;
+        self
+    }
+
+    pub fn with_print_width(mut self, print_width: Option<usize>) -> Self {
+        self.print_width = print_width;
+        self
+    }
+
+    pub fn with_indent(mut self, indent: Indent) -> Self {
+        self.indent = indent;
+        self
+    }
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde-impl", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde-impl", serde(rename_all = "camelCase"))]
+pub enum QuoteStyle {
+    /// Keeps the quotes of the input if possible. Otherwise, uses double
+    /// quotes unless the string contains more double quotes than single
+    /// quotes.
+    #[default]
+    Auto,
+    /// Uses double quotes unless the string contains more double quotes than
+    /// single quotes.
+    Double,
+    /// Uses single quotes unless the string contains more single quotes than
+    /// double quotes.
+    Single,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde-impl", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde-impl", serde(rename_all = "camelCase"))]
+pub enum TrailingComma {
+    /// Emits trailing commas only where they are required, e.g. for a hole at
+    /// the end of an array.
+    #[default]
+    None,
+    /// Emits trailing commas where ES5 allows them, i.e. in array literals,
+    /// object literals and enums.
+    Es5,
+    /// Emits trailing commas in arguments of calls too, if the target is
+    /// ES2017 or later.
+    All,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde-impl", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde-impl", serde(rename_all = "camelCase"))]
+pub enum Indent {
+    /// Indents with a tab.
+    Tab,
+    /// Indents with the given number of spaces, up to 16.
+    Spaces(u8),
+}
+
+impl Default for Indent {
+    fn default() -> Self {
+        Indent::Spaces(4)
+    }
+}
+
+impl Indent {
+    /// Returns the string to write for each level of indentation.
+    pub fn as_str(self) -> &'static str {
+        const SPACES: &str = "                ";
+
+        match self {
+            Indent::Tab => "\t",
+            Indent::Spaces(n) => &SPACES[..SPACES.len().min(n as usize)],
+        }
+    }
 }
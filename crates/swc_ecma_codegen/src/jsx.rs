@@ -3,7 +3,7 @@ use swc_ecma_ast::*;
 use swc_ecma_codegen_macros::node_impl;
 
 use super::Emitter;
-use crate::text_writer::WriteJs;
+use crate::{config::QuoteStyle, text_writer::WriteJs};
 
 impl<W, S: SourceMapper> Emitter<'_, W, S>
 where
@@ -85,6 +85,16 @@ impl MacroNode for JSXAttr {
 impl MacroNode for JSXAttrValue {
     fn emit(&mut self, emitter: &mut Macro) -> Result {
         match *self {
+            // Strings in JSX can't contain escapes, so the quotes can't be changed
+            JSXAttrValue::Lit(Lit::Str(Str {
+                span,
+                raw: Some(ref raw),
+                ..
+            })) if !emitter.cfg.minify && emitter.cfg.quote_style != QuoteStyle::Auto => {
+                emitter.emit_leading_comments_of_span(span, false)?;
+
+                emitter.wr.write_str_lit(span, raw)?;
+            }
             JSXAttrValue::Lit(ref n) => emit!(n),
             JSXAttrValue::JSXExprContainer(ref n) => emit!(n),
             JSXAttrValue::JSXElement(ref n) => emit!(n),
@@ -19,7 +19,7 @@ use swc_common::{
 use swc_ecma_ast::*;
 use swc_ecma_codegen_macros::node_impl;
 
-pub use self::config::Config;
+pub use self::config::{Config, Indent, QuoteStyle, TrailingComma};
 use self::{text_writer::WriteJs, util::StartsWithAlphaNum};
 use crate::util::EndsWithAlphaNum;
 
@@ -333,6 +333,8 @@ where
         nodes: &[ExprOrSpread],
         format: ListFormat,
     ) -> Result {
+        let format = self.wrap_list_format(parent_node, nodes, format)?;
+
         self.emit_list(parent_node, Some(nodes), format)
     }
 
@@ -521,12 +523,23 @@ where
                 }
             };
 
+        let should_write_closing_line_terminator =
+            self.cm
+                .should_write_closing_line_terminator(parent_node, last_child, format);
+
         if has_trailing_comma
             && format.contains(ListFormat::CommaDelimited)
             && (!self.cfg.minify || !format.contains(ListFormat::CanSkipTrailingComma))
         {
             punct!(self, ",");
             formatting_space!(self);
+        } else if should_write_closing_line_terminator
+            && last_child.is_some()
+            && !self.cfg.minify
+            && format.contains(ListFormat::CommaDelimited)
+            && self.should_emit_trailing_comma(format)
+        {
+            punct!(self, ",");
         }
 
         {
@@ -562,10 +575,7 @@ where
         }
 
         // Write the closing line terminator or closing whitespace.
-        if self
-            .cm
-            .should_write_closing_line_terminator(parent_node, last_child, format)
-        {
+        if should_write_closing_line_terminator {
             if !self.cfg.minify {
                 self.wr.write_line()?;
             }
@@ -576,6 +586,79 @@ where
         Ok(())
     }
 
+    /// Returns true if [Config::trailing_comma] allows a trailing comma in a
+    /// multi-line list of `format`.
+    fn should_emit_trailing_comma(&self, format: ListFormat) -> bool {
+        let is_call_like = format.intersects(ListFormat::Parenthesis | ListFormat::AngleBrackets);
+
+        match self.cfg.trailing_comma {
+            TrailingComma::None => false,
+            TrailingComma::Es5 => !is_call_like && self.cfg.target >= EsVersion::Es5,
+            TrailingComma::All => {
+                if is_call_like {
+                    self.cfg.target >= EsVersion::Es2017
+                } else {
+                    self.cfg.target >= EsVersion::Es5
+                }
+            }
+        }
+    }
+
+    /// Returns `format` changed to print each element in a line if printing
+    /// `children` in the current line exceeds [Config::print_width].
+    fn wrap_list_format<N: Node>(
+        &mut self,
+        parent_node: Span,
+        children: &[N],
+        format: ListFormat,
+    ) -> io::Result<ListFormat> {
+        let Some(print_width) = self.cfg.print_width else {
+            return Ok(format);
+        };
+
+        if self.cfg.minify || children.is_empty() {
+            return Ok(format);
+        }
+
+        let Some(column) = self.wr.current_column() else {
+            return Ok(format);
+        };
+
+        // The closing bracket and a following `;` or `,`
+        let Some(limit) = print_width.checked_sub(column + 2) else {
+            return Ok(format | ListFormat::MultiLine | ListFormat::Indented);
+        };
+
+        // Printing stops as soon as the width is known, so nested lists cost
+        // at most `print_width` bytes for each of their ancestors.
+        let mut counter = WidthCounter {
+            width: 0,
+            limit,
+            done: false,
+        };
+
+        {
+            let mut emitter = Emitter {
+                cfg: Config {
+                    print_width: None,
+                    ..self.cfg
+                },
+                cm: self.cm.clone(),
+                comments: None,
+                wr: text_writer::JsWriter::new(Default::default(), "\n", &mut counter, None),
+            };
+
+            // Fails once the counter is done.
+            let _ = emitter.emit_list(parent_node, Some(children), format);
+        }
+
+        if counter.width > limit {
+            Ok(format | ListFormat::MultiLine | ListFormat::Indented)
+        } else {
+            Ok(format)
+        }
+    }
+
     /// This method exists to reduce compile time.
     #[inline(never)]
     fn emit_last_of_list5(
@@ -2323,3 +2406,38 @@ impl MacroNode for IdentName {
         Ok(())
     }
 }
+
+/// Counts bytes of the first line written to it, and fails once the line is
+/// done or longer than `limit` to stop the emitter.
+struct WidthCounter {
+    width: usize,
+    limit: usize,
+    done: bool,
+}
+
+impl io::Write for WidthCounter {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        if !self.done {
+            match buf.iter().position(|&c| c == b'\n') {
+                Some(pos) => {
+                    self.width += pos;
+                    self.done = true;
+                }
+                None => {
+                    self.width += buf.len();
+                    self.done = self.width > self.limit;
+                }
+            }
+        }
+
+        if self.done {
+            return Err(io::Error::new(io::ErrorKind::Other, "measured the width"));
+        }
+
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
@@ -6,7 +6,7 @@ use swc_common::{Spanned, DUMMY_SP};
 use swc_ecma_ast::*;
 use swc_ecma_codegen_macros::node_impl;
 
-use crate::{text_writer::WriteJs, CowStr, Emitter, SourceMapperExt};
+use crate::{config::QuoteStyle, text_writer::WriteJs, CowStr, Emitter, SourceMapperExt};
 
 #[node_impl]
 impl MacroNode for Lit {
@@ -76,7 +76,14 @@ impl MacroNode for Str {
                     _ => true,
                 };
 
+                let quote_matches = match emitter.cfg.quote_style {
+                    QuoteStyle::Auto => true,
+                    QuoteStyle::Double => raw.starts_with('"'),
+                    QuoteStyle::Single => raw.starts_with('\''),
+                };
+
                 if es5_safe
+                    && quote_matches
                     && (!emitter.cfg.ascii_only || raw.is_ascii())
                     && (!emitter.cfg.inline_script
                         || !self.raw.as_ref().unwrap().contains("script"))
@@ -87,7 +94,12 @@ impl MacroNode for Str {
             }
         }
 
-        let (quote_char, mut value) = get_quoted_utf16(&self.value, emitter.cfg.ascii_only, target);
+        let (quote_char, mut value) = get_quoted_utf16(
+            &self.value,
+            emitter.cfg.ascii_only,
+            target,
+            emitter.cfg.quote_style,
+        );
 
         if emitter.cfg.inline_script {
             value = CowStr::Owned(
@@ -314,8 +326,35 @@ where
     }
 }
 
+/// Returns the quote which needs less escapes, preferring single quotes if
+/// `prefer_single` is true.
+fn choose_quote(
+    single_quote_count: usize,
+    double_quote_count: usize,
+    prefer_single: bool,
+) -> AsciiChar {
+    if prefer_single {
+        if single_quote_count > double_quote_count {
+            AsciiChar::Quotation
+        } else {
+            AsciiChar::Apostrophe
+        }
+    } else if double_quote_count > single_quote_count {
+        AsciiChar::Apostrophe
+    } else {
+        AsciiChar::Quotation
+    }
+}
+
 /// Returns `(quote_char, value)`
-pub fn get_quoted_utf16(v: &str, ascii_only: bool, target: EsVersion) -> (AsciiChar, CowStr) {
+pub fn get_quoted_utf16(
+    v: &str,
+    ascii_only: bool,
+    target: EsVersion,
+    quote_style: QuoteStyle,
+) -> (AsciiChar, CowStr) {
+    let prefer_single = quote_style == QuoteStyle::Single;
+
     // Fast path: If the string is ASCII and doesn't need escaping, we can avoid
     // allocation
     if v.is_ascii() {
@@ -337,11 +376,7 @@ pub fn get_quoted_utf16(v: &str, ascii_only: bool, target: EsVersion) -> (AsciiC
         }
 
         if !needs_escaping {
-            let quote_char = if double_quote_count > single_quote_count {
-                AsciiChar::Apostrophe
-            } else {
-                AsciiChar::Quotation
-            };
+            let quote_char = choose_quote(single_quote_count, double_quote_count, prefer_single);
 
             // If there are no quotes to escape, we can return the original string
             if (quote_char == AsciiChar::Apostrophe && single_quote_count == 0)
@@ -364,11 +399,7 @@ pub fn get_quoted_utf16(v: &str, ascii_only: bool, target: EsVersion) -> (AsciiC
     }
 
     // Pre-calculate capacity to avoid reallocations
-    let quote_char = if double_quote_count > single_quote_count {
-        AsciiChar::Apostrophe
-    } else {
-        AsciiChar::Quotation
-    };
+    let quote_char = choose_quote(single_quote_count, double_quote_count, prefer_single);
     let escape_char = if quote_char == AsciiChar::Apostrophe {
         AsciiChar::Apostrophe
     } else {
@@ -596,7 +596,12 @@ fn test_get_quoted_utf16() {
     #[track_caller]
     fn es2020(src: &str, expected: &str) {
         assert_eq!(
-            combine(get_quoted_utf16(src, true, EsVersion::Es2020)),
+            combine(get_quoted_utf16(
+                src,
+                true,
+                EsVersion::Es2020,
+                QuoteStyle::Auto
+            )),
             expected
         )
     }
@@ -604,7 +609,12 @@ fn test_get_quoted_utf16() {
     #[track_caller]
     fn es2020_nonascii(src: &str, expected: &str) {
         assert_eq!(
-            combine(get_quoted_utf16(src, true, EsVersion::Es2020)),
+            combine(get_quoted_utf16(
+                src,
+                true,
+                EsVersion::Es2020,
+                QuoteStyle::Auto
+            )),
             expected
         )
     }
@@ -612,7 +622,12 @@ fn test_get_quoted_utf16() {
     #[track_caller]
     fn es5(src: &str, expected: &str) {
         assert_eq!(
-            combine(get_quoted_utf16(src, true, EsVersion::Es5)),
+            combine(get_quoted_utf16(
+                src,
+                true,
+                EsVersion::Es5,
+                QuoteStyle::Auto
+            )),
             expected
         )
     }
@@ -681,7 +696,7 @@ fn issue_1619_2() {
 #[test]
 fn issue_1619_3() {
     assert_eq!(
-        &*get_quoted_utf16("\x00\x31", true, EsVersion::Es3).1,
+        &*get_quoted_utf16("\x00\x31", true, EsVersion::Es3, QuoteStyle::Auto).1,
         "\\x001"
     );
 }
@@ -1,4 +1,7 @@
-pub use self::{basic_impl::JsWriter, semicolon::omit_trailing_semi};
+pub use self::{
+    basic_impl::JsWriter,
+    semicolon::{omit_semi, omit_trailing_semi},
+};
 use super::*;
 
 mod basic_impl;
@@ -41,6 +44,13 @@ pub trait WriteJs {
 
     fn commit_pending_semi(&mut self) -> Result;
 
+    /// Returns the width of the current line written so far, if known.
+    ///
+    /// This is used to wrap lists exceeding [crate::Config::print_width].
+    fn current_column(&self) -> Option<usize> {
+        None
+    }
+
     /// If true, the code generator will skip **modification** of invalid
     /// unicode characters.
     ///
@@ -143,6 +153,11 @@ where
         (**self).commit_pending_semi()
     }
 
+    #[inline]
+    fn current_column(&self) -> Option<usize> {
+        (**self).current_column()
+    }
+
     #[inline(always)]
     fn can_ignore_invalid_unicodes(&mut self) -> bool {
         (**self).can_ignore_invalid_unicodes()
@@ -243,6 +258,11 @@ where
         (**self).commit_pending_semi()
     }
 
+    #[inline]
+    fn current_column(&self) -> Option<usize> {
+        (**self).current_column()
+    }
+
     #[inline(always)]
     fn can_ignore_invalid_unicodes(&mut self) -> bool {
         (**self).can_ignore_invalid_unicodes()
@@ -18,6 +18,9 @@ pub struct JsWriter<'a, W: Write> {
     line_start: bool,
     line_count: usize,
     line_pos: usize,
+    /// Byte offset from the start of the current line, tracked regardless of
+    /// source maps.
+    column: usize,
     new_line: &'a str,
     srcmap: Option<&'a mut Vec<(BytePos, LineCol)>>,
     srcmap_done: HashSet<(BytePos, u32, u32), FxBuildHasher>,
@@ -39,6 +42,7 @@ impl<'a, W: Write> JsWriter<'a, W> {
             line_start: true,
             line_count: 0,
             line_pos: Default::default(),
+            column: Default::default(),
             new_line,
             srcmap,
             wr,
@@ -67,6 +71,7 @@ impl<'a, W: Write> JsWriter<'a, W> {
         if self.srcmap.is_some() {
             self.line_pos += self.indent_str.len() * self.indent;
         }
+        self.column += self.indent_str.len() * self.indent;
 
         Ok(())
     }
@@ -107,6 +112,11 @@ impl<'a, W: Write> JsWriter<'a, W> {
 
     #[inline]
     fn update_pos(&mut self, s: &str) {
+        match s.rfind('\n') {
+            Some(idx) => self.column = s.len() - idx - 1,
+            None => self.column += s.len(),
+        }
+
         if self.srcmap.is_some() {
             let line_start_of_s = compute_line_starts(s);
             self.line_count += line_start_of_s.line_count;
@@ -200,6 +210,7 @@ impl<W: Write> WriteJs for JsWriter<'_, W> {
                 self.line_count += 1;
                 self.line_pos = 0;
             }
+            self.column = 0;
             self.line_start = true;
 
             if let Some(pending) = pending {
@@ -273,6 +284,14 @@ impl<W: Write> WriteJs for JsWriter<'_, W> {
         Ok(())
     }
 
+    fn current_column(&self) -> Option<usize> {
+        if self.line_start {
+            Some(self.indent_str.len() * self.indent)
+        } else {
+            Some(self.column)
+        }
+    }
+
     #[inline]
     fn commit_pending_semi(&mut self) -> Result {
         Ok(())
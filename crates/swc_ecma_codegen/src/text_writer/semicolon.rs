@@ -98,6 +98,164 @@ impl<W: WriteJs> WriteJs for OmitTrailingSemi<W> {
         Ok(())
     }
 
+    #[inline]
+    fn current_column(&self) -> Option<usize> {
+        self.inner.current_column()
+    }
+
+    #[inline(always)]
+    fn can_ignore_invalid_unicodes(&mut self) -> bool {
+        self.inner.can_ignore_invalid_unicodes()
+    }
+}
+
+/// Omits semicolons which can be inserted by the automatic semicolon
+/// insertion, i.e. semicolons followed by a line break.
+///
+/// A semicolon is still written if the next line starts with a token which
+/// would continue the previous statement, like `(`, `[` or `in`, or if the
+/// previous token is `get`, `set` or `static`, which would become a modifier
+/// of the next class member.
+pub fn omit_semi<W: WriteJs>(w: W) -> impl WriteJs {
+    OmitSemi {
+        inner: w,
+        pending_semi: None,
+        line_start: true,
+        after_modifier: false,
+    }
+}
+
+#[derive(Debug, Clone)]
+struct OmitSemi<W: WriteJs> {
+    inner: W,
+    pending_semi: Option<Span>,
+    line_start: bool,
+    /// Whether the last token is a word which is a modifier if it's followed
+    /// by a class member, like `get` in `class A { get; foo() {} }`.
+    after_modifier: bool,
+}
+
+impl<W: WriteJs> OmitSemi<W> {
+    fn resolve_pending_semi(&mut self, data: &str) -> Result {
+        if let Some(span) = self.pending_semi.take() {
+            let is_hazard = data.starts_with(['(', '[', '`', '+', '-', '/', '<', '*'])
+                || matches!(data, "in" | "instanceof");
+
+            if is_hazard || (!self.line_start && data != "}") {
+                self.inner.write_semi(Some(span))?;
+            }
+        }
+
+        if !data.is_empty() {
+            self.line_start = false;
+        }
+
+        if !data.trim().is_empty() {
+            self.after_modifier = matches!(data, "get" | "set" | "static");
+        }
+
+        Ok(())
+    }
+}
+
+macro_rules! with_pending_semi {
+    (
+        $fn_name:ident
+        (
+            $(
+                $arg_name:ident
+                :
+                $arg_ty:ty
+            ),*
+        ),
+        $data:ident
+    ) => {
+        fn $fn_name(&mut self, $($arg_name: $arg_ty),* ) -> Result {
+            self.resolve_pending_semi($data)?;
+
+            self.inner.$fn_name( $($arg_name),* )
+        }
+    };
+}
+
+impl<W: WriteJs> WriteJs for OmitSemi<W> {
+    with_pending_semi!(write_keyword(span: Option<Span>, s: &'static str), s);
+
+    with_pending_semi!(write_operator(span: Option<Span>, s: &str), s);
+
+    with_pending_semi!(write_param(s: &str), s);
+
+    with_pending_semi!(write_property(s: &str), s);
+
+    with_pending_semi!(write_lit(span: Span, s: &str), s);
+
+    with_pending_semi!(write_str_lit(span: Span, s: &str), s);
+
+    with_pending_semi!(write_str(s: &str), s);
+
+    with_pending_semi!(write_symbol(span: Span, s: &str), s);
+
+    with_pending_semi!(write_punct(span: Option<Span>, s: &'static str), s);
+
+    #[inline]
+    fn increase_indent(&mut self) -> Result {
+        self.inner.increase_indent()
+    }
+
+    #[inline]
+    fn decrease_indent(&mut self) -> Result {
+        self.inner.decrease_indent()
+    }
+
+    fn write_semi(&mut self, span: Option<Span>) -> Result {
+        self.resolve_pending_semi("")?;
+
+        if self.after_modifier {
+            return self.inner.write_semi(span);
+        }
+
+        self.pending_semi = Some(span.unwrap_or(DUMMY_SP));
+        Ok(())
+    }
+
+    fn write_space(&mut self) -> Result {
+        self.resolve_pending_semi(" ")?;
+
+        self.inner.write_space()
+    }
+
+    fn write_line(&mut self) -> Result {
+        self.line_start = true;
+
+        self.inner.write_line()
+    }
+
+    // Comments don't affect the automatic semicolon insertion.
+    #[inline]
+    fn write_comment(&mut self, s: &str) -> Result {
+        self.inner.write_comment(s)
+    }
+
+    #[inline]
+    fn care_about_srcmap(&self) -> bool {
+        self.inner.care_about_srcmap()
+    }
+
+    #[inline]
+    fn add_srcmap(&mut self, pos: BytePos) -> Result {
+        self.inner.add_srcmap(pos)
+    }
+
+    #[inline]
+    fn commit_pending_semi(&mut self) -> Result {
+        self.inner.commit_pending_semi()
+    }
+
+    #[inline]
+    fn current_column(&self) -> Option<usize> {
+        self.inner.current_column()
+    }
+
     #[inline(always)]
     fn can_ignore_invalid_unicodes(&mut self) -> bool {
         self.inner.can_ignore_invalid_unicodes()
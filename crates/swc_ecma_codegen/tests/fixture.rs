@@ -7,7 +7,7 @@ use serde::Deserialize;
 use swc_ecma_ast::EsVersion;
 use swc_ecma_codegen::{
     text_writer::{JsWriter, WriteJs},
-    Emitter,
+    Emitter, Indent, QuoteStyle, TrailingComma,
 };
 use swc_ecma_parser::{parse_file_as_module, Syntax, TsSyntax};
 use testing::{run_test2, NormalizedOutput};
@@ -20,12 +20,63 @@ const fn true_by_default() -> bool {
 struct TestConfig {
     #[serde(default = "true_by_default")]
     reduce_escaped_newline: bool,
+
+    /// `auto`, `double` or `single`
+    #[serde(default)]
+    quote_style: Option<String>,
+
+    /// `none`, `es5` or `all`
+    #[serde(default)]
+    trailing_comma: Option<String>,
+
+    #[serde(default)]
+    print_width: Option<usize>,
+
+    /// `tab` or the number of spaces
+    #[serde(default)]
+    indent: Option<String>,
+
+    #[serde(default = "true_by_default")]
+    semicolons: bool,
 }
 
 impl Default for TestConfig {
     fn default() -> Self {
         TestConfig {
             reduce_escaped_newline: true,
+            quote_style: None,
+            trailing_comma: None,
+            print_width: None,
+            indent: None,
+            semicolons: true,
+        }
+    }
+}
+
+impl TestConfig {
+    fn quote_style(&self) -> QuoteStyle {
+        match self.quote_style.as_deref() {
+            None | Some("auto") => QuoteStyle::Auto,
+            Some("double") => QuoteStyle::Double,
+            Some("single") => QuoteStyle::Single,
+            Some(v) => panic!("invalid quote style: {v}"),
+        }
+    }
+
+    fn trailing_comma(&self) -> TrailingComma {
+        match self.trailing_comma.as_deref() {
+            None | Some("none") => TrailingComma::None,
+            Some("es5") => TrailingComma::Es5,
+            Some("all") => TrailingComma::All,
+            Some(v) => panic!("invalid trailing comma: {v}"),
+        }
+    }
+
+    fn indent(&self) -> Indent {
+        match self.indent.as_deref() {
+            None => Indent::default(),
+            Some("tab") => Indent::Tab,
+            Some(v) => Indent::Spaces(v.parse().expect("invalid indent")),
         }
     }
 }
@@ -82,17 +133,25 @@ fn run(input: &Path, minify: bool) {
         let mut buf = Vec::new();
 
         {
-            let mut wr =
-                Box::new(JsWriter::new(cm.clone(), "\n", &mut buf, None)) as Box<dyn WriteJs>;
+            let mut writer = JsWriter::new(cm.clone(), "\n", &mut buf, None);
+            writer.set_indent_str(config.indent().as_str());
+
+            let mut wr = Box::new(writer) as Box<dyn WriteJs>;
 
             if minify {
                 wr = Box::new(swc_ecma_codegen::text_writer::omit_trailing_semi(wr));
+            } else if !config.semicolons {
+                wr = Box::new(swc_ecma_codegen::text_writer::omit_semi(wr));
             }
 
             let mut emitter = Emitter {
                 cfg: swc_ecma_codegen::Config::default()
                     .with_minify(minify)
-                    .with_reduce_escaped_newline(config.reduce_escaped_newline),
+                    .with_reduce_escaped_newline(config.reduce_escaped_newline)
+                    .with_quote_style(config.quote_style())
+                    .with_trailing_comma(config.trailing_comma())
+                    .with_print_width(config.print_width)
+                    .with_indent(config.indent()),
                 cm,
                 comments: None,
                 wr,
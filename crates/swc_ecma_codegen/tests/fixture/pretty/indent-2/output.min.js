@@ -0,0 +1 @@
+function foo(){if(a){return{b:1}}}
@@ -0,0 +1,7 @@
+function foo() {
+	if (a) {
+		return {
+			b: 1
+		};
+	}
+}
@@ -0,0 +1,7 @@
+foo(a, b);
+someFunctionWithALongName(firstArgument, secondArgument);
+new SomeConstructor(firstArgument, secondArgument, third);
+if (cond) {
+    callback(shortArg, anotherArgument, yetAnother);
+}
+outer(inner(firstArgument, secondArgument), x);
@@ -0,0 +1 @@
+const a="foo";const b="bar";const c='say "hi"';const d="it's";
@@ -0,0 +1,6 @@
+const a = "foo";
+const b = 'bar';
+const c = "it's";
+const d = "say \"hi\"";
+const e = <div className="foo" title='bar' />;
+import x from "./x";
@@ -0,0 +1,34 @@
+const a = 1
+let b = a
+;(function() {})()
+;[
+    1,
+    2
+].forEach(log)
+;`template`.length
+;+b
+for(let i = 0; i < 10; i++){
+    log(i)
+}
+class A {
+    x = 1
+    ;[b] = 2
+    y = 3
+    ;*gen() {}
+}
+class B {
+    get;
+    foo() {}
+    set;
+    bar() {}
+    static;
+    baz() {}
+    z = 1
+    ;in() {}
+    w
+    ;instanceof() {}
+}
+do {
+    b--
+}while (b)
+export default a
@@ -0,0 +1 @@
+const obj={a:1,b:2};foo(obj);someFunctionWithALongName(firstArgument,secondArgument);function f(a,...rest){}
@@ -0,0 +1,11 @@
+const obj = {
+    a: 1,
+    b: 2
+};
+const arr = [
+    1,
+    2
+];
+const empty = {};
+const { c, d } = obj;
+foo(obj, arr);
@@ -0,0 +1,153 @@
+//! Applies fixes suggested by lint rules.
+//!
+//! Rules attach fixes to their diagnostics as machine-applicable
+//! suggestions. [fix] applies the fixes which do not overlap each other, lints
+//! the result again and repeats until there is nothing left to fix.
+
+use std::ops::Range;
+
+use swc_common::{
+    errors::{Applicability, Diagnostic},
+    sync::Lrc,
+    SourceFile, Span,
+};
+
+/// The maximum number of times a file is linted and fixed.
+pub const MAX_FIX_PASSES: usize = 10;
+
+/// A replacement of a byte range of a source file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TextEdit {
+    pub range: Range<usize>,
+    pub text: String,
+}
+
+#[derive(Debug)]
+pub struct FixOutput {
+    /// The fixed source code.
+    pub code: String,
+    /// The number of fixes applied.
+    pub fixed: usize,
+    /// Diagnostics of the fixed source code, which could not be fixed
+    /// automatically.
+    pub diagnostics: Vec<Diagnostic>,
+}
+
+/// Returns the fix attached to `diagnostic`, if it's machine-applicable and
+/// all of its edits are in `fm`.
+pub fn get_fix(fm: &SourceFile, diagnostic: &Diagnostic) -> Option<Vec<TextEdit>> {
+    let suggestion = diagnostic
+        .suggestions
+        .iter()
+        .find(|s| s.applicability == Applicability::MachineApplicable)?;
+    let substitution = suggestion.substitutions.first()?;
+
+    substitution
+        .parts
+        .iter()
+        .map(|part| {
+            Some(TextEdit {
+                range: span_to_range(fm, part.span)?,
+                text: part.snippet.clone(),
+            })
+        })
+        .collect()
+}
+
+fn span_to_range(fm: &SourceFile, span: Span) -> Option<Range<usize>> {
+    if span.is_dummy() || span.lo < fm.start_pos || span.hi > fm.end_pos || span.lo > span.hi {
+        return None;
+    }
+
+    Some((span.lo - fm.start_pos).0 as usize..(span.hi - fm.start_pos).0 as usize)
+}
+
+/// Applies the fixes attached to `diagnostics` to the source code of `fm`.
+///
+/// If fixes overlap, the one which starts first wins and the others are
+/// skipped, as they will be reported again in the next pass. Returns [None]
+/// if nothing is fixed.
+pub fn apply_fixes(fm: &SourceFile, diagnostics: &[Diagnostic]) -> Option<(String, usize)> {
+    let mut fixes = diagnostics
+        .iter()
+        .filter_map(|d| get_fix(fm, d))
+        .filter(|edits| !edits.is_empty())
+        .collect::<Vec<_>>();
+    fixes.sort_by_key(|edits| edits.iter().map(|e| e.range.start).min());
+
+    let mut accepted: Vec<TextEdit> = Vec::new();
+    let mut fixed = 0;
+
+    for edits in fixes {
+        let overlaps = edits.iter().any(|edit| {
+            accepted.iter().any(|prev| {
+                (edit.range.start < prev.range.end && prev.range.start < edit.range.end)
+                    || edit.range.start == prev.range.start
+            })
+        });
+        if overlaps {
+            continue;
+        }
+
+        accepted.extend(edits);
+        fixed += 1;
+    }
+
+    if fixed == 0 {
+        return None;
+    }
+
+    accepted.sort_by_key(|edit| edit.range.start);
+
+    let src = &*fm.src;
+    let mut code = String::with_capacity(src.len());
+    let mut pos = 0;
+
+    for edit in accepted {
+        code.push_str(&src[pos..edit.range.start]);
+        code.push_str(&edit.text);
+        pos = edit.range.end;
+    }
+    code.push_str(&src[pos..]);
+
+    Some((code, fixed))
+}
+
+/// Lints `code` with `lint` and applies the fixes repeatedly, up to
+/// [MAX_FIX_PASSES] times.
+///
+/// `lint` should create a new source file for the given code and return it
+/// with the diagnostics of it.
+pub fn fix<F>(code: String, mut lint: F) -> FixOutput
+where
+    F: FnMut(String) -> (Lrc<SourceFile>, Vec<Diagnostic>),
+{
+    let mut code = code;
+    let mut fixed = 0;
+
+    for _ in 0..MAX_FIX_PASSES {
+        let (fm, diagnostics) = lint(code.clone());
+
+        match apply_fixes(&fm, &diagnostics) {
+            Some((new_code, count)) => {
+                code = new_code;
+                fixed += count;
+            }
+            None => {
+                return FixOutput {
+                    code,
+                    fixed,
+                    diagnostics,
+                }
+            }
+        }
+    }
+
+    let (_, diagnostics) = lint(code.clone());
+
+    FixOutput {
+        code,
+        fixed,
+        diagnostics,
+    }
+}
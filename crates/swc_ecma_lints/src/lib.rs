@@ -4,5 +4,6 @@
 #![allow(dead_code)]
 
 pub mod config;
+pub mod fix;
 pub mod rule;
 pub mod rules;
@@ -1,8 +1,17 @@
+use std::{
+    fmt::{self, Debug},
+    sync::Arc,
+};
+
 use dashmap::DashMap;
 use regex::Regex;
 use rustc_hash::FxBuildHasher;
 use serde::{Deserialize, Serialize};
-use swc_common::{errors::HANDLER, sync::Lazy, Span};
+use swc_common::{
+    errors::{Applicability, HANDLER},
+    sync::Lazy,
+    SourceMap, Span,
+};
 use swc_ecma_ast::*;
 use swc_ecma_visit::{noop_visit_type, Visit, VisitWith};
 
@@ -23,27 +32,59 @@ pub struct DotNotationConfig {
 
 pub fn dot_notation(
     program: &Program,
+    source_map: &Arc<SourceMap>,
     config: &RuleConfig<DotNotationConfig>,
 ) -> Option<Box<dyn Rule>> {
     match config.get_rule_reaction() {
         LintRuleReaction::Off => None,
-        _ => Some(visitor_rule(DotNotation::new(program.is_module(), config))),
+        _ => Some(visitor_rule(DotNotation::new(
+            program.is_module(),
+            source_map.clone(),
+            config,
+        ))),
     }
 }
 
-#[derive(Default, Debug)]
+#[derive(Default)]
 struct DotNotation {
+    source_map: Arc<SourceMap>,
+
     expected_reaction: LintRuleReaction,
     allow_keywords: bool,
     pattern: Option<String>,
     is_module: bool,
 }
 
+impl Debug for DotNotation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DotNotation")
+            .field("expected_reaction", &self.expected_reaction)
+            .field("allow_keywords", &self.allow_keywords)
+            .field("pattern", &self.pattern)
+            .field("is_module", &self.is_module)
+            .finish()
+    }
+}
+
+fn is_identifier_name(s: &str) -> bool {
+    let mut chars = s.chars();
+
+    match chars.next() {
+        Some(first) => Ident::is_valid_start(first) && chars.all(Ident::is_valid_continue),
+        None => false,
+    }
+}
+
 impl DotNotation {
-    fn new(is_module: bool, config: &RuleConfig<DotNotationConfig>) -> Self {
+    fn new(
+        is_module: bool,
+        source_map: Arc<SourceMap>,
+        config: &RuleConfig<DotNotationConfig>,
+    ) -> Self {
         let dot_notation_config = config.get_rule_config();
 
         Self {
+            source_map,
             expected_reaction: config.get_rule_reaction(),
             allow_keywords: dot_notation_config.allow_keywords.unwrap_or(true),
             is_module,
@@ -58,17 +99,51 @@ impl DotNotation {
             quote = quote_type.get_char()
         );
 
-        HANDLER.with(|handler| match self.expected_reaction {
-            LintRuleReaction::Error => {
-                handler.struct_span_err(span, &message).emit();
-            }
-            LintRuleReaction::Warning => {
-                handler.struct_span_warn(span, &message).emit();
+        let fix = self.get_fix(span, prop);
+
+        HANDLER.with(|handler| {
+            let mut builder = match self.expected_reaction {
+                LintRuleReaction::Error => handler.struct_span_err(span, &message),
+                LintRuleReaction::Warning => handler.struct_span_warn(span, &message),
+                _ => return,
+            };
+
+            if let Some(fix) = fix {
+                builder.span_suggestion_with_applicability(
+                    span,
+                    "Use dot notation",
+                    fix,
+                    Applicability::MachineApplicable,
+                );
             }
-            _ => {}
+
+            builder.emit();
         });
     }
 
+    /// Returns the replacement of the computed property at `span`.
+    fn get_fix(&self, span: Span, prop: &str) -> Option<String> {
+        if !is_identifier_name(prop) {
+            return None;
+        }
+
+        self.source_map
+            .with_span_to_prev_source(span, |prev| {
+                let prev = prev.trim_end();
+
+                if prev.ends_with("?.") {
+                    Some(prop.to_string())
+                } else if prev.ends_with(|c: char| c.is_ascii_digit()) {
+                    // `1["toString"]` can't be written as `1.toString`
+                    None
+                } else {
+                    Some(format!(".{}", prop))
+                }
+            })
+            .ok()
+            .flatten()
+    }
+
     fn check(&self, span: Span, quote_type: QuotesType, prop_name: &str) {
         if self.allow_keywords
             && (prop_name.is_reserved() || prop_name.is_reserved_in_strict_mode(self.is_module))
@@ -1,11 +1,20 @@
+use std::{
+    fmt::{self, Debug},
+    sync::Arc,
+};
+
 use serde::{Deserialize, Serialize};
-use swc_common::{errors::HANDLER, Span};
+use swc_common::{
+    errors::{Applicability, HANDLER},
+    SourceMap,
+};
 use swc_ecma_ast::*;
 use swc_ecma_visit::{noop_visit_type, Visit, VisitWith};
 
 use crate::{
     config::{LintRuleReaction, RuleConfig},
     rule::{visitor_rule, Rule},
+    rules::utils::bin_op_span,
 };
 
 #[derive(Debug, Clone, Copy, Serialize, Deserialize)]
@@ -27,61 +36,115 @@ pub struct EqeqeqConfig {
     mode: EqEqEqMode,
 }
 
-pub fn eqeqeq(config: &RuleConfig<EqeqeqConfig>) -> Option<Box<dyn Rule>> {
+pub fn eqeqeq(
+    source_map: &Arc<SourceMap>,
+    config: &RuleConfig<EqeqeqConfig>,
+) -> Option<Box<dyn Rule>> {
     match config.get_rule_reaction() {
         LintRuleReaction::Off => None,
-        _ => Some(visitor_rule(Eqeqeq::new(config))),
+        _ => Some(visitor_rule(Eqeqeq::new(source_map.clone(), config))),
     }
 }
 
-#[derive(Debug, Default)]
+#[derive(Default)]
 struct Eqeqeq {
+    source_map: Arc<SourceMap>,
+
     expected_reaction: LintRuleReaction,
     mode: EqEqEqMode,
 }
 
+impl Debug for Eqeqeq {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Eqeqeq")
+            .field("expected_reaction", &self.expected_reaction)
+            .field("mode", &self.mode)
+            .finish()
+    }
+}
+
+/// Returns true if replacing the operator of `bin_expr` doesn't change the
+/// result, because both operands are known to have the same type.
+fn is_safe_to_fix(bin_expr: &BinExpr) -> bool {
+    let is_type_of = |expr: &Expr| {
+        matches!(
+            expr,
+            Expr::Unary(UnaryExpr {
+                op: op!("typeof"),
+                ..
+            })
+        )
+    };
+
+    match (&*bin_expr.left, &*bin_expr.right) {
+        (Expr::Lit(Lit::Str(..)), right) => {
+            is_type_of(right) || matches!(right, Expr::Lit(Lit::Str(..)))
+        }
+        (left, Expr::Lit(Lit::Str(..))) => is_type_of(left),
+        (Expr::Lit(Lit::Num(..)), Expr::Lit(Lit::Num(..)))
+        | (Expr::Lit(Lit::Bool(..)), Expr::Lit(Lit::Bool(..))) => true,
+        (left, right) => is_type_of(left) && is_type_of(right),
+    }
+}
+
 impl Eqeqeq {
-    fn new(config: &RuleConfig<EqeqeqConfig>) -> Self {
+    fn new(source_map: Arc<SourceMap>, config: &RuleConfig<EqeqeqConfig>) -> Self {
         Self {
+            source_map,
             expected_reaction: config.get_rule_reaction(),
             mode: config.get_rule_config().mode,
         }
     }
 
-    fn emit_report(&self, span: Span, actual: &str, expected: &str) {
+    fn emit_report(&self, bin_expr: &BinExpr, actual: &str, expected: &str) {
         let message = format!("Use '{}' instead of '{}'", expected, actual);
 
-        HANDLER.with(|handler| match self.expected_reaction {
-            LintRuleReaction::Error => {
-                handler.struct_span_err(span, &message).emit();
-            }
-            LintRuleReaction::Warning => {
-                handler.struct_span_warn(span, &message).emit();
+        let fix = if is_safe_to_fix(bin_expr) {
+            bin_op_span(&self.source_map, bin_expr).map(|span| (span, expected.to_string()))
+        } else {
+            None
+        };
+
+        HANDLER.with(|handler| {
+            let mut builder = match self.expected_reaction {
+                LintRuleReaction::Error => handler.struct_span_err(bin_expr.span, &message),
+                LintRuleReaction::Warning => handler.struct_span_warn(bin_expr.span, &message),
+                _ => return,
+            };
+
+            if let Some((span, snippet)) = fix {
+                builder.span_suggestion_with_applicability(
+                    span,
+                    &format!("Replace '{}' with '{}'", actual, expected),
+                    snippet,
+                    Applicability::MachineApplicable,
+                );
             }
-            _ => {}
+
+            builder.emit();
         });
     }
 
-    fn check(&self, span: Span, bin_op: &BinaryOp) {
-        match bin_op {
+    fn check(&self, bin_expr: &BinExpr) {
+        match bin_expr.op {
             op!("==") => {
                 if let EqEqEqMode::Always = self.mode {
-                    self.emit_report(span, "==", "===");
+                    self.emit_report(bin_expr, "==", "===");
                 }
             }
             op!("!=") => {
                 if let EqEqEqMode::Always = self.mode {
-                    self.emit_report(span, "!=", "!==");
+                    self.emit_report(bin_expr, "!=", "!==");
                 }
             }
             op!("===") => {
                 if let EqEqEqMode::Never = self.mode {
-                    self.emit_report(span, "===", "==");
+                    self.emit_report(bin_expr, "===", "==");
                 }
             }
             op!("!==") => {
                 if let EqEqEqMode::Never = self.mode {
-                    self.emit_report(span, "!==", "!=");
+                    self.emit_report(bin_expr, "!==", "!=");
                 }
             }
             _ => {}
@@ -93,7 +156,7 @@ impl Visit for Eqeqeq {
     noop_visit_type!();
 
     fn visit_bin_expr(&mut self, bin_expr: &BinExpr) {
-        self.check(bin_expr.span, &bin_expr.op);
+        self.check(bin_expr);
 
         bin_expr.visit_children_with(self);
     }
@@ -109,6 +109,7 @@ pub fn all(lint_params: LintParams) -> Vec<Box<dyn Rule>> {
 
         rules.extend(dot_notation::dot_notation(
             program,
+            &source_map,
             &lint_config.dot_notation,
         ));
 
@@ -121,7 +122,7 @@ pub fn all(lint_params: LintParams) -> Vec<Box<dyn Rule>> {
             &lint_config.no_empty_pattern,
         ));
 
-        rules.extend(eqeqeq::eqeqeq(&lint_config.eqeqeq));
+        rules.extend(eqeqeq::eqeqeq(&source_map, &lint_config.eqeqeq));
 
         rules.extend(no_loop_func::no_loop_func(&lint_config.no_loop_func));
 
@@ -139,7 +140,7 @@ pub fn all(lint_params: LintParams) -> Vec<Box<dyn Rule>> {
             &lint_config.default_param_last,
         ));
 
-        rules.extend(yoda::yoda(&lint_config.yoda));
+        rules.extend(yoda::yoda(&source_map, &lint_config.yoda));
 
         rules.extend(no_new_symbol::no_new_symbol(
             unresolved_ctxt,
@@ -1,5 +1,10 @@
-use swc_common::{errors::HANDLER, Span};
+use rustc_hash::{FxHashMap, FxHashSet};
+use swc_common::{
+    errors::{Applicability, HANDLER},
+    BytePos, Span,
+};
 use swc_ecma_ast::*;
+use swc_ecma_utils::find_pat_ids;
 use swc_ecma_visit::{Visit, VisitWith};
 
 use crate::{
@@ -21,32 +26,235 @@ pub fn no_var(config: &RuleConfig<()>) -> Option<Box<dyn Rule>> {
 #[derive(Debug, Default)]
 struct NoVar {
     expected_reaction: LintRuleReaction,
+
+    usages: FxHashMap<Id, Usage>,
+    // `lo` of var declarations which are directly in a function body or a
+    // module, where the scope of `let` is the same as `var`.
+    fixable_decls: FxHashSet<BytePos>,
 }
 
 impl NoVar {
     fn new(expected_reaction: LintRuleReaction) -> Self {
-        Self { expected_reaction }
+        Self {
+            expected_reaction,
+            ..Default::default()
+        }
     }
 
-    fn emit_error(&self, span: Span) {
-        HANDLER.with(|handler| match self.expected_reaction {
-            LintRuleReaction::Error => {
-                handler.struct_span_err(span, MESSAGE).emit();
-            }
-            LintRuleReaction::Warning => {
-                handler.struct_span_warn(span, MESSAGE).emit();
+    fn emit_error(&self, span: Span, fix: Option<Span>) {
+        HANDLER.with(|handler| {
+            let mut builder = match self.expected_reaction {
+                LintRuleReaction::Error => handler.struct_span_err(span, MESSAGE),
+                LintRuleReaction::Warning => handler.struct_span_warn(span, MESSAGE),
+                _ => return,
+            };
+
+            if let Some(kind_span) = fix {
+                builder.span_suggestion_with_applicability(
+                    kind_span,
+                    "Use 'let'",
+                    "let".into(),
+                    Applicability::MachineApplicable,
+                );
             }
-            _ => {}
+
+            builder.emit();
         });
     }
+
+    /// Returns true if replacing `var` with `let` doesn't change the behavior,
+    /// which means that every binding is declared only once, is not used
+    /// before the end of the declaration and is not used from other functions.
+    fn can_fix(&self, var_decl: &VarDecl) -> bool {
+        if var_decl.declare || !self.fixable_decls.contains(&var_decl.span.lo) {
+            return false;
+        }
+
+        var_decl.decls.iter().all(|decl| {
+            find_pat_ids::<_, Ident>(&decl.name).iter().all(|ident| {
+                match self.usages.get(&ident.to_id()) {
+                    Some(usage) => {
+                        usage.declarations == 1
+                            && !usage.in_other_function
+                            && usage
+                                .occurrences
+                                .iter()
+                                .all(|&lo| lo == ident.span.lo || lo >= decl.span.hi)
+                    }
+                    None => false,
+                }
+            })
+        })
+    }
+
+    fn collect_fixable_decls<'a>(&mut self, stmts: impl Iterator<Item = &'a Stmt>) {
+        for stmt in stmts {
+            if let Stmt::Decl(Decl::Var(var_decl)) = stmt {
+                self.fixable_decls.insert(var_decl.span.lo);
+            }
+        }
+    }
 }
 
 impl Visit for NoVar {
+    fn visit_module(&mut self, module: &Module) {
+        self.usages = collect_usages(module);
+
+        for item in &module.body {
+            match item {
+                ModuleItem::Stmt(Stmt::Decl(Decl::Var(var_decl)))
+                | ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(ExportDecl {
+                    decl: Decl::Var(var_decl),
+                    ..
+                })) => {
+                    self.fixable_decls.insert(var_decl.span.lo);
+                }
+                _ => {}
+            }
+        }
+
+        module.visit_children_with(self);
+    }
+
+    fn visit_script(&mut self, script: &Script) {
+        // Top-level `var` of scripts creates a property of the global object.
+        self.usages = collect_usages(script);
+
+        script.visit_children_with(self);
+    }
+
+    fn visit_function(&mut self, function: &Function) {
+        if let Some(body) = &function.body {
+            self.collect_fixable_decls(body.stmts.iter());
+        }
+
+        function.visit_children_with(self);
+    }
+
+    fn visit_arrow_expr(&mut self, arrow_expr: &ArrowExpr) {
+        if let BlockStmtOrExpr::BlockStmt(body) = &*arrow_expr.body {
+            self.collect_fixable_decls(body.stmts.iter());
+        }
+
+        arrow_expr.visit_children_with(self);
+    }
+
     fn visit_var_decl(&mut self, var_decl: &VarDecl) {
         if let VarDeclKind::Var = var_decl.kind {
-            self.emit_error(var_decl.span);
+            let fix = if self.can_fix(var_decl) {
+                let lo = var_decl.span.lo;
+
+                Some(Span::new(lo, lo + BytePos(3)))
+            } else {
+                None
+            };
+
+            self.emit_error(var_decl.span, fix);
         }
 
         var_decl.visit_children_with(self);
     }
 }
+
+#[derive(Debug)]
+struct Usage {
+    occurrences: Vec<BytePos>,
+    declarations: usize,
+    function_depth: usize,
+    in_other_function: bool,
+}
+
+fn collect_usages<N>(node: &N) -> FxHashMap<Id, Usage>
+where
+    N: VisitWith<UsageCollector>,
+{
+    let mut v = UsageCollector::default();
+    node.visit_with(&mut v);
+    v.usages
+}
+
+/// Collects the positions and the number of declarations of identifiers.
+#[derive(Default)]
+struct UsageCollector {
+    function_depth: usize,
+    usages: FxHashMap<Id, Usage>,
+}
+
+impl UsageCollector {
+    fn usage(&mut self, ident: &Ident) -> &mut Usage {
+        let function_depth = self.function_depth;
+
+        let usage = self.usages.entry(ident.to_id()).or_insert(Usage {
+            occurrences: Vec::new(),
+            declarations: 0,
+            function_depth,
+            in_other_function: false,
+        });
+
+        if usage.function_depth != function_depth {
+            usage.in_other_function = true;
+        }
+
+        usage
+    }
+
+    fn add_declarations<N>(&mut self, node: &N)
+    where
+        N: VisitWith<swc_ecma_utils::DestructuringFinder<Ident>>,
+    {
+        for ident in find_pat_ids::<_, Ident>(node) {
+            self.usage(&ident).declarations += 1;
+        }
+    }
+}
+
+impl Visit for UsageCollector {
+    fn visit_ident(&mut self, ident: &Ident) {
+        self.usage(ident).occurrences.push(ident.span.lo);
+    }
+
+    fn visit_var_declarator(&mut self, var_declarator: &VarDeclarator) {
+        self.add_declarations(&var_declarator.name);
+
+        var_declarator.visit_children_with(self);
+    }
+
+    fn visit_param(&mut self, param: &Param) {
+        self.add_declarations(&param.pat);
+
+        param.visit_children_with(self);
+    }
+
+    fn visit_catch_clause(&mut self, catch_clause: &CatchClause) {
+        if let Some(param) = &catch_clause.param {
+            self.add_declarations(param);
+        }
+
+        catch_clause.visit_children_with(self);
+    }
+
+    fn visit_fn_decl(&mut self, fn_decl: &FnDecl) {
+        self.usage(&fn_decl.ident).declarations += 1;
+
+        fn_decl.visit_children_with(self);
+    }
+
+    fn visit_class_decl(&mut self, class_decl: &ClassDecl) {
+        self.usage(&class_decl.ident).declarations += 1;
+
+        class_decl.visit_children_with(self);
+    }
+
+    fn visit_function(&mut self, function: &Function) {
+        self.function_depth += 1;
+        function.visit_children_with(self);
+        self.function_depth -= 1;
+    }
+
+    fn visit_arrow_expr(&mut self, arrow_expr: &ArrowExpr) {
+        self.function_depth += 1;
+        self.add_declarations(&arrow_expr.params);
+        arrow_expr.visit_children_with(self);
+        self.function_depth -= 1;
+    }
+}
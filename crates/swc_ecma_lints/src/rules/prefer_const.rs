@@ -1,6 +1,9 @@
-use rustc_hash::FxHashMap;
+use rustc_hash::{FxHashMap, FxHashSet};
 use serde::{Deserialize, Serialize};
-use swc_common::{errors::HANDLER, Span};
+use swc_common::{
+    errors::{Applicability, HANDLER},
+    BytePos, Span,
+};
 use swc_ecma_ast::*;
 use swc_ecma_visit::{Visit, VisitWith};
 
@@ -35,6 +38,8 @@ pub fn prefer_const(config: &RuleConfig<PreferConstConfig>) -> Option<Box<dyn Ru
 struct VariableMeta {
     span: Span,
     order: usize,
+    // index of the declaration in `let_decls`
+    decl: usize,
     initialized: bool,
     block_depth: usize,
     // for cases like
@@ -52,10 +57,19 @@ struct VariableMeta {
     used_before_initialize: bool,
 }
 
+#[derive(Debug)]
+struct LetDecl {
+    // span of the `let` keyword
+    kind_span: Span,
+    bindings: usize,
+    fixable: bool,
+}
+
 #[derive(Debug, Default)]
 struct PreferConst {
     expected_reaction: LintRuleReaction,
     vars_meta: FxHashMap<Id, VariableMeta>,
+    let_decls: Vec<LetDecl>,
     scope_vars_idx: usize,
     block_depth: usize,
     cycle_head_depth: usize,
@@ -68,6 +82,7 @@ impl PreferConst {
         Self {
             expected_reaction,
             vars_meta: Default::default(),
+            let_decls: Default::default(),
             scope_vars_idx: 0,
             block_depth: 0,
             cycle_head_depth: 0,
@@ -76,28 +91,41 @@ impl PreferConst {
         }
     }
 
-    fn emit_report(&self, span: Span, var_name: &str) {
+    fn emit_report(&self, span: Span, var_name: &str, fix: Option<Span>) {
         let message = format!("'{}' is never reassigned. Use 'const' insted", var_name);
 
-        HANDLER.with(|handler| match self.expected_reaction {
-            LintRuleReaction::Error => {
-                handler.struct_span_err(span, &message).emit();
-            }
-            LintRuleReaction::Warning => {
-                handler.struct_span_warn(span, &message).emit();
+        HANDLER.with(|handler| {
+            let mut builder = match self.expected_reaction {
+                LintRuleReaction::Error => handler.struct_span_err(span, &message),
+                LintRuleReaction::Warning => handler.struct_span_warn(span, &message),
+                _ => return,
+            };
+
+            if let Some(kind_span) = fix {
+                builder.span_suggestion_with_applicability(
+                    kind_span,
+                    "Use 'const'",
+                    "const".into(),
+                    Applicability::MachineApplicable,
+                );
             }
-            _ => {}
+
+            builder.emit();
         });
     }
 
     fn add_var_meta(&mut self, ident: &Ident, initialized: bool) {
         self.scope_vars_idx += 1;
 
+        let decl = self.let_decls.len() - 1;
+        self.let_decls[decl].bindings += 1;
+
         self.vars_meta.insert(
             ident.to_id(),
             VariableMeta {
                 span: ident.span,
                 order: self.scope_vars_idx,
+                decl,
                 initialized,
                 block_depth: self.block_depth,
                 destructuring_assign: false,
@@ -192,6 +220,18 @@ impl PreferConst {
 
         vars.sort_by(|(_, a), (_, b)| a.order.cmp(&b.order));
 
+        // A declaration can be fixed only if all of its bindings can be const.
+        let mut fixable_bindings = FxHashMap::<usize, usize>::default();
+        for (_, var_meta) in &vars {
+            if (var_meta.initialized || var_meta.declared_into_cycle_head)
+                && !var_meta.postinitialized
+                && !var_meta.destructuring_assign
+            {
+                *fixable_bindings.entry(var_meta.decl).or_default() += 1;
+            }
+        }
+        let mut fixed_decls = FxHashSet::default();
+
         vars.into_iter().for_each(|(id, var_meta)| {
             let postinitialized = if self.ignore_read_before_assign {
                 var_meta.postinitialized && !var_meta.used_before_initialize
@@ -204,7 +244,17 @@ impl PreferConst {
                 || var_meta.destructuring_assign
                 || var_meta.declared_into_cycle_head
             {
-                self.emit_report(var_meta.span, &id.0);
+                let decl = &self.let_decls[var_meta.decl];
+                let fix = if decl.fixable
+                    && fixable_bindings.get(&var_meta.decl) == Some(&decl.bindings)
+                    && fixed_decls.insert(var_meta.decl)
+                {
+                    Some(decl.kind_span)
+                } else {
+                    None
+                };
+
+                self.emit_report(var_meta.span, &id.0, fix);
             }
         });
     }
@@ -225,6 +275,14 @@ impl Visit for PreferConst {
 
     fn visit_var_decl(&mut self, var_decl: &VarDecl) {
         if let VarDeclKind::Let = var_decl.kind {
+            let lo = var_decl.span.lo;
+
+            self.let_decls.push(LetDecl {
+                kind_span: Span::new(lo, lo + BytePos(3)),
+                bindings: 0,
+                fixable: !var_decl.declare,
+            });
+
             var_decl.decls.iter().for_each(|var_decl| {
                 self.collect_decl_pat(var_decl.init.is_some(), &var_decl.name);
             })
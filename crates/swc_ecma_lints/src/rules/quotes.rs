@@ -1,5 +1,8 @@
 use serde::{Deserialize, Serialize};
-use swc_common::{errors::HANDLER, Span};
+use swc_common::{
+    errors::{Applicability, HANDLER},
+    Span,
+};
 use swc_ecma_ast::*;
 use swc_ecma_visit::{noop_visit_type, Visit, VisitWith};
 
@@ -50,24 +53,87 @@ impl Quotes {
         }
     }
 
-    fn emit_report(&self, span: Span) {
+    fn emit_report(&self, span: Span, fix: Option<String>) {
         let message = match &self.prefer {
             QuotesType::Backtick => MUST_USE_BACKTICK_QUOTES_MESSAGE,
             QuotesType::Single => MUST_USE_SINGLE_QUOTES_MESSAGE,
             QuotesType::Double => MUST_USE_DOUBLE_QUOTES_MESSAGE,
         };
 
-        HANDLER.with(|handler| match self.expected_reaction {
-            LintRuleReaction::Error => {
-                handler.struct_span_err(span, message).emit();
-            }
-            LintRuleReaction::Warning => {
-                handler.struct_span_warn(span, message).emit();
+        HANDLER.with(|handler| {
+            let mut builder = match self.expected_reaction {
+                LintRuleReaction::Error => handler.struct_span_err(span, message),
+                LintRuleReaction::Warning => handler.struct_span_warn(span, message),
+                _ => return,
+            };
+
+            if let Some(fix) = fix {
+                builder.span_suggestion_with_applicability(
+                    span,
+                    "Change the quotes",
+                    fix,
+                    Applicability::MachineApplicable,
+                );
             }
-            _ => {}
+
+            builder.emit();
         });
     }
 
+    /// Quotes `raw`, the source of a string without quotes, with the preferred
+    /// quote.
+    fn requote(&self, raw: &str, found: char) -> Option<String> {
+        let quote = self.prefer.get_char();
+
+        if quote == '`' && raw.contains("${") {
+            return None;
+        }
+        // Line continuations and line breaks are only allowed in some of them.
+        if raw.contains(['\n', '\r']) {
+            return None;
+        }
+
+        let mut result = String::with_capacity(raw.len() + 2);
+        result.push(quote);
+
+        let mut chars = raw.chars();
+        while let Some(ch) = chars.next() {
+            match ch {
+                '\\' => match chars.next() {
+                    // Legacy octal escapes, `\8` and `\9` are syntax errors in templates.
+                    Some('1'..='9') if quote == '`' => return None,
+                    Some('0')
+                        if quote == '`'
+                            && chars.clone().next().is_some_and(|c| c.is_ascii_digit()) =>
+                    {
+                        return None
+                    }
+                    Some(next) if next == found && next != quote => result.push(next),
+                    Some(next) => {
+                        result.push(ch);
+                        result.push(next);
+                    }
+                    None => return None,
+                },
+                _ if ch == quote => {
+                    result.push('\\');
+                    result.push(ch);
+                }
+                _ => result.push(ch),
+            }
+        }
+
+        result.push(quote);
+
+        Some(result)
+    }
+
+    fn get_str_fix(&self, found: QuotesType, lit_str: &Str) -> Option<String> {
+        let raw = lit_str.raw.as_deref()?;
+
+        self.requote(raw.get(1..raw.len() - 1)?, found.get_char())
+    }
+
     fn is_mirroring_escape(&self, value: &str) -> bool {
         let quote = self.prefer.get_char();
 
@@ -91,14 +157,14 @@ impl Quotes {
                     return;
                 }
 
-                self.emit_report(*span);
+                self.emit_report(*span, self.get_str_fix(found_quote_type, lit_str));
             }
             (QuotesType::Single, QuotesType::Double) => {
                 if self.avoid_escape && self.is_mirroring_escape(value) {
                     return;
                 }
 
-                self.emit_report(*span);
+                self.emit_report(*span, self.get_str_fix(found_quote_type, lit_str));
             }
             (QuotesType::Backtick, _) => {
                 if is_method_key_check {
@@ -109,7 +175,7 @@ impl Quotes {
                     return;
                 }
 
-                self.emit_report(*span);
+                self.emit_report(*span, self.get_str_fix(found_quote_type, lit_str));
             }
             _ => {}
         }
@@ -130,7 +196,9 @@ impl Quotes {
             return;
         }
 
-        self.emit_report(*span);
+        let fix = self.requote(&tpl_str.quasis[0].raw, '`');
+
+        self.emit_report(*span, fix);
     }
 }
 
@@ -1,7 +1,9 @@
 use serde::{Deserialize, Serialize};
 use swc_atoms::Atom;
-use swc_common::SyntaxContext;
-use swc_ecma_ast::{Expr, Lit, MemberExpr, MemberProp, Number, Regex, Str, TaggedTpl, Tpl};
+use swc_common::{BytePos, SourceMap, SourceMapper, Span, Spanned, SyntaxContext};
+use swc_ecma_ast::{
+    BinExpr, Expr, Lit, MemberExpr, MemberProp, Number, Regex, Str, TaggedTpl, Tpl,
+};
 
 #[derive(Debug, Clone, Copy, Serialize, Deserialize)]
 #[serde(rename_all = "lowercase")]
@@ -91,3 +93,29 @@ pub fn extract_arg_val(unresolved_ctxt: SyntaxContext, expr: &Expr) -> ArgValue
         _ => ArgValue::Other,
     }
 }
+
+/// Returns the span of the operator of `bin_expr`.
+///
+/// The operator doesn't have a span, so we find it between the operands,
+/// skipping whitespace and comments.
+pub fn bin_op_span(source_map: &SourceMap, bin_expr: &BinExpr) -> Option<Span> {
+    let between = Span::new(bin_expr.left.span_hi(), bin_expr.right.span_lo());
+    let snippet = source_map.span_to_snippet(between).ok()?;
+    let op = bin_expr.op.as_str();
+
+    let mut rest = &*snippet;
+    loop {
+        let trimmed = rest.trim_start();
+        rest = if let Some(comment) = trimmed.strip_prefix("/*") {
+            &comment[comment.find("*/")? + 2..]
+        } else if let Some(comment) = trimmed.strip_prefix("//") {
+            &comment[comment.find(['\n', '\r', '\u{2028}', '\u{2029}'])?..]
+        } else if trimmed.starts_with(op) {
+            let lo = between.lo + BytePos((snippet.len() - trimmed.len()) as u32);
+
+            return Some(Span::new(lo, lo + BytePos(op.len() as u32)));
+        } else {
+            return None;
+        };
+    }
+}
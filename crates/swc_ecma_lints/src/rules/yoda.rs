@@ -1,11 +1,20 @@
+use std::{
+    fmt::{self, Debug},
+    sync::Arc,
+};
+
 use serde::{Deserialize, Serialize};
-use swc_common::{errors::HANDLER, Span};
+use swc_common::{
+    errors::{Applicability, HANDLER},
+    SourceMap, SourceMapper, Span, Spanned,
+};
 use swc_ecma_ast::*;
 use swc_ecma_visit::{noop_visit_type, Visit, VisitWith};
 
 use crate::{
     config::{LintRuleReaction, RuleConfig},
     rule::{visitor_rule, Rule},
+    rules::utils::bin_op_span,
 };
 
 #[derive(Debug, Clone, Copy, Serialize, Deserialize)]
@@ -32,15 +41,17 @@ pub struct YodaConfig {
     only_equality: bool,
 }
 
-pub fn yoda(config: &RuleConfig<YodaConfig>) -> Option<Box<dyn Rule>> {
+pub fn yoda(source_map: &Arc<SourceMap>, config: &RuleConfig<YodaConfig>) -> Option<Box<dyn Rule>> {
     match config.get_rule_reaction() {
         LintRuleReaction::Off => None,
-        _ => Some(visitor_rule(Yoda::new(config))),
+        _ => Some(visitor_rule(Yoda::new(source_map.clone(), config))),
     }
 }
 
-#[derive(Debug, Default)]
+#[derive(Default)]
 struct Yoda {
+    source_map: Arc<SourceMap>,
+
     expected_reaction: LintRuleReaction,
 
     mode: YodaConfigMode,
@@ -48,11 +59,85 @@ struct Yoda {
     except_range: bool,
 }
 
+impl Debug for Yoda {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Yoda")
+            .field("expected_reaction", &self.expected_reaction)
+            .field("mode", &self.mode)
+            .field("only_equality", &self.only_equality)
+            .field("except_range", &self.except_range)
+            .finish()
+    }
+}
+
+/// Returns the operator which gives the same result when the operands are
+/// swapped.
+fn flip_op(op: BinaryOp) -> Option<BinaryOp> {
+    match op {
+        op!("==") | op!("!=") | op!("===") | op!("!==") => Some(op),
+        op!("<") => Some(op!(">")),
+        op!("<=") => Some(op!(">=")),
+        op!(">") => Some(op!("<")),
+        op!(">=") => Some(op!("<=")),
+        _ => None,
+    }
+}
+
+/// Returns true if `expr` is a literal which can be compared with the other
+/// operand.
+fn is_literal(expr: &Expr) -> bool {
+    match expr {
+        Expr::Lit(_) => true,
+        Expr::Unary(UnaryExpr {
+            op: op!(unary, "-"),
+            arg,
+            ..
+        }) => matches!(arg.as_ref(), Expr::Lit(Lit::Num(_))),
+        Expr::Tpl(Tpl { exprs, .. }) => exprs.is_empty(),
+        _ => false,
+    }
+}
+
+/// Returns true if evaluating `expr` does not have side effects, ignoring
+/// getters and conversions of objects to primitives.
+fn is_side_effect_free(expr: &Expr) -> bool {
+    match expr {
+        Expr::Ident(_) | Expr::Lit(_) | Expr::This(_) => true,
+        Expr::Paren(ParenExpr { expr, .. }) => is_side_effect_free(expr),
+        Expr::Member(MemberExpr { obj, prop, .. }) => {
+            is_side_effect_free(obj)
+                && match prop {
+                    MemberProp::Computed(ComputedPropName { expr, .. }) => {
+                        is_side_effect_free(expr)
+                    }
+                    _ => true,
+                }
+        }
+        Expr::Unary(UnaryExpr { op, arg, .. }) => *op != op!("delete") && is_side_effect_free(arg),
+        Expr::Bin(BinExpr { left, right, .. }) => {
+            is_side_effect_free(left) && is_side_effect_free(right)
+        }
+        Expr::Tpl(Tpl { exprs, .. }) => exprs.iter().all(|expr| is_side_effect_free(expr)),
+        _ => false,
+    }
+}
+
+/// Returns true if `expr` should be wrapped in parentheses to be an operand of
+/// `op`, on either side.
+fn needs_parens(expr: &Expr, op: BinaryOp) -> bool {
+    match expr {
+        Expr::Bin(bin) => bin.op.precedence() <= op.precedence(),
+        Expr::Cond(_) | Expr::Seq(_) | Expr::Assign(_) | Expr::Arrow(_) | Expr::Yield(_) => true,
+        _ => false,
+    }
+}
+
 impl Yoda {
-    fn new(config: &RuleConfig<YodaConfig>) -> Self {
+    fn new(source_map: Arc<SourceMap>, config: &RuleConfig<YodaConfig>) -> Self {
         let rule_config = config.get_rule_config();
 
         Self {
+            source_map,
             expected_reaction: config.get_rule_reaction(),
             mode: rule_config.mode,
             only_equality: rule_config.only_equality,
@@ -60,39 +145,76 @@ impl Yoda {
         }
     }
 
-    fn emit_report(&self, span: Span, op: BinaryOp) {
+    /// Swaps the operands of `bin_expr`.
+    ///
+    /// A fix is offered only if one operand is a literal and the other one
+    /// does not have side effects, so the evaluation order does not matter.
+    fn get_fix(&self, bin_expr: &BinExpr) -> Option<Vec<(Span, String)>> {
+        let flipped = flip_op(bin_expr.op)?;
+
+        let (literal, other) = match self.mode {
+            YodaConfigMode::Always => (&bin_expr.right, &bin_expr.left),
+            YodaConfigMode::Never => (&bin_expr.left, &bin_expr.right),
+        };
+        if !is_literal(literal) || is_literal(other) || !is_side_effect_free(other) {
+            return None;
+        }
+
+        let left = bin_expr.left.span();
+        let right = bin_expr.right.span();
+        let op = bin_op_span(&self.source_map, bin_expr)?;
+
+        let snippet = |expr: &Expr, span: Span| {
+            let snippet = self.source_map.span_to_snippet(span).ok()?;
+            if needs_parens(expr, flipped) {
+                Some(format!("({})", snippet))
+            } else {
+                Some(snippet)
+            }
+        };
+        let left_snippet = snippet(&bin_expr.left, left)?;
+        let right_snippet = snippet(&bin_expr.right, right)?;
+
+        Some(vec![
+            (left, right_snippet),
+            (op, flipped.to_string()),
+            (right, left_snippet),
+        ])
+    }
+
+    fn emit_report(&self, bin_expr: &BinExpr) {
         let side = match self.mode {
             YodaConfigMode::Always => "left",
             YodaConfigMode::Never => "right",
         };
 
-        let message = format!("Expected literal to be on the {} side of {}", side, op);
+        let message = format!(
+            "Expected literal to be on the {} side of {}",
+            side, bin_expr.op
+        );
+        let fix = self.get_fix(bin_expr);
 
-        HANDLER.with(|handler| match self.expected_reaction {
-            LintRuleReaction::Error => {
-                handler.struct_span_err(span, &message).emit();
-            }
-            LintRuleReaction::Warning => {
-                handler.struct_span_warn(span, &message).emit();
+        HANDLER.with(|handler| {
+            let mut builder = match self.expected_reaction {
+                LintRuleReaction::Error => handler.struct_span_err(bin_expr.span, &message),
+                LintRuleReaction::Warning => handler.struct_span_warn(bin_expr.span, &message),
+                _ => return,
+            };
+
+            if let Some(fix) = fix {
+                builder.multipart_suggestion_with_applicability(
+                    "Swap the operands",
+                    fix,
+                    Applicability::MachineApplicable,
+                );
             }
-            _ => {}
+
+            builder.emit();
         });
     }
 
     fn is_yoda_style(&self, bin_expr: &BinExpr) -> bool {
-        let left_expr = bin_expr.left.as_ref();
-        match left_expr {
-            Expr::Lit(_) => true,
-            Expr::Unary(UnaryExpr { op, arg, .. }) => {
-                if let (op!(unary, "-"), Expr::Lit(Lit::Num(_))) = (op, arg.as_ref()) {
-                    return true;
-                }
-
-                false
-            }
-            Expr::Tpl(Tpl { exprs, .. }) => exprs.is_empty(),
-            _ => false,
-        }
+        is_literal(&bin_expr.left)
     }
 
     fn check(&self, bin_expr: &BinExpr) {
@@ -105,7 +227,7 @@ impl Yoda {
             }
         }
 
-        self.emit_report(bin_expr.span, bin_expr.op);
+        self.emit_report(bin_expr);
     }
 }
 
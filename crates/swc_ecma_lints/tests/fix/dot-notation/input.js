@@ -0,0 +1,4 @@
+foo["bar"];
+foo?.["bar"];
+foo["bar"]["baz"];
+foo["not-ident"];
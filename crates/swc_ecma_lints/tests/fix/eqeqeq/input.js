@@ -0,0 +1,9 @@
+if (typeof foo == "undefined") {
+}
+if ("a" != "b") {
+}
+// Not safe to fix
+if (foo == bar) {
+}
+if (typeof foo /* == */ == "undefined") {
+}
@@ -0,0 +1,19 @@
+var a = 1;
+export var b = a;
+
+function foo() {
+    var c = 1;
+    if (c) {
+        var d = 2;
+    }
+    var e = e;
+    return c + d + e;
+}
+
+function bar() {
+    baz();
+    var f = 1;
+    function baz() {
+        return f;
+    }
+}
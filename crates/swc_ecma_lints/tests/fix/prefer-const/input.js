@@ -0,0 +1,9 @@
+let a = 1;
+let b = 2, c = 3;
+let d = 4, e = 5;
+e = 6;
+let f;
+f = 7;
+for (let x of [a, b, c, d, f]) {
+    console.log(x);
+}
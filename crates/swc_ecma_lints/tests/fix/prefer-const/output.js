@@ -0,0 +1,9 @@
+const a = 1;
+const b = 2, c = 3;
+let d = 4, e = 5;
+e = 6;
+let f;
+f = 7;
+for (const x of [a, b, c, d, f]) {
+    console.log(x);
+}
@@ -0,0 +1,6 @@
+const a = `double`;
+const b = `\0`;
+const c = "\1";
+const d = "\01";
+const e = "\8";
+const f = "\9";
@@ -0,0 +1,3 @@
+const a = "double";
+const b = "it's";
+const c = "escaped \"quote\"";
@@ -0,0 +1,11 @@
+if (color === "red") {
+}
+if (a == b == 1) {
+}
+if (value /* < */ < -1) {
+}
+// Not safe to fix
+if (f() == g()) {
+}
+if (getCount() > 1) {
+}
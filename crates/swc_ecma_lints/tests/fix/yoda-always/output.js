@@ -0,0 +1,11 @@
+if ("red" === color) {
+}
+if (1 == (a == b)) {
+}
+if (-1 /* < */ > value) {
+}
+// Not safe to fix
+if (f() == g()) {
+}
+if (getCount() > 1) {
+}
@@ -0,0 +1,9 @@
+if ("red" === color) {
+}
+if (1 < count /* count */) {
+}
+if (-1 >= value) {
+}
+// Not safe to fix
+if ("red" === getColor()) {
+}
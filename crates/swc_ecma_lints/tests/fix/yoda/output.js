@@ -0,0 +1,9 @@
+if (color === "red") {
+}
+if (count > 1 /* count */) {
+}
+if (value <= -1) {
+}
+// Not safe to fix
+if ("red" === getColor()) {
+}
@@ -1,9 +1,18 @@
-use std::path::PathBuf;
+use std::{
+    fs,
+    path::PathBuf,
+    sync::{Arc, Mutex},
+};
 
-use swc_common::{errors::HANDLER, input::SourceFileInput, Mark, SyntaxContext};
+use swc_common::{
+    errors::{Diagnostic, DiagnosticBuilder, Emitter, Handler, HANDLER},
+    input::SourceFileInput,
+    FileName, Mark, SyntaxContext,
+};
 use swc_ecma_ast::{EsVersion, Program};
 use swc_ecma_lints::{
     config::LintConfig,
+    fix::fix,
     rule::Rule,
     rules::{all, LintParams},
 };
@@ -76,3 +85,69 @@ fn pass(input: PathBuf) {
     })
     .unwrap();
 }
+
+#[testing::fixture("tests/fix/**/input.js")]
+fn fixed(input: PathBuf) {
+    let dir = input.parent().unwrap();
+    let config: LintConfig =
+        serde_json::from_str(&fs::read_to_string(dir.join("config.json")).unwrap()).unwrap();
+
+    testing::run_test(false, |cm, _| {
+        let es_version = EsVersion::latest();
+
+        let output = fix(fs::read_to_string(&input).unwrap(), |code| {
+            let fm = cm.new_source_file(FileName::Real(input.clone()).into(), code);
+
+            let lexer = Lexer::new(
+                Syntax::Es(Default::default()),
+                es_version,
+                SourceFileInput::from(&*fm),
+                None,
+            );
+            let mut parser = Parser::new_from(lexer);
+            let mut program = parser.parse_program().unwrap();
+
+            let unresolved_mark = Mark::new();
+            let top_level_mark = Mark::new();
+
+            program.mutate(resolver(unresolved_mark, top_level_mark, false));
+
+            let mut rules = all(LintParams {
+                program: &program,
+                lint_config: &config,
+                unresolved_ctxt: SyntaxContext::empty().apply_mark(unresolved_mark),
+                top_level_ctxt: SyntaxContext::empty().apply_mark(top_level_mark),
+                es_version,
+                source_map: cm.clone(),
+            });
+
+            let diagnostics = Collect::default();
+            let handler = Handler::with_emitter(true, false, Box::new(diagnostics.clone()));
+
+            HANDLER.set(&handler, || match &program {
+                Program::Module(m) => rules.lint_module(m),
+                Program::Script(s) => rules.lint_script(s),
+            });
+
+            let diagnostics = diagnostics.0.lock().unwrap().clone();
+
+            (fm, diagnostics)
+        });
+
+        testing::NormalizedOutput::from(output.code)
+            .compare_to_file(dir.join("output.js"))
+            .unwrap();
+
+        Ok(())
+    })
+    .unwrap();
+}
+
+#[derive(Default, Clone)]
+struct Collect(Arc<Mutex<Vec<Diagnostic>>>);
+
+impl Emitter for Collect {
+    fn emit(&mut self, db: &mut DiagnosticBuilder<'_>) {
+        self.0.lock().unwrap().push((**db).clone());
+    }
+}
@@ -14,8 +14,35 @@ pub struct Resolution {
     pub slug: Option<Atom>,
 }
 
+/// How a module is imported.
+///
+/// This selects the `import` or `require` condition of `exports` and `imports`
+/// in package.json.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ImportKind {
+    /// `import` declarations, `export ... from` and `import()`.
+    #[default]
+    Import,
+    /// `require()` calls.
+    Require,
+}
+
 pub trait Resolve: Send + Sync {
     fn resolve(&self, base: &FileName, module_specifier: &str) -> Result<Resolution, Error>;
+
+    /// Resolve `module_specifier` imported by `kind`.
+    ///
+    /// Resolvers which do not care about [ImportKind] don't need to override
+    /// this.
+    fn resolve_with_kind(
+        &self,
+        base: &FileName,
+        module_specifier: &str,
+        kind: ImportKind,
+    ) -> Result<Resolution, Error> {
+        let _ = kind;
+        self.resolve(base, module_specifier)
+    }
 }
 
 macro_rules! impl_ref {
@@ -27,6 +54,15 @@ macro_rules! impl_ref {
             fn resolve(&self, base: &FileName, src: &str) -> Result<Resolution, Error> {
                 (**self).resolve(base, src)
             }
+
+            fn resolve_with_kind(
+                &self,
+                base: &FileName,
+                src: &str,
+                kind: ImportKind,
+            ) -> Result<Resolution, Error> {
+                (**self).resolve_with_kind(base, src, kind)
+            }
         }
     };
 }
@@ -0,0 +1,47 @@
+//! File system access of resolvers.
+//!
+//! Files in zip archives and virtual paths of Yarn PnP are supported if the
+//! `pnp` feature is enabled.
+
+use std::{
+    io,
+    path::{Path, PathBuf},
+};
+
+pub(crate) fn is_file(path: &Path) -> bool {
+    #[cfg(feature = "pnp")]
+    if let Some(v) = super::pnp::zip_fs::is_file(path) {
+        return v;
+    }
+
+    path.is_file()
+}
+
+pub(crate) fn is_dir(path: &Path) -> bool {
+    #[cfg(feature = "pnp")]
+    if let Some(v) = super::pnp::zip_fs::is_dir(path) {
+        return v;
+    }
+
+    path.is_dir()
+}
+
+pub(crate) fn read_to_string(path: &Path) -> io::Result<String> {
+    #[cfg(feature = "pnp")]
+    if let Some(v) = super::pnp::zip_fs::read_to_string(path) {
+        return v;
+    }
+
+    std::fs::read_to_string(path)
+}
+
+pub(crate) fn canonicalize(path: &Path) -> io::Result<PathBuf> {
+    // Paths in zip archives can't be canonicalized, and virtual paths are
+    // distinct modules.
+    #[cfg(feature = "pnp")]
+    if super::pnp::zip_fs::is_virtual_or_zip(path) {
+        return Ok(path_clean::PathClean::clean(&path.to_path_buf()));
+    }
+
+    path.canonicalize()
+}
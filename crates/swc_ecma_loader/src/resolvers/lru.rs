@@ -5,14 +5,14 @@ use lru::LruCache;
 use parking_lot::Mutex;
 use swc_common::FileName;
 
-use crate::resolve::{Resolution, Resolve};
+use crate::resolve::{ImportKind, Resolution, Resolve};
 
 #[derive(Debug)]
 pub struct CachingResolver<R>
 where
     R: Resolve,
 {
-    cache: Mutex<LruCache<(FileName, String), Resolution>>,
+    cache: Mutex<LruCache<(FileName, String, ImportKind), Resolution>>,
     inner: R,
 }
 
@@ -44,18 +44,27 @@ where
     R: Resolve,
 {
     fn resolve(&self, base: &FileName, src: &str) -> Result<Resolution, Error> {
+        self.resolve_with_kind(base, src, ImportKind::Import)
+    }
+
+    fn resolve_with_kind(
+        &self,
+        base: &FileName,
+        src: &str,
+        kind: ImportKind,
+    ) -> Result<Resolution, Error> {
         {
             let mut lock = self.cache.lock();
             //
-            if let Some(v) = lock.get(&(base.clone(), src.to_string())) {
+            if let Some(v) = lock.get(&(base.clone(), src.to_string(), kind)) {
                 return Ok(v.clone());
             }
         }
 
-        let resolved = self.inner.resolve(base, src)?;
+        let resolved = self.inner.resolve_with_kind(base, src, kind)?;
         {
             let mut lock = self.cache.lock();
-            lock.put((base.clone(), src.to_string()), resolved.clone());
+            lock.put((base.clone(), src.to_string(), kind), resolved.clone());
         }
 
         Ok(resolved)
@@ -1,9 +1,14 @@
+#[cfg(feature = "node")]
+mod fs;
 #[cfg(feature = "lru")]
 #[cfg_attr(docsrs, doc(cfg(feature = "lru")))]
 pub mod lru;
 #[cfg(feature = "node")]
 #[cfg_attr(docsrs, doc(cfg(feature = "node")))]
 pub mod node;
+#[cfg(feature = "pnp")]
+#[cfg_attr(docsrs, doc(cfg(feature = "pnp")))]
+pub mod pnp;
 #[cfg(feature = "tsc")]
 #[cfg_attr(docsrs, doc(cfg(feature = "tsc")))]
 pub mod tsc;
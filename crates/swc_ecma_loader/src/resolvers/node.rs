@@ -4,9 +4,9 @@
 
 use std::{
     env::current_dir,
-    fs::File,
-    io::BufReader,
+    fmt,
     path::{Component, Path, PathBuf},
+    sync::Arc,
 };
 
 use anyhow::{bail, Context, Error};
@@ -17,12 +17,16 @@ use once_cell::sync::Lazy;
 use path_clean::PathClean;
 use pathdiff::diff_paths;
 use rustc_hash::{FxBuildHasher, FxHashMap, FxHashSet};
-use serde::Deserialize;
+use serde::{
+    de::{MapAccess, Visitor},
+    Deserialize, Deserializer,
+};
 use swc_common::FileName;
 use tracing::{debug, trace, Level};
 
+use super::fs;
 use crate::{
-    resolve::{Resolution, Resolve},
+    resolve::{ImportKind, Resolution, Resolve},
     TargetEnv, NODE_BUILTINS,
 };
 
@@ -51,7 +55,7 @@ fn find_package_root(path: &Path) -> Option<PathBuf> {
     let mut parent = path.parent();
     while let Some(p) = parent {
         let pkg = p.join(PACKAGE);
-        if pkg.is_file() {
+        if fs::is_file(&pkg) {
             return Some(p.to_path_buf());
         }
         parent = p.parent();
@@ -59,6 +63,32 @@ fn find_package_root(path: &Path) -> Option<PathBuf> {
     None
 }
 
+/// Find the nearest directory which contains a `package.json` file, including
+/// `dir` itself.
+fn find_package_scope(dir: &Path) -> Option<PathBuf> {
+    dir.ancestors()
+        .find(|p| fs::is_file(&p.join(PACKAGE)))
+        .map(Path::to_path_buf)
+}
+
+/// Split a bare specifier into the package name and the subpath, e.g.
+/// `@scope/pkg/foo` into `@scope/pkg` and `./foo`.
+pub(crate) fn split_package_specifier(specifier: &str) -> (&str, String) {
+    let name_len = if specifier.starts_with('@') {
+        specifier
+            .match_indices('/')
+            .nth(1)
+            .map(|(i, _)| i)
+            .unwrap_or(specifier.len())
+    } else {
+        specifier.find('/').unwrap_or(specifier.len())
+    };
+
+    let (name, rest) = specifier.split_at(name_len);
+
+    (name, format!(".{}", rest))
+}
+
 pub fn to_absolute_path(path: &Path) -> Result<PathBuf, Error> {
     let absolute_path = if path.is_absolute() {
         path.to_path_buf()
@@ -76,12 +106,74 @@ pub(crate) fn is_core_module(s: &str) -> bool {
 
 #[derive(Deserialize)]
 struct PackageJson {
+    #[serde(default)]
+    name: Option<String>,
     #[serde(default)]
     main: Option<String>,
     #[serde(default)]
     browser: Option<Browser>,
     #[serde(default)]
     module: Option<String>,
+    #[serde(default)]
+    exports: Option<Exports>,
+    #[serde(default)]
+    imports: Option<Exports>,
+}
+
+impl PackageJson {
+    fn read(pkg_path: &Path) -> Result<Self, Error> {
+        let content = fs::read_to_string(pkg_path)?;
+
+        serde_json::from_str(&content)
+            .context(format!("failed to deserialize {}", pkg_path.display()))
+    }
+}
+
+/// Value of `exports` or `imports` in package.json, or a target in them.
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum Exports {
+    /// `null`, which excludes a subpath.
+    Null(()),
+    Str(String),
+    Array(Vec<Exports>),
+    Map(ExportsMap),
+}
+
+/// Subpaths or conditions, in the order of package.json.
+///
+/// The order of conditions matters, so we can't use a hash map.
+#[derive(Debug)]
+struct ExportsMap(Vec<(String, Exports)>);
+
+impl<'de> Deserialize<'de> for ExportsMap {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct MapVisitor;
+
+        impl<'de> Visitor<'de> for MapVisitor {
+            type Value = ExportsMap;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str("an object")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut entries = Vec::new();
+                while let Some(entry) = map.next_entry()? {
+                    entries.push(entry);
+                }
+                Ok(ExportsMap(entries))
+            }
+        }
+
+        deserializer.deserialize_map(MapVisitor)
+    }
 }
 
 #[derive(Deserialize)]
@@ -98,6 +190,14 @@ enum StringOrBool {
     Bool(bool),
 }
 
+/// The package which contains a directory, used to resolve self-references.
+#[derive(Debug)]
+struct PackageScope {
+    dir: PathBuf,
+    name: String,
+    exports: Exports,
+}
+
 #[derive(Debug, Default)]
 pub struct NodeModulesResolver {
     target_env: TargetEnv,
@@ -105,6 +205,12 @@ pub struct NodeModulesResolver {
     // if true do not resolve symlink
     preserve_symlinks: bool,
     ignore_node_modules: bool,
+    /// Conditions used for `exports` and `imports` of package.json. If
+    /// [None], conditions are determined by `target_env`.
+    conditions: Option<Vec<String>>,
+    /// Cache of [PackageScope] by directory. [None] if the package does not
+    /// have both of `name` and `exports`.
+    package_scopes: DashMap<PathBuf, Option<Arc<PackageScope>>, FxBuildHasher>,
 }
 
 static EXTENSIONS: &[&str] = &["ts", "tsx", "js", "jsx", "node"];
@@ -121,6 +227,8 @@ impl NodeModulesResolver {
             alias,
             preserve_symlinks,
             ignore_node_modules: false,
+            conditions: None,
+            package_scopes: Default::default(),
         }
     }
 
@@ -135,15 +243,77 @@ impl NodeModulesResolver {
             alias,
             preserve_symlinks,
             ignore_node_modules: true,
+            conditions: None,
+            package_scopes: Default::default(),
         }
     }
 
+    /// Set conditions used to resolve `exports` and `imports` of package.json,
+    /// e.g. `["node", "import"]`.
+    ///
+    /// `default` is always matched, and `conditions` are used as-is for all
+    /// kinds of imports. By default, `node` for [TargetEnv::Node] or `browser`
+    /// for [TargetEnv::Browser] is used, along with `import` or `require`
+    /// depending on the [ImportKind].
+    pub fn with_conditions(mut self, conditions: Vec<String>) -> Self {
+        self.conditions = Some(conditions);
+        self
+    }
+
+    fn matches_condition(&self, condition: &str, kind: ImportKind) -> bool {
+        if condition == "default" {
+            return true;
+        }
+
+        match &self.conditions {
+            Some(conditions) => conditions.iter().any(|c| c == condition),
+            None => {
+                let env = match self.target_env {
+                    TargetEnv::Node => "node",
+                    TargetEnv::Browser => "browser",
+                };
+                let kind = match kind {
+                    ImportKind::Import => "import",
+                    ImportKind::Require => "require",
+                };
+                condition == env || condition == kind
+            }
+        }
+    }
+
+    /// Find the package which contains `dir` and has both of `name` and
+    /// `exports`.
+    fn package_scope(&self, dir: &Path) -> Result<Option<Arc<PackageScope>>, Error> {
+        if let Some(scope) = self.package_scopes.get(dir) {
+            return Ok(scope.clone());
+        }
+
+        let scope = match find_package_scope(dir) {
+            Some(pkg_dir) => {
+                let pkg = PackageJson::read(&pkg_dir.join(PACKAGE))?;
+                match (pkg.name, pkg.exports) {
+                    (Some(name), Some(exports)) => Some(Arc::new(PackageScope {
+                        dir: pkg_dir,
+                        name,
+                        exports,
+                    })),
+                    _ => None,
+                }
+            }
+            None => None,
+        };
+
+        self.package_scopes.insert(dir.to_path_buf(), scope.clone());
+
+        Ok(scope)
+    }
+
     fn wrap(&self, path: Option<PathBuf>) -> Result<FileName, Error> {
         if let Some(path) = path {
             if self.preserve_symlinks {
                 return Ok(FileName::Real(path.clean()));
             } else {
-                return Ok(FileName::Real(path.canonicalize()?));
+                return Ok(FileName::Real(fs::canonicalize(&path)?));
             }
         }
         bail!("index not found")
@@ -171,20 +341,20 @@ impl NodeModulesResolver {
 
         let try_exact = path.extension().is_some();
         if try_exact {
-            if path.is_file() {
+            if fs::is_file(path) {
                 return Ok(Some(path.to_path_buf()));
             }
         } else {
             // We try `.js` first.
             let mut path = path.to_path_buf();
             path.set_extension("js");
-            if path.is_file() {
+            if fs::is_file(&path) {
                 return Ok(Some(path));
             }
         }
 
         // Try exact file after checking .js, for performance
-        if !try_exact && path.is_file() {
+        if !try_exact && fs::is_file(path) {
             return Ok(Some(path.to_path_buf()));
         }
 
@@ -193,7 +363,7 @@ impl NodeModulesResolver {
             let name = name.to_string_lossy();
             for ext in EXTENSIONS {
                 ext_path.set_file_name(format!("{}.{}", name, ext));
-                if ext_path.is_file() {
+                if fs::is_file(&ext_path) {
                     return Ok(Some(ext_path));
                 }
             }
@@ -217,7 +387,7 @@ impl NodeModulesResolver {
                 for ext in extensions {
                     ext_path.set_extension(ext);
 
-                    if ext_path.is_file() {
+                    if fs::is_file(&ext_path) {
                         return Ok(Some(ext_path));
                     }
                 }
@@ -252,7 +422,7 @@ impl NodeModulesResolver {
         }
 
         let pkg_path = path.join(PACKAGE);
-        if allow_package_entry && pkg_path.is_file() {
+        if allow_package_entry && fs::is_file(&pkg_path) {
             if let Some(main) = self.resolve_package_entry(path, &pkg_path)? {
                 return Ok(Some(main));
             }
@@ -261,7 +431,7 @@ impl NodeModulesResolver {
         // Try to resolve to an index file.
         for ext in EXTENSIONS {
             let ext_path = path.join(format!("index.{}", ext));
-            if ext_path.is_file() {
+            if fs::is_file(&ext_path) {
                 return Ok(Some(ext_path));
             }
         }
@@ -288,10 +458,7 @@ impl NodeModulesResolver {
             None
         };
 
-        let file = File::open(pkg_path)?;
-        let reader = BufReader::new(file);
-        let pkg: PackageJson = serde_json::from_reader(reader)
-            .context(format!("failed to deserialize {}", pkg_path.display()))?;
+        let pkg = PackageJson::read(pkg_path)?;
 
         let main_fields = match self.target_env {
             TargetEnv::Node => {
@@ -385,16 +552,40 @@ impl NodeModulesResolver {
         &self,
         base_dir: &Path,
         target: &str,
+        kind: ImportKind,
     ) -> Result<Option<PathBuf>, Error> {
         if self.ignore_node_modules {
             return Ok(None);
         }
 
         let absolute_path = to_absolute_path(base_dir)?;
+        let (pkg_name, subpath) = split_package_specifier(target);
+
+        // A package can import itself by its name using `exports`.
+        if let Some(scope) = self.package_scope(&absolute_path)? {
+            if scope.name == pkg_name {
+                return self
+                    .resolve_package_exports(&scope.dir, &subpath, &scope.exports, kind)
+                    .map(Some);
+            }
+        }
+
         let mut path = Some(&*absolute_path);
         while let Some(dir) = path {
             let node_modules = dir.join("node_modules");
-            if node_modules.is_dir() {
+            if fs::is_dir(&node_modules) {
+                // `exports` takes precedence over other fields, and no other file of the
+                // package can be imported if it exists.
+                let pkg_dir = node_modules.join(pkg_name);
+                let pkg_path = pkg_dir.join(PACKAGE);
+                if fs::is_file(&pkg_path) {
+                    if let Some(exports) = PackageJson::read(&pkg_path)?.exports {
+                        return self
+                            .resolve_package_exports(&pkg_dir, &subpath, &exports, kind)
+                            .map(Some);
+                    }
+                }
+
                 let path = node_modules.join(target);
                 if let Some(result) = self
                     .resolve_as_file(&path)
@@ -411,7 +602,233 @@ impl NodeModulesResolver {
         Ok(None)
     }
 
-    fn resolve_filename(&self, base: &FileName, module_specifier: &str) -> Result<FileName, Error> {
+    /// Resolve `subpath` (e.g. `.` or `./foo`) of the package located at
+    /// `pkg_dir`, which is found by another resolver.
+    #[cfg_attr(not(feature = "pnp"), allow(dead_code))]
+    pub(crate) fn resolve_in_package(
+        &self,
+        pkg_dir: &Path,
+        subpath: &str,
+        kind: ImportKind,
+    ) -> Result<FileName, Error> {
+        let pkg_path = pkg_dir.join(PACKAGE);
+        if fs::is_file(&pkg_path) {
+            if let Some(exports) = PackageJson::read(&pkg_path)?.exports {
+                let path = self.resolve_package_exports(pkg_dir, subpath, &exports, kind)?;
+                return self.wrap(Some(path));
+            }
+        }
+
+        let path = match subpath.strip_prefix("./") {
+            Some(subpath) => pkg_dir.join(subpath),
+            None => pkg_dir.to_path_buf(),
+        };
+        let path = self
+            .resolve_as_file(&path)
+            .or_else(|_| self.resolve_as_directory(&path, true))?;
+
+        self.wrap(path)
+    }
+
+    /// Resolve `subpath` of a package using the `exports` field.
+    ///
+    /// See: https://nodejs.org/api/packages.html#package-entry-points
+    fn resolve_package_exports(
+        &self,
+        pkg_dir: &Path,
+        subpath: &str,
+        exports: &Exports,
+        kind: ImportKind,
+    ) -> Result<PathBuf, Error> {
+        let resolved = match exports {
+            Exports::Map(map) if map.0.iter().any(|(k, _)| k.starts_with('.')) => {
+                if map.0.iter().any(|(k, _)| !k.starts_with('.')) {
+                    bail!(
+                        "invalid `exports` in {}: keys should either all be subpaths or all be \
+                         conditions",
+                        pkg_dir.join(PACKAGE).display()
+                    )
+                }
+
+                self.resolve_subpath_map(pkg_dir, subpath, map, false, kind)?
+            }
+            // Conditions or a target for the main entry.
+            _ if subpath == "." => self.resolve_target(pkg_dir, exports, "", false, kind)?,
+            _ => None,
+        };
+
+        resolved.with_context(|| {
+            format!(
+                "package subpath `{}` is not exported from {}",
+                subpath,
+                pkg_dir.display()
+            )
+        })
+    }
+
+    /// Resolve `#name` using the `imports` field of the package which contains
+    /// `base_dir`.
+    fn resolve_package_imports(
+        &self,
+        base_dir: &Path,
+        target: &str,
+        kind: ImportKind,
+    ) -> Result<PathBuf, Error> {
+        let absolute_path = to_absolute_path(base_dir)?;
+        let pkg_dir = find_package_scope(&absolute_path)
+            .with_context(|| format!("failed to find package.json for `{}`", target))?;
+        let pkg = PackageJson::read(&pkg_dir.join(PACKAGE))?;
+
+        let resolved = match &pkg.imports {
+            Some(Exports::Map(map)) => {
+                self.resolve_subpath_map(&pkg_dir, target, map, true, kind)?
+            }
+            _ => None,
+        };
+
+        resolved.with_context(|| {
+            format!(
+                "`{}` is not defined in `imports` of {}",
+                target,
+                pkg_dir.join(PACKAGE).display()
+            )
+        })
+    }
+
+    /// Find the target of `key` from subpaths, which may contain a `*` pattern.
+    fn resolve_subpath_map(
+        &self,
+        pkg_dir: &Path,
+        key: &str,
+        map: &ExportsMap,
+        is_imports: bool,
+        kind: ImportKind,
+    ) -> Result<Option<PathBuf>, Error> {
+        if let Some((_, target)) = map.0.iter().find(|(k, _)| k == key && !k.contains('*')) {
+            return self.resolve_target(pkg_dir, target, "", is_imports, kind);
+        }
+
+        // The pattern with the longest prefix wins.
+        let mut best: Option<(&str, &str, &Exports)> = None;
+        for (k, target) in &map.0 {
+            let (prefix, suffix) = match k.split_once('*') {
+                Some(v) if !v.1.contains('*') => v,
+                _ => continue,
+            };
+            if key.len() < k.len() || !key.starts_with(prefix) || !key.ends_with(suffix) {
+                continue;
+            }
+
+            let is_better = match best {
+                Some((best_key, best_prefix, _)) => {
+                    prefix.len() > best_prefix.len()
+                        || (prefix.len() == best_prefix.len() && k.len() > best_key.len())
+                }
+                None => true,
+            };
+            if is_better {
+                best = Some((k, prefix, target));
+            }
+        }
+
+        match best {
+            Some((k, prefix, target)) => {
+                let suffix_len = k.len() - prefix.len() - 1;
+                let pattern_match = &key[prefix.len()..key.len() - suffix_len];
+
+                self.resolve_target(pkg_dir, target, pattern_match, is_imports, kind)
+            }
+            None => Ok(None),
+        }
+    }
+
+    /// Resolve a target of `exports` or `imports`.
+    ///
+    /// Returns [None] if no condition matches.
+    fn resolve_target(
+        &self,
+        pkg_dir: &Path,
+        target: &Exports,
+        pattern_match: &str,
+        is_imports: bool,
+        kind: ImportKind,
+    ) -> Result<Option<PathBuf>, Error> {
+        match target {
+            Exports::Null(..) => bail!("the subpath is excluded by {}", pkg_dir.display()),
+            Exports::Str(target) => {
+                let target = target.replace('*', pattern_match);
+
+                if !target.starts_with("./") {
+                    // Only `imports` can map to other packages.
+                    if is_imports && !target.starts_with("../") && !target.starts_with('/') {
+                        return self
+                            .resolve_node_modules(pkg_dir, &target, kind)?
+                            .with_context(|| format!("failed to resolve `{}`", target))
+                            .map(Some);
+                    }
+
+                    bail!(
+                        "invalid target `{}` in {}",
+                        target,
+                        pkg_dir.join(PACKAGE).display()
+                    )
+                }
+
+                // Targets should not escape the package.
+                let is_invalid = target[2..].split(['/', '\\']).any(|segment| {
+                    segment == "."
+                        || segment == ".."
+                        || segment.eq_ignore_ascii_case("node_modules")
+                });
+                if is_invalid {
+                    bail!(
+                        "invalid target `{}` in {}",
+                        target,
+                        pkg_dir.join(PACKAGE).display()
+                    )
+                }
+
+                self.resolve_as_file(&pkg_dir.join(&target[2..]))
+            }
+            Exports::Array(targets) => {
+                let mut last_err = None;
+                for target in targets {
+                    match self.resolve_target(pkg_dir, target, pattern_match, is_imports, kind) {
+                        Ok(Some(v)) => return Ok(Some(v)),
+                        Ok(None) => {}
+                        Err(err) => last_err = Some(err),
+                    }
+                }
+
+                match last_err {
+                    Some(err) => Err(err),
+                    None => Ok(None),
+                }
+            }
+            Exports::Map(conditions) => {
+                for (condition, target) in &conditions.0 {
+                    if !self.matches_condition(condition, kind) {
+                        continue;
+                    }
+
+                    if let Some(v) =
+                        self.resolve_target(pkg_dir, target, pattern_match, is_imports, kind)?
+                    {
+                        return Ok(Some(v));
+                    }
+                }
+
+                Ok(None)
+            }
+        }
+    }
+
+    fn resolve_filename(
+        &self,
+        base: &FileName,
+        module_specifier: &str,
+        kind: ImportKind,
+    ) -> Result<FileName, Error> {
         debug!(
             "Resolving {} from {:#?} for {:#?}",
             module_specifier, base, self.target_env
@@ -434,7 +851,7 @@ impl NodeModulesResolver {
             _ => bail!("node-resolver supports only files"),
         };
 
-        let base_dir = if base.is_file() {
+        let base_dir = if fs::is_file(base) {
             let cwd = &Path::new(".");
             base.parent().unwrap_or(cwd)
         } else {
@@ -479,7 +896,13 @@ impl NodeModulesResolver {
         let target_path = Path::new(target);
 
         let file_name = {
-            if target_path.is_absolute() {
+            if target.starts_with('#') {
+                self.resolve_package_imports(base_dir, target, kind)
+                    .and_then(|path| {
+                        let current_directory = current_dir()?;
+                        self.wrap(diff_paths(path, current_directory))
+                    })
+            } else if target_path.is_absolute() {
                 let path = PathBuf::from(target_path);
                 self.resolve_as_file(&path)
                     .or_else(|_| self.resolve_as_directory(&path, true))
@@ -503,7 +926,7 @@ impl NodeModulesResolver {
                         .or_else(|_| self.resolve_as_directory(&path, true))
                         .and_then(|p| self.wrap(p))
                 } else {
-                    self.resolve_node_modules(base_dir, target)
+                    self.resolve_node_modules(base_dir, target, kind)
                         .and_then(|path| {
                             let file_path = path.context("failed to get the node_modules path");
                             let current_directory = current_dir()?;
@@ -541,10 +964,27 @@ impl NodeModulesResolver {
 
 impl Resolve for NodeModulesResolver {
     fn resolve(&self, base: &FileName, module_specifier: &str) -> Result<Resolution, Error> {
-        self.resolve_filename(base, module_specifier)
-            .map(|filename| Resolution {
-                filename,
-                slug: None,
+        self.resolve_with_kind(base, module_specifier, ImportKind::Import)
+    }
+
+    fn resolve_with_kind(
+        &self,
+        base: &FileName,
+        module_specifier: &str,
+        kind: ImportKind,
+    ) -> Result<Resolution, Error> {
+        self.resolve_filename(base, module_specifier, kind)
+            .map(|filename| {
+                // Subpath imports like `#env` are not file names, so the name of the
+                // resolved file is used instead.
+                let slug = match &filename {
+                    FileName::Real(path) if module_specifier.starts_with('#') => {
+                        path.file_stem().map(|stem| stem.to_string_lossy().into())
+                    }
+                    _ => None,
+                };
+
+                Resolution { filename, slug }
             })
     }
 }
@@ -0,0 +1,527 @@
+//! Resolver for Yarn Plug'n'Play.
+//!
+//! Dependencies are resolved using the PnP manifest (`.pnp.data.json` or
+//! `.pnp.cjs`) instead of `node_modules`, and files in zip archives of the
+//! Yarn cache are read directly.
+//!
+//! See: https://yarnpkg.com/advanced/pnp-spec
+
+use std::{
+    io,
+    path::{Path, PathBuf},
+};
+
+use anyhow::{bail, Context, Error};
+use path_clean::PathClean;
+use rustc_hash::{FxHashMap, FxHashSet};
+use serde::Deserialize;
+use swc_common::FileName;
+use tracing::debug;
+
+use super::{
+    fs,
+    node::{is_core_module, split_package_specifier, to_absolute_path, NodeModulesResolver},
+};
+use crate::resolve::{ImportKind, Resolution, Resolve};
+
+static MANIFEST_FILES: &[&str] = &[".pnp.data.json", ".pnp.cjs", ".pnp.js"];
+
+/// Find the PnP manifest of the project which contains `dir`.
+pub fn find_manifest(dir: &Path) -> Option<PathBuf> {
+    let dir = to_absolute_path(dir).ok()?;
+
+    dir.ancestors().find_map(|dir| {
+        MANIFEST_FILES
+            .iter()
+            .map(|name| dir.join(name))
+            .find(|path| path.is_file())
+    })
+}
+
+/// Read a file, which may be in a zip archive of the Yarn cache.
+///
+/// Loaders should use this to load files resolved by [PnpResolver].
+pub fn read_to_string(path: &Path) -> io::Result<String> {
+    fs::read_to_string(path)
+}
+
+/// Returns true if `path` is in a zip archive or a virtual path of Yarn.
+pub fn is_archive_path(path: &Path) -> bool {
+    zip_fs::is_virtual_or_zip(path)
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct Locator {
+    name: Option<String>,
+    reference: Option<String>,
+}
+
+impl Locator {
+    const TOP_LEVEL: Locator = Locator {
+        name: None,
+        reference: None,
+    };
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum Dependency {
+    Reference(String),
+    /// `[name, reference]`, for aliased packages.
+    Alias(String, String),
+}
+
+impl Dependency {
+    fn locator(&self, name: &str) -> Locator {
+        match self {
+            Dependency::Reference(reference) => Locator {
+                name: Some(name.to_string()),
+                reference: Some(reference.clone()),
+            },
+            Dependency::Alias(name, reference) => Locator {
+                name: Some(name.clone()),
+                reference: Some(reference.clone()),
+            },
+        }
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RawManifest {
+    #[serde(default)]
+    enable_top_level_fallback: bool,
+    #[serde(default)]
+    fallback_exclusion_list: Vec<(String, Vec<String>)>,
+    #[serde(default)]
+    fallback_pool: Vec<(String, Option<Dependency>)>,
+    package_registry_data: Vec<(Option<String>, Vec<(Option<String>, RawPackage)>)>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RawPackage {
+    package_location: String,
+    #[serde(default)]
+    package_dependencies: Vec<(String, Option<Dependency>)>,
+}
+
+struct Package {
+    location: PathBuf,
+    /// [None] for unmet peer dependencies.
+    dependencies: FxHashMap<String, Option<Dependency>>,
+}
+
+struct Manifest {
+    packages: FxHashMap<Locator, Package>,
+    /// Sorted by the length of the path, in descending order.
+    locations: Vec<(PathBuf, Locator)>,
+    enable_top_level_fallback: bool,
+    fallback_exclusions: FxHashSet<Locator>,
+    fallback_pool: FxHashMap<String, Option<Dependency>>,
+}
+
+impl Manifest {
+    fn load(path: &Path) -> Result<Self, Error> {
+        let content = std::fs::read_to_string(path)
+            .with_context(|| format!("failed to read {}", path.display()))?;
+
+        let json = if path.extension().map_or(false, |ext| ext == "json") {
+            content
+        } else {
+            extract_runtime_state(&content).with_context(|| {
+                format!("failed to find the runtime state in {}", path.display())
+            })?
+        };
+
+        let raw: RawManifest = serde_json::from_str(&json)
+            .with_context(|| format!("failed to deserialize {}", path.display()))?;
+
+        let dir = to_absolute_path(path.parent().unwrap_or(Path::new(".")))?;
+
+        let mut packages = FxHashMap::default();
+        let mut locations = Vec::new();
+        for (name, references) in raw.package_registry_data {
+            for (reference, pkg) in references {
+                let locator = Locator {
+                    name: name.clone(),
+                    reference,
+                };
+                let location = dir.join(&pkg.package_location).clean();
+
+                locations.push((location.clone(), locator.clone()));
+                packages.insert(
+                    locator,
+                    Package {
+                        location,
+                        dependencies: pkg.package_dependencies.into_iter().collect(),
+                    },
+                );
+            }
+        }
+        locations.sort_by_key(|(path, _)| std::cmp::Reverse(path.as_os_str().len()));
+
+        let fallback_exclusions = raw
+            .fallback_exclusion_list
+            .into_iter()
+            .flat_map(|(name, references)| {
+                references.into_iter().map(move |reference| Locator {
+                    name: Some(name.clone()),
+                    reference: Some(reference),
+                })
+            })
+            .collect();
+
+        Ok(Self {
+            packages,
+            locations,
+            enable_top_level_fallback: raw.enable_top_level_fallback,
+            fallback_exclusions,
+            fallback_pool: raw.fallback_pool.into_iter().collect(),
+        })
+    }
+
+    /// Find the package which contains `path`.
+    fn find_locator(&self, path: &Path) -> Option<&Locator> {
+        self.locations
+            .iter()
+            .find(|(location, _)| path.starts_with(location))
+            .map(|(_, locator)| locator)
+    }
+
+    /// Find the package `name` required by `issuer`.
+    fn resolve_dependency(&self, issuer: &Locator, name: &str) -> Result<Option<&Package>, Error> {
+        let issuer_pkg = match self.packages.get(issuer) {
+            Some(v) => v,
+            None => bail!("package {:?} is not registered in the PnP manifest", issuer),
+        };
+
+        let dependency = match issuer_pkg.dependencies.get(name) {
+            Some(Some(dep)) => Some(dep),
+            Some(None) => bail!(
+                "{} tried to access {} (a peer dependency), but it isn't provided by its ancestors",
+                issuer_name(issuer),
+                name
+            ),
+            None if self.enable_top_level_fallback
+                && !self.fallback_exclusions.contains(issuer) =>
+            {
+                self.packages
+                    .get(&Locator::TOP_LEVEL)
+                    .and_then(|top_level| top_level.dependencies.get(name))
+                    .or_else(|| self.fallback_pool.get(name))
+                    .and_then(Option::as_ref)
+            }
+            None => None,
+        };
+
+        Ok(dependency.and_then(|dep| self.packages.get(&dep.locator(name))))
+    }
+}
+
+fn issuer_name(locator: &Locator) -> &str {
+    locator.name.as_deref().unwrap_or("the top-level package")
+}
+
+/// Extract the JSON state embedded in `.pnp.cjs`, which is a single-quoted
+/// string literal assigned to `RAW_RUNTIME_STATE`.
+fn extract_runtime_state(script: &str) -> Option<String> {
+    let start = script.find("RAW_RUNTIME_STATE")?;
+    let literal = &script[start..];
+    let literal = &literal[literal.find('\'')? + 1..];
+
+    let mut json = String::with_capacity(literal.len());
+    let mut chars = literal.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '\'' => return Some(json),
+            '\\' => match chars.next()? {
+                // Line continuation
+                '\n' => {}
+                c => json.push(c),
+            },
+            c => json.push(c),
+        }
+    }
+
+    None
+}
+
+/// Resolves dependencies using the PnP manifest.
+///
+/// Relative paths, builtin modules and files outside of the project are
+/// resolved by the inner [NodeModulesResolver], which is also used to resolve
+/// files in the packages found from the manifest.
+pub struct PnpResolver {
+    manifest: Manifest,
+    inner: NodeModulesResolver,
+}
+
+impl PnpResolver {
+    /// Load the PnP manifest at `manifest_path`, which is either
+    /// `.pnp.data.json` or `.pnp.cjs`.
+    pub fn new(manifest_path: &Path, inner: NodeModulesResolver) -> Result<Self, Error> {
+        Ok(Self {
+            manifest: Manifest::load(manifest_path)?,
+            inner,
+        })
+    }
+
+    fn resolve_filename(
+        &self,
+        base: &FileName,
+        module_specifier: &str,
+        kind: ImportKind,
+    ) -> Result<FileName, Error> {
+        let is_bare = !(module_specifier.starts_with('.')
+            || module_specifier.starts_with('#')
+            || module_specifier.starts_with("node:")
+            || Path::new(module_specifier).is_absolute());
+
+        let base_path = match base {
+            FileName::Real(v) if is_bare => to_absolute_path(v)?,
+            _ => {
+                return self
+                    .inner
+                    .resolve_with_kind(base, module_specifier, kind)
+                    .map(|v| v.filename)
+            }
+        };
+
+        let issuer = match self.manifest.find_locator(&base_path) {
+            Some(v) => v,
+            // Not managed by PnP
+            None => {
+                return self
+                    .inner
+                    .resolve_with_kind(base, module_specifier, kind)
+                    .map(|v| v.filename)
+            }
+        };
+
+        let (pkg_name, subpath) = split_package_specifier(module_specifier);
+
+        match self.manifest.resolve_dependency(issuer, pkg_name)? {
+            Some(pkg) => {
+                debug!(
+                    "Resolved {} from {} to {}",
+                    pkg_name,
+                    issuer_name(issuer),
+                    pkg.location.display()
+                );
+
+                self.inner.resolve_in_package(&pkg.location, &subpath, kind)
+            }
+            None if is_core_module(module_specifier) => self
+                .inner
+                .resolve_with_kind(base, module_specifier, kind)
+                .map(|v| v.filename),
+            None => bail!(
+                "{} tried to access {}, but it isn't declared in its dependencies",
+                issuer_name(issuer),
+                pkg_name
+            ),
+        }
+    }
+}
+
+impl Resolve for PnpResolver {
+    fn resolve(&self, base: &FileName, module_specifier: &str) -> Result<Resolution, Error> {
+        self.resolve_with_kind(base, module_specifier, ImportKind::Import)
+    }
+
+    fn resolve_with_kind(
+        &self,
+        base: &FileName,
+        module_specifier: &str,
+        kind: ImportKind,
+    ) -> Result<Resolution, Error> {
+        self.resolve_filename(base, module_specifier, kind)
+            .map(|filename| Resolution {
+                filename,
+                slug: None,
+            })
+    }
+}
+
+/// Read-only access to zip archives of the Yarn cache.
+pub(crate) mod zip_fs {
+    use std::{
+        fs::File,
+        io::{self, Read},
+        path::{Component, Path, PathBuf},
+        sync::{Arc, Mutex},
+    };
+
+    use dashmap::DashMap;
+    use once_cell::sync::Lazy;
+    use path_clean::PathClean;
+    use rustc_hash::{FxBuildHasher, FxHashSet};
+    use zip::ZipArchive;
+
+    static ARCHIVES: Lazy<DashMap<PathBuf, Option<Arc<Archive>>, FxBuildHasher>> =
+        Lazy::new(Default::default);
+
+    struct Archive {
+        files: FxHashSet<String>,
+        dirs: FxHashSet<String>,
+        zip: Mutex<ZipArchive<File>>,
+    }
+
+    fn open(path: &Path) -> Option<Arc<Archive>> {
+        if let Some(v) = ARCHIVES.get(path) {
+            return v.clone();
+        }
+
+        let archive = File::open(path)
+            .ok()
+            .and_then(|file| ZipArchive::new(file).ok())
+            .map(|zip| {
+                let mut files = FxHashSet::default();
+                let mut dirs = FxHashSet::default();
+
+                for name in zip.file_names() {
+                    let name = name.trim_end_matches('/');
+                    let mut parent = Path::new(name).parent();
+                    while let Some(p) = parent {
+                        dirs.insert(p.to_string_lossy().to_string());
+                        parent = p.parent();
+                    }
+
+                    if zip.index_for_name(name).is_some() {
+                        files.insert(name.to_string());
+                    } else {
+                        dirs.insert(name.to_string());
+                    }
+                }
+
+                Arc::new(Archive {
+                    files,
+                    dirs,
+                    zip: Mutex::new(zip),
+                })
+            });
+
+        ARCHIVES.insert(path.to_path_buf(), archive.clone());
+        archive
+    }
+
+    /// Map a virtual path like `.yarn/__virtual__/pkg-virtual-hash/2/a/b` to
+    /// the real path, which is `a/b` in the parent of `.yarn`, in this case.
+    fn resolve_virtual(path: &Path) -> Option<PathBuf> {
+        let components = path.components().collect::<Vec<_>>();
+        let idx = components
+            .iter()
+            .position(|c| c.as_os_str() == "__virtual__")?;
+
+        let depth = components
+            .get(idx + 2)?
+            .as_os_str()
+            .to_str()?
+            .parse::<usize>()
+            .ok()?;
+
+        let mut real = components[..idx].iter().collect::<PathBuf>();
+        for _ in 0..depth {
+            real.pop();
+        }
+        real.extend(&components[(idx + 3).min(components.len())..]);
+
+        Some(real)
+    }
+
+    /// Split a path into the path of the zip archive and the path in it.
+    fn split_zip_path(path: &Path) -> Option<(PathBuf, String)> {
+        let path = path.to_path_buf().clean();
+        let mut archive = PathBuf::new();
+        let mut components = path.components();
+
+        for c in components.by_ref() {
+            archive.push(c);
+
+            if let Component::Normal(name) = c {
+                if name.to_string_lossy().ends_with(".zip") {
+                    let inner = components
+                        .map(|c| c.as_os_str().to_string_lossy())
+                        .collect::<Vec<_>>()
+                        .join("/");
+
+                    return Some((archive, inner));
+                }
+            }
+        }
+
+        None
+    }
+
+    /// Returns true if `path` has a `__virtual__` or `*.zip` component.
+    ///
+    /// This does not allocate, so it's checked before others for normal paths.
+    fn may_be_virtual_or_zip(path: &Path) -> bool {
+        path.components().any(|c| match c {
+            Component::Normal(name) => {
+                name == "__virtual__" || name.to_string_lossy().ends_with(".zip")
+            }
+            _ => false,
+        })
+    }
+
+    pub(crate) fn is_virtual_or_zip(path: &Path) -> bool {
+        may_be_virtual_or_zip(path)
+            && (resolve_virtual(path).is_some() || split_zip_path(path).is_some())
+    }
+
+    /// Returns [None] if `path` is a normal path.
+    fn with_path<T>(
+        path: &Path,
+        zip: impl FnOnce(Option<Arc<Archive>>, &str) -> T,
+        real: impl FnOnce(&Path) -> T,
+    ) -> Option<T> {
+        if !may_be_virtual_or_zip(path) {
+            return None;
+        }
+
+        let resolved = resolve_virtual(path);
+        let path = resolved.as_deref().unwrap_or(path);
+
+        match split_zip_path(path) {
+            Some((archive, inner)) => Some(zip(open(&archive), &inner)),
+            None if resolved.is_some() => Some(real(path)),
+            None => None,
+        }
+    }
+
+    pub(crate) fn is_file(path: &Path) -> Option<bool> {
+        with_path(
+            path,
+            |archive, inner| archive.map_or(false, |a| a.files.contains(inner)),
+            Path::is_file,
+        )
+    }
+
+    pub(crate) fn is_dir(path: &Path) -> Option<bool> {
+        with_path(
+            path,
+            |archive, inner| archive.map_or(false, |a| inner.is_empty() || a.dirs.contains(inner)),
+            Path::is_dir,
+        )
+    }
+
+    pub(crate) fn read_to_string(path: &Path) -> Option<io::Result<String>> {
+        with_path(
+            path,
+            |archive, inner| {
+                let archive = archive.ok_or_else(|| {
+                    io::Error::new(io::ErrorKind::NotFound, "failed to open zip archive")
+                })?;
+                let mut zip = archive.zip.lock().unwrap();
+                let mut file = zip.by_name(inner)?;
+
+                let mut content = String::new();
+                file.read_to_string(&mut content)?;
+                Ok(content)
+            },
+            |path| std::fs::read_to_string(path),
+        )
+    }
+}
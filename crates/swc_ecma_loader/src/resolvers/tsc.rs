@@ -7,7 +7,7 @@ use anyhow::{bail, Context, Error};
 use swc_common::FileName;
 use tracing::{debug, info, trace, warn, Level};
 
-use crate::resolve::{Resolution, Resolve};
+use crate::resolve::{ImportKind, Resolution, Resolve};
 
 #[derive(Debug)]
 enum Pattern {
@@ -116,14 +116,18 @@ where
         &self,
         base: &FileName,
         module_specifier: &str,
+        kind: ImportKind,
     ) -> Result<Resolution, Error> {
-        let res = self.inner.resolve(base, module_specifier).with_context(|| {
-            format!(
-                "failed to resolve `{module_specifier}` from `{base}` using inner \
-                 resolver\nbase_url={}",
-                self.base_url_filename
-            )
-        });
+        let res = self
+            .inner
+            .resolve_with_kind(base, module_specifier, kind)
+            .with_context(|| {
+                format!(
+                    "failed to resolve `{module_specifier}` from `{base}` using inner \
+                     resolver\nbase_url={}",
+                    self.base_url_filename
+                )
+            });
 
         match res {
             Ok(resolved) => {
@@ -173,6 +177,15 @@ where
     R: Resolve,
 {
     fn resolve(&self, base: &FileName, module_specifier: &str) -> Result<Resolution, Error> {
+        self.resolve_with_kind(base, module_specifier, ImportKind::Import)
+    }
+
+    fn resolve_with_kind(
+        &self,
+        base: &FileName,
+        module_specifier: &str,
+        kind: ImportKind,
+    ) -> Result<Resolution, Error> {
         let _tracing = if cfg!(debug_assertions) {
             Some(
                 tracing::span!(
@@ -194,7 +207,7 @@ where
                 || module_specifier.starts_with("../"))
         {
             return self
-                .invoke_inner_resolver(base, module_specifier)
+                .invoke_inner_resolver(base, module_specifier, kind)
                 .context("not processed by tsc resolver because it's relative import");
         }
 
@@ -203,9 +216,11 @@ where
                 Component::Normal(v) => v == "node_modules",
                 _ => false,
             }) {
-                return self.invoke_inner_resolver(base, module_specifier).context(
-                    "not processed by tsc resolver because base module is in node_modules",
-                );
+                return self
+                    .invoke_inner_resolver(base, module_specifier, kind)
+                    .context(
+                        "not processed by tsc resolver because base module is in node_modules",
+                    );
             }
         }
 
@@ -252,12 +267,12 @@ where
                         let relative = format!("./{}", replaced);
 
                         let res = self
-                            .invoke_inner_resolver(base, module_specifier)
+                            .invoke_inner_resolver(base, module_specifier, kind)
                             .or_else(|_| {
-                                self.invoke_inner_resolver(&self.base_url_filename, &relative)
+                                self.invoke_inner_resolver(&self.base_url_filename, &relative, kind)
                             })
                             .or_else(|_| {
-                                self.invoke_inner_resolver(&self.base_url_filename, &replaced)
+                                self.invoke_inner_resolver(&self.base_url_filename, &replaced, kind)
                             });
 
                         errors.push(match res {
@@ -312,9 +327,11 @@ where
                         });
                     }
 
-                    if let Ok(res) = self
-                        .invoke_inner_resolver(&self.base_url_filename, &format!("./{}", &to[0]))
-                    {
+                    if let Ok(res) = self.invoke_inner_resolver(
+                        &self.base_url_filename,
+                        &format!("./{}", &to[0]),
+                        kind,
+                    ) {
                         return Ok(Resolution { slug, ..res });
                     }
 
@@ -331,11 +348,11 @@ where
             let path = self.base_url.join(module_specifier);
 
             // https://www.typescriptlang.org/docs/handbook/modules/reference.html#baseurl
-            if let Ok(v) = self.invoke_inner_resolver(base, &path.to_string_lossy()) {
+            if let Ok(v) = self.invoke_inner_resolver(base, &path.to_string_lossy(), kind) {
                 return Ok(v);
             }
         }
 
-        self.invoke_inner_resolver(base, module_specifier)
+        self.invoke_inner_resolver(base, module_specifier, kind)
     }
 }
@@ -10,7 +10,11 @@ use std::{
 use lazy_static::lazy_static;
 use swc_common::FileName;
 extern crate swc_ecma_loader;
-use swc_ecma_loader::{resolve::Resolve, resolvers::node::NodeModulesResolver, TargetEnv};
+use swc_ecma_loader::{
+    resolve::{ImportKind, Resolve},
+    resolvers::node::NodeModulesResolver,
+    TargetEnv,
+};
 
 lazy_static! {
     static ref UPDATE_DIR_MUTEX: Arc<Mutex<()>> = Arc::new(Mutex::new(()));
@@ -105,3 +109,127 @@ fn browser_overwrite() {
         );
     });
 }
+
+#[test]
+fn exports_conditions() {
+    inside_directory("/tests/exports", || {
+        let base = FileName::Real(PathBuf::from("src/index.js"));
+
+        let node_resolver = NodeModulesResolver::new(TargetEnv::Node, Default::default(), true);
+        let resolved = node_resolver.resolve(&base, "pkg").expect("should resolve");
+        assert_eq!(
+            resolved.filename,
+            FileName::Real(PathBuf::from("node_modules/pkg/esm/index.mjs"))
+        );
+
+        let resolved = node_resolver
+            .resolve_with_kind(&base, "pkg", ImportKind::Require)
+            .expect("should resolve");
+        assert_eq!(
+            resolved.filename,
+            FileName::Real(PathBuf::from("node_modules/pkg/cjs/index.js"))
+        );
+
+        let browser_resolver =
+            NodeModulesResolver::new(TargetEnv::Browser, Default::default(), true);
+        let resolved = browser_resolver
+            .resolve(&base, "pkg")
+            .expect("should resolve");
+        assert_eq!(
+            resolved.filename,
+            FileName::Real(PathBuf::from("node_modules/pkg/browser.mjs"))
+        );
+
+        let require_resolver = NodeModulesResolver::new(TargetEnv::Node, Default::default(), true)
+            .with_conditions(vec!["node".into(), "require".into()]);
+        let resolved = require_resolver
+            .resolve(&base, "pkg")
+            .expect("should resolve");
+        assert_eq!(
+            resolved.filename,
+            FileName::Real(PathBuf::from("node_modules/pkg/cjs/index.js"))
+        );
+    });
+}
+
+#[test]
+fn exports_subpaths() {
+    inside_directory("/tests/exports", || {
+        let base = FileName::Real(PathBuf::from("src/index.js"));
+        let node_resolver = NodeModulesResolver::new(TargetEnv::Node, Default::default(), true);
+
+        let resolved = node_resolver
+            .resolve(&base, "pkg/features/a.js")
+            .expect("should resolve");
+        assert_eq!(
+            resolved.filename,
+            FileName::Real(PathBuf::from("node_modules/pkg/src/features/a.js"))
+        );
+
+        let resolved = node_resolver
+            .resolve(&base, "pkg/package.json")
+            .expect("should resolve");
+        assert_eq!(
+            resolved.filename,
+            FileName::Real(PathBuf::from("node_modules/pkg/package.json"))
+        );
+
+        // Excluded by `null`.
+        assert!(node_resolver
+            .resolve(&base, "pkg/features/private/b.js")
+            .is_err());
+        // Not exported, although the file exists.
+        assert!(node_resolver.resolve(&base, "pkg/internal.js").is_err());
+
+        // Self-reference
+        let resolved = node_resolver.resolve(&base, "app").expect("should resolve");
+        assert_eq!(
+            resolved.filename,
+            FileName::Real(PathBuf::from("src/index.js"))
+        );
+    });
+}
+
+#[test]
+fn imports() {
+    inside_directory("/tests/exports", || {
+        let base = FileName::Real(PathBuf::from("src/index.js"));
+
+        let node_resolver = NodeModulesResolver::new(TargetEnv::Node, Default::default(), true);
+        let resolved = node_resolver
+            .resolve(&base, "#utils/a")
+            .expect("should resolve");
+        assert_eq!(
+            resolved.filename,
+            FileName::Real(PathBuf::from("src/utils/a.js"))
+        );
+
+        let resolved = node_resolver
+            .resolve(&base, "#dep")
+            .expect("should resolve");
+        assert_eq!(
+            resolved.filename,
+            FileName::Real(PathBuf::from("node_modules/pkg/esm/index.mjs"))
+        );
+
+        let resolved = node_resolver
+            .resolve_with_kind(&base, "pkg", ImportKind::Require)
+            .expect("should resolve");
+        assert_eq!(
+            resolved.filename,
+            FileName::Real(PathBuf::from("node_modules/pkg/cjs/index.js"))
+        );
+
+        let browser_resolver =
+            NodeModulesResolver::new(TargetEnv::Browser, Default::default(), true);
+        let resolved = browser_resolver
+            .resolve(&base, "#dep")
+            .expect("should resolve");
+        assert_eq!(
+            resolved.filename,
+            FileName::Real(PathBuf::from("src/dep-browser.js"))
+        );
+
+        assert!(node_resolver.resolve(&base, "#unknown").is_err());
+    });
+}
@@ -0,0 +1 @@
+import get from 'lodash/get';
@@ -0,0 +1,61 @@
+#!/usr/bin/env node
+/* eslint-disable */
+"use strict";
+
+const RAW_RUNTIME_STATE =
+'{\
+  "__info": [\
+    "This file is automatically generated."\
+  ],\
+  "dependencyTreeRoots": [\
+    {\
+      "name": "app",\
+      "reference": "workspace:."\
+    },\
+    {\
+      "name": "utils",\
+      "reference": "workspace:packages/utils"\
+    }\
+  ],\
+  "enableTopLevelFallback": false,\
+  "ignorePatternData": null,\
+  "fallbackExclusionList": [],\
+  "fallbackPool": [],\
+  "packageRegistryData": [\
+    [\
+      null,\
+      [\
+        [\
+          null,\
+          {\
+            "packageLocation": "./",\
+            "packageDependencies": [\
+              [\
+                "dep",\
+                "npm:1.0.0"\
+              ]\
+            ],\
+            "linkType": "SOFT"\
+          }\
+        ]\
+      ]\
+    ],\
+    [\
+      "dep",\
+      [\
+        [\
+          "npm:1.0.0",\
+          {\
+            "packageLocation": "./deps/dep/",\
+            "packageDependencies": [],\
+            "linkType": "HARD"\
+          }\
+        ]\
+      ]\
+    ]\
+  ]\
+}';
+
+function $$SETUP_STATE(hydrateRuntimeState, basePath) {
+  return hydrateRuntimeState(JSON.parse(RAW_RUNTIME_STATE), {basePath: basePath || __dirname});
+}
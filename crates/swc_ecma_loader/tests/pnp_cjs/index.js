@@ -0,0 +1 @@
+import dep from 'dep';
@@ -0,0 +1,118 @@
+#![cfg(feature = "pnp")]
+
+use std::path::{Path, PathBuf};
+
+use swc_common::FileName;
+use swc_ecma_loader::{
+    resolve::Resolve,
+    resolvers::{
+        node::NodeModulesResolver,
+        pnp::{find_manifest, read_to_string, PnpResolver},
+    },
+    TargetEnv,
+};
+
+fn fixture(name: &str) -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("tests")
+        .join(name)
+}
+
+fn resolver(dir: &Path) -> PnpResolver {
+    let manifest = find_manifest(&dir.join("src")).expect("should find manifest");
+
+    PnpResolver::new(
+        &manifest,
+        NodeModulesResolver::new(TargetEnv::Node, Default::default(), true),
+    )
+    .expect("should load manifest")
+}
+
+#[test]
+fn zip_archive() {
+    let dir = fixture("pnp");
+    let resolver = resolver(&dir);
+    let zip = dir.join(".yarn/cache/lodash-npm-1.0.0-abc.zip/node_modules/lodash");
+
+    let resolved = resolver
+        .resolve(&FileName::Real(dir.join("src/index.js")), "lodash/get")
+        .expect("should resolve");
+    assert_eq!(resolved.filename, FileName::Real(zip.join("get.js")));
+
+    let resolved = resolver
+        .resolve(&FileName::Real(dir.join("src/index.js")), "lodash")
+        .expect("should resolve");
+    assert_eq!(resolved.filename, FileName::Real(zip.join("lodash.js")));
+
+    // Relative imports in the archive
+    let resolved = resolver
+        .resolve(&FileName::Real(zip.join("get.js")), "./internal/baseGet")
+        .expect("should resolve");
+    assert_eq!(
+        resolved.filename,
+        FileName::Real(zip.join("internal/baseGet.js"))
+    );
+
+    let content = read_to_string(&zip.join("internal/baseGet.js")).expect("should read");
+    assert!(content.contains("function baseGet"), "{}", content);
+}
+
+#[test]
+fn workspace() {
+    let dir = fixture("pnp");
+    let resolver = resolver(&dir);
+
+    let resolved = resolver
+        .resolve(&FileName::Real(dir.join("src/index.js")), "utils")
+        .expect("should resolve");
+    assert_eq!(
+        resolved.filename,
+        FileName::Real(dir.join("packages/utils/index.js"))
+    );
+
+    let resolved = resolver
+        .resolve(&FileName::Real(dir.join("src/index.js")), "path")
+        .expect("should resolve");
+    assert_eq!(resolved.filename, FileName::Custom("node:path".to_string()));
+}
+
+#[test]
+fn undeclared_dependency() {
+    let dir = fixture("pnp");
+    let resolver = resolver(&dir);
+
+    // `utils` does not depend on `lodash`.
+    let err = resolver
+        .resolve(
+            &FileName::Real(dir.join("packages/utils/index.js")),
+            "lodash",
+        )
+        .expect_err("should not resolve");
+    assert!(
+        err.to_string()
+            .contains("isn't declared in its dependencies"),
+        "{:?}",
+        err
+    );
+}
+
+#[test]
+fn runtime_state_of_script() {
+    let dir = fixture("pnp_cjs");
+    let manifest = find_manifest(&dir).expect("should find manifest");
+    assert!(manifest.ends_with(".pnp.cjs"), "{}", manifest.display());
+
+    let resolver = PnpResolver::new(
+        &manifest,
+        NodeModulesResolver::new(TargetEnv::Node, Default::default(), true),
+    )
+    .expect("should load manifest");
+
+    let resolved = resolver
+        .resolve(&FileName::Real(dir.join("index.js")), "dep")
+        .expect("should resolve");
+    assert_eq!(
+        resolved.filename,
+        FileName::Real(dir.join("deps/dep/index.js"))
+    );
+}
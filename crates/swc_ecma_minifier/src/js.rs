@@ -53,6 +53,15 @@ pub struct JsMinifyOptions {
 
     #[serde(default = "true_by_default")]
     pub emit_source_map_columns: bool,
+
+    /// Path to a JSON file to persist mangled property names, in the format
+    /// of `nameCache` of terser.
+    ///
+    /// The file is read before mangling and written after it, so that
+    /// `mangle.props` yields the same names across builds. It's read once per
+    /// process and shared by all files minified with it.
+    #[serde(default)]
+    pub name_cache_file: Option<String>,
 }
 
 fn true_by_default() -> bool {
@@ -1,6 +1,6 @@
 #![cfg_attr(not(feature = "extra-serde"), allow(unused))]
 
-use std::sync::Arc;
+use std::{collections::BTreeMap, fs, io, path::Path, sync::Arc};
 
 use parking_lot::RwLock;
 use rustc_hash::FxHashMap;
@@ -490,6 +490,18 @@ pub trait MangleCache: Send + Sync {
     fn update_vars_cache(&self, new_data: &FxHashMap<Id, Atom>);
 
     fn update_props_cache(&self, new_data: &FxHashMap<Atom, Atom>);
+
+    /// Calls `op` with the mangled property names, which `op` may extend.
+    ///
+    /// Implementations should lock the cache while `op` runs, so names
+    /// generated for files minified in parallel do not collide. The default
+    /// implementation does not.
+    fn update_props_cache_with(&self, op: &mut dyn FnMut(&mut FxHashMap<Atom, Atom>)) {
+        let mut props = FxHashMap::default();
+        self.props_cache(&mut |cached| props.clone_from(cached));
+        op(&mut props);
+        self.update_props_cache(&props);
+    }
 }
 
 #[derive(Debug, Default)]
@@ -498,6 +510,80 @@ pub struct SimpleMangleCache {
     pub props: RwLock<FxHashMap<Atom, Atom>>,
 }
 
+impl SimpleMangleCache {
+    /// Creates a cache containing mangled property names of `name_cache`.
+    pub fn from_name_cache(name_cache: &NameCache) -> Self {
+        let props = name_cache
+            .props
+            .props
+            .iter()
+            .filter_map(|(k, v)| Some((k.strip_prefix('$')?.into(), v.clone())))
+            .collect();
+
+        Self {
+            vars: Default::default(),
+            props: RwLock::new(props),
+        }
+    }
+
+    /// Returns mangled property names in this cache.
+    ///
+    /// Mangled names of variables are not included, as they are bound to
+    /// [SyntaxContext](swc_common::SyntaxContext)s which differ between runs.
+    pub fn to_name_cache(&self) -> NameCache {
+        let props = self
+            .props
+            .read()
+            .iter()
+            .map(|(k, v)| (format!("${k}"), v.clone()))
+            .collect();
+
+        NameCache {
+            props: NameCacheProps { props },
+        }
+    }
+
+    /// Loads a cache from a JSON file in the format of [NameCache].
+    ///
+    /// Returns an empty cache if the file does not exist.
+    pub fn load(path: &Path) -> io::Result<Self> {
+        let content = match fs::read_to_string(path) {
+            Ok(content) => content,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(Default::default()),
+            Err(err) => return Err(err),
+        };
+
+        let name_cache: NameCache = serde_json::from_str(&content)?;
+
+        Ok(Self::from_name_cache(&name_cache))
+    }
+
+    /// Saves the cache to a JSON file in the format of [NameCache].
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        let content = serde_json::to_string_pretty(&self.to_name_cache())?;
+
+        fs::write(path, content)
+    }
+}
+
+/// Serializable mangled names, compatible with `nameCache` of terser.
+///
+/// ```json
+/// { "props": { "props": { "$foo": "a" } } }
+/// ```
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct NameCache {
+    #[serde(default)]
+    pub props: NameCacheProps,
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct NameCacheProps {
+    /// Original property names prefixed with `$` to mangled ones.
+    #[serde(default)]
+    pub props: BTreeMap<String, Atom>,
+}
+
 impl MangleCache for SimpleMangleCache {
     fn vars_cache(&self, op: &mut dyn FnMut(&FxHashMap<Id, Atom>)) {
         let vars = self.vars.read();
@@ -518,4 +604,9 @@ impl MangleCache for SimpleMangleCache {
         let mut props = self.props.write();
         props.extend(new_data.iter().map(|(k, v)| (k.clone(), v.clone())));
     }
+
+    fn update_props_cache_with(&self, op: &mut dyn FnMut(&mut FxHashMap<Atom, Atom>)) {
+        let mut props = self.props.write();
+        op(&mut props);
+    }
 }
@@ -1,4 +1,4 @@
-use std::collections::HashSet;
+use std::{collections::HashSet, sync::Arc};
 
 use once_cell::sync::Lazy;
 use rustc_hash::{FxHashMap, FxHashSet};
@@ -10,7 +10,7 @@ use swc_ecma_ast::{
 use swc_ecma_visit::{noop_visit_mut_type, VisitMut, VisitMutWith};
 
 use crate::{
-    option::ManglePropertiesOptions,
+    option::{MangleCache, ManglePropertiesOptions},
     program_data::{analyze, ProgramData},
     util::base54::Base54Chars,
 };
@@ -41,6 +41,10 @@ struct ManglePropertiesState {
     // Cache of already mangled names
     cache: FxHashMap<Atom, Atom>,
 
+    // Mangled names loaded from the mangle cache, which should not be reused
+    // for other properties
+    cached_names: FxHashSet<Atom>,
+
     // Numbers to pass to base54()
     n: usize,
 }
@@ -85,7 +89,13 @@ impl ManglePropertiesState {
             if let Some(cached) = self.cache.get(name) {
                 Some(cached.clone())
             } else {
-                let mangled_name = self.chars.encode(&mut self.n, true);
+                let mangled_name = loop {
+                    let mangled_name = self.chars.encode(&mut self.n, true);
+
+                    if !self.cached_names.contains(&mangled_name) {
+                        break mangled_name;
+                    }
+                };
 
                 self.cache.insert(name.clone(), mangled_name.clone());
                 Some(mangled_name)
@@ -100,6 +110,7 @@ pub(crate) fn mangle_properties(
     m: &mut Program,
     options: ManglePropertiesOptions,
     chars: Base54Chars,
+    mangle_name_cache: Option<Arc<dyn MangleCache>>,
 ) {
     let mut state = ManglePropertiesState {
         options,
@@ -107,16 +118,40 @@ pub(crate) fn mangle_properties(
         names_to_mangle: Default::default(),
         unmangleable: Default::default(),
         cache: Default::default(),
+        cached_names: Default::default(),
         n: 0,
     };
 
+    if let Some(mangle_cache) = &mangle_name_cache {
+        mangle_cache.props_cache(&mut |props| {
+            state
+                .cache
+                .extend(props.iter().map(|(k, v)| (k.clone(), v.clone())));
+        });
+        state.cached_names = state.cache.values().cloned().collect();
+    }
+
     let data = analyze(&*m, None);
     m.visit_mut_with(&mut PropertyCollector {
         state: &mut state,
         data,
     });
 
-    m.visit_mut_with(&mut Mangler { state: &mut state });
+    match &mangle_name_cache {
+        // Names are generated while the cache is locked, and checked against
+        // names added by other files since it was read above.
+        Some(mangle_cache) => mangle_cache.update_props_cache_with(&mut |props| {
+            state
+                .cache
+                .extend(props.iter().map(|(k, v)| (k.clone(), v.clone())));
+            state.cached_names = state.cache.values().cloned().collect();
+
+            m.visit_mut_with(&mut Mangler { state: &mut state });
+
+            props.extend(state.cache.iter().map(|(k, v)| (k.clone(), v.clone())));
+        }),
+        None => m.visit_mut_with(&mut Mangler { state: &mut state }),
+    }
 }
 
 // Step 1 -- collect candidates to mangle
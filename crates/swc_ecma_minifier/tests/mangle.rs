@@ -3,6 +3,7 @@
 use std::{
     path::{Path, PathBuf},
     process::{Command, Stdio},
+    sync::Arc,
 };
 
 use swc_common::{errors::Handler, sync::Lrc, FileName, Mark, SourceFile, SourceMap};
@@ -13,7 +14,10 @@ use swc_ecma_codegen::{
 };
 use swc_ecma_minifier::{
     optimize,
-    option::{ExtraOptions, MangleOptions, ManglePropertiesOptions, MinifyOptions},
+    option::{
+        ExtraOptions, MangleCache, MangleOptions, ManglePropertiesOptions, MinifyOptions,
+        NameCache, SimpleMangleCache,
+    },
 };
 use swc_ecma_parser::parse_file_as_program;
 use swc_ecma_transforms_base::{fixer::paren_remover, resolver};
@@ -184,6 +188,16 @@ fn fixture(input: PathBuf) {
 
 #[track_caller]
 fn assert_mangled(src: &str, expected: &str, opts: MangleOptions) {
+    assert_mangled_with_cache(src, expected, opts, None)
+}
+
+#[track_caller]
+fn assert_mangled_with_cache(
+    src: &str,
+    expected: &str,
+    opts: MangleOptions,
+    mangle_name_cache: Option<Arc<dyn MangleCache>>,
+) {
     testing::run_test2(false, |cm, handler| {
         let fm = cm.new_source_file(FileName::Anon.into(), src.into());
 
@@ -205,7 +219,7 @@ fn assert_mangled(src: &str, expected: &str, opts: MangleOptions) {
             &ExtraOptions {
                 unresolved_mark,
                 top_level_mark,
-                mangle_name_cache: None,
+                mangle_name_cache,
             },
         );
 
@@ -359,3 +373,58 @@ class s {
         },
     )
 }
+
+#[test]
+fn props_cache_across_files() {
+    let cache = Arc::new(SimpleMangleCache::default());
+    let opts = MangleOptions {
+        props: Some(ManglePropertiesOptions::default()),
+        ..Default::default()
+    };
+
+    assert_mangled_with_cache(
+        "globalThis.api = { shared: 1, first: 2 };",
+        "globalThis.api = {
+    a: 1,
+    i: 2
+};",
+        opts.clone(),
+        Some(cache.clone()),
+    );
+
+    // `shared` keeps its name and `second` does not reuse a name in the cache.
+    assert_mangled_with_cache(
+        "globalThis.api = { second: 1, shared: 2 };",
+        "globalThis.api = {
+    d: 1,
+    a: 2
+};",
+        opts,
+        Some(cache.clone()),
+    );
+}
+
+#[test]
+fn props_cache_from_name_cache() {
+    let name_cache: NameCache =
+        serde_json::from_str(r#"{ "props": { "props": { "$shared": "x" } } }"#).unwrap();
+    let cache = Arc::new(SimpleMangleCache::from_name_cache(&name_cache));
+
+    assert_mangled_with_cache(
+        "globalThis.api = { shared: 1, other: 2 };",
+        "globalThis.api = {
+    x: 1,
+    a: 2
+};",
+        MangleOptions {
+            props: Some(ManglePropertiesOptions::default()),
+            ..Default::default()
+        },
+        Some(cache.clone()),
+    );
+
+    assert_eq!(
+        serde_json::to_value(cache.to_name_cache()).unwrap(),
+        serde_json::json!({ "props": { "props": { "$other": "a", "$shared": "x" } } })
+    );
+}
@@ -410,6 +410,7 @@ define_helpers!(Helpers {
     ts_generator: (),
     ts_metadata: (),
     ts_param: (),
+    ts_rewrite_relative_import_extension: (),
     ts_values: (),
     ts_add_disposable_resource: (),
     ts_dispose_resources: (),
@@ -7,7 +7,10 @@ use serde::{Deserialize, Serialize};
 use swc_common::{Span, SyntaxContext};
 use util::Config;
 
-pub use self::{amd::amd, common_js::common_js, system_js::system_js, umd::umd};
+pub use self::{
+    amd::amd, common_js::common_js, rewrite_import_extensions::rewrite_import_extensions,
+    system_js::system_js, umd::umd,
+};
 
 #[macro_use]
 pub mod util;
@@ -17,6 +20,7 @@ pub mod import_analysis;
 pub(crate) mod module_decl_strip;
 pub(crate) mod module_ref_rewriter;
 pub mod path;
+pub mod rewrite_import_extensions;
 pub mod rewriter;
 pub mod system_js;
 mod top_level_this;
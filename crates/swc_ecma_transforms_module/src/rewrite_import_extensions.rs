@@ -0,0 +1,157 @@
+use swc_atoms::Atom;
+use swc_common::{util::take::Take, Mark, SyntaxContext};
+use swc_ecma_ast::*;
+use swc_ecma_transforms_base::helper;
+use swc_ecma_utils::ExprFactory;
+use swc_ecma_visit::{noop_visit_mut_type, visit_mut_pass, VisitMut, VisitMutWith};
+
+/// Rewrites extensions of TypeScript files in relative module specifiers,
+/// like `rewriteRelativeImportExtensions` of TypeScript.
+///
+/// `.ts` and `.tsx` are rewritten to `out_file_extension`, `.mts` to `.mjs`
+/// and `.cts` to `.cjs`.
+///
+/// This does not depend on the import resolver, so it works for sources which
+/// do not exist on disk.
+///
+/// Specifiers of imports, exports, `import x = require()`, `require()` and
+/// `import()` are rewritten. Specifiers of `import()` which are not string
+/// literals are rewritten at runtime by the helper of TypeScript, which always
+/// uses `.js` for `.ts` and `.tsx`. Type-only imports and exports are not
+/// touched, as they are removed from the output.
+pub fn rewrite_import_extensions(unresolved_mark: Mark, out_file_extension: &str) -> impl Pass {
+    visit_mut_pass(RewriteImportExtensions {
+        unresolved_ctxt: SyntaxContext::empty().apply_mark(unresolved_mark),
+        out_file_extension: out_file_extension.into(),
+    })
+}
+
+struct RewriteImportExtensions {
+    unresolved_ctxt: SyntaxContext,
+    out_file_extension: Atom,
+}
+
+impl VisitMut for RewriteImportExtensions {
+    noop_visit_mut_type!(fail);
+
+    fn visit_mut_call_expr(&mut self, n: &mut CallExpr) {
+        n.visit_mut_children_with(self);
+
+        let is_import = match &n.callee {
+            Callee::Import(..) => true,
+            Callee::Expr(callee) => match &**callee {
+                Expr::Ident(Ident { sym, ctxt, .. }) => {
+                    if &**sym != "require" || *ctxt != self.unresolved_ctxt {
+                        return;
+                    }
+
+                    false
+                }
+                _ => return,
+            },
+            _ => return,
+        };
+
+        let Some(ExprOrSpread { spread: None, expr }) = n.args.first_mut() else {
+            return;
+        };
+
+        match &mut **expr {
+            Expr::Lit(Lit::Str(src)) => self.rewrite_src(src),
+            _ if is_import => {
+                *expr = CallExpr {
+                    callee: helper!(ts, ts_rewrite_relative_import_extension),
+                    args: vec![expr.take().as_arg()],
+                    ..Default::default()
+                }
+                .into();
+            }
+            _ => {}
+        }
+    }
+
+    fn visit_mut_export_all(&mut self, n: &mut ExportAll) {
+        if !n.type_only {
+            self.rewrite_src(&mut n.src);
+        }
+    }
+
+    fn visit_mut_import_decl(&mut self, n: &mut ImportDecl) {
+        if !n.type_only {
+            self.rewrite_src(&mut n.src);
+        }
+    }
+
+    fn visit_mut_named_export(&mut self, n: &mut NamedExport) {
+        if n.type_only {
+            return;
+        }
+
+        if let Some(src) = &mut n.src {
+            self.rewrite_src(src);
+        }
+    }
+
+    fn visit_mut_ts_import_equals_decl(&mut self, n: &mut TsImportEqualsDecl) {
+        if n.is_type_only {
+            return;
+        }
+
+        if let TsModuleRef::TsExternalModuleRef(module_ref) = &mut n.module_ref {
+            self.rewrite_src(&mut module_ref.expr);
+        }
+    }
+}
+
+impl RewriteImportExtensions {
+    fn rewrite_src(&self, src: &mut Str) {
+        if let Some(value) = rewrite_extension(&src.value, &self.out_file_extension) {
+            src.value = value;
+            src.raw = None;
+        }
+    }
+}
+
+/// Returns the specifier of the emitted file, if `src` is a relative specifier
+/// of a TypeScript file.
+///
+/// This matches `__rewriteRelativeImportExtension` of `tslib`, and the
+/// `ts_rewrite_relative_import_extension` helper, if `out_file_extension` is
+/// `js`.
+fn rewrite_extension(src: &str, out_file_extension: &str) -> Option<Atom> {
+    if !src.starts_with("./") && !src.starts_with("../") {
+        return None;
+    }
+
+    // ASCII lowercasing keeps the byte offsets.
+    let lower = src.to_ascii_lowercase();
+
+    if lower.ends_with(".tsx") {
+        return Some(format!("{}.{}", &src[..src.len() - 4], out_file_extension).into());
+    }
+
+    let (stem_len, ext) = if lower.ends_with(".mts") {
+        (src.len() - 4, "mjs")
+    } else if lower.ends_with(".cts") {
+        (src.len() - 4, "cjs")
+    } else if lower.ends_with(".ts") {
+        (src.len() - 3, out_file_extension)
+    } else {
+        return None;
+    };
+
+    // Declaration files, like `foo.d.ts` and `foo.d.css.ts`, are not emitted.
+    let stem = &lower[..stem_len];
+    if stem.ends_with(".d") {
+        return None;
+    }
+    if !lower.ends_with(".mts") && !lower.ends_with(".cts") {
+        if let Some((rest, file_ext)) = stem.rsplit_once('.') {
+            if !file_ext.is_empty() && !file_ext.contains('/') && rest.ends_with(".d") {
+                return None;
+            }
+        }
+    }
+
+    Some(format!("{}.{}", &src[..stem_len], ext).into())
+}
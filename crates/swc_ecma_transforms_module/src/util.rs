@@ -49,6 +49,15 @@ pub struct Config {
 
     #[serde(default = "Config::default_js_ext")]
     pub out_file_extension: String,
+
+    /// Rewrites `.ts`, `.tsx`, `.mts` and `.cts` extensions in relative
+    /// specifiers to the extensions of the emitted files.
+    ///
+    /// Unlike `resolve_fully`, this does not require the files to exist. The
+    /// rewrite is done by [crate::rewrite_import_extensions], which should be
+    /// applied before helpers are injected.
+    #[serde(default)]
+    pub rewrite_relative_import_extensions: bool,
 }
 
 impl Config {
@@ -71,6 +80,7 @@ impl Default for Config {
             preserve_import_meta: false,
             resolve_fully: false,
             out_file_extension: "js".to_string(),
+            rewrite_relative_import_extensions: false,
         }
     }
 }
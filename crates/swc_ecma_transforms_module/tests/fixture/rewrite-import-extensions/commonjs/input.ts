@@ -0,0 +1,9 @@
+import a from "./a.ts";
+import equals = require("./equals.ts");
+export { b } from "./b.mts";
+
+export function load(name: string) {
+    return import(name);
+}
+
+console.log(a, equals);
@@ -0,0 +1,25 @@
+"use strict";
+Object.defineProperty(exports, "__esModule", {
+    value: true
+});
+function _export(target, all) {
+    for(var name in all)Object.defineProperty(target, name, {
+        enumerable: true,
+        get: Object.getOwnPropertyDescriptor(all, name).get
+    });
+}
+_export(exports, {
+    get b () {
+        return _b.b;
+    },
+    get load () {
+        return load;
+    }
+});
+const _a = /*#__PURE__*/ _interop_require_default(require("./a.js"));
+const _b = require("./b.mjs");
+const equals = require("./equals.js");
+function load(name) {
+    return Promise.resolve(_ts_rewrite_relative_import_extension(name)).then((p)=>/*#__PURE__*/ _interop_require_wildcard(require(p)));
+}
+console.log(_a.default, equals);
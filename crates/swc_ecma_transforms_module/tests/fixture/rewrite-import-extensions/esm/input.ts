@@ -0,0 +1,27 @@
+import a from "./a.ts";
+import b from "../b.tsx";
+import c from "./c.mts";
+import d from "./d.CTS";
+import "./side-effect.ts";
+import type { T } from "./types.ts";
+import { type U } from "./mixed.ts";
+import pkg from "pkg/index.ts";
+import json from "./data.json";
+import dts from "./decl.d.ts";
+import css from "./style.d.css.ts";
+import esmCss from "./style.d.css.mts";
+import dotted from "./file.test.ts";
+
+export * from "./all.ts";
+export * as ns from "./ns.mts";
+export { e } from "./e.ts";
+export type { V } from "./v.ts";
+
+console.log(a, b, c, d, pkg, json, dts, css, esmCss, dotted);
+
+export async function load(name: string) {
+    await import("./lazy.ts");
+    await import(`./${name}.ts`);
+    await import(name);
+    return require("./required.cts");
+}
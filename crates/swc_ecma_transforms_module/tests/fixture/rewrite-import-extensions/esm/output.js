@@ -0,0 +1,21 @@
+import a from "./a.js";
+import b from "../b.js";
+import c from "./c.mjs";
+import d from "./d.cjs";
+import "./side-effect.js";
+import pkg from "pkg/index.ts";
+import json from "./data.json";
+import dts from "./decl.d.ts";
+import css from "./style.d.css.ts";
+import esmCss from "./style.d.css.mjs";
+import dotted from "./file.test.js";
+export * from "./all.js";
+export * as ns from "./ns.mjs";
+export { e } from "./e.js";
+console.log(a, b, c, d, pkg, json, dts, css, esmCss, dotted);
+export async function load(name) {
+    await import("./lazy.js");
+    await import(_ts_rewrite_relative_import_extension(`./${name}.ts`));
+    await import(_ts_rewrite_relative_import_extension(name));
+    return require("./required.cjs");
+}
@@ -0,0 +1,15 @@
+import a from "./a.ts";
+import b from "../b.tsx";
+import c from "./c.mts";
+import d from "./d.cts";
+import dts from "./decl.d.ts";
+import css from "./style.d.css.ts";
+import dotted from "./file.test.ts";
+export { e } from "./e.ts";
+
+console.log(a, b, c, d, dts, css, dotted);
+
+export async function load() {
+    await import("./lazy.ts");
+    return require("./required.ts");
+}
@@ -0,0 +1,13 @@
+import a from "./a.mjs";
+import b from "../b.mjs";
+import c from "./c.mjs";
+import d from "./d.cjs";
+import dts from "./decl.d.ts";
+import css from "./style.d.css.ts";
+import dotted from "./file.test.mjs";
+export { e } from "./e.mjs";
+console.log(a, b, c, d, dts, css, dotted);
+export async function load() {
+    await import("./lazy.mjs");
+    return require("./required.mjs");
+}
@@ -0,0 +1,50 @@
+use std::path::PathBuf;
+
+use swc_common::Mark;
+use swc_ecma_ast::Pass;
+use swc_ecma_parser::{Syntax, TsSyntax};
+use swc_ecma_transforms_base::{feature::FeatureFlag, resolver};
+use swc_ecma_transforms_module::{common_js, rewrite_import_extensions};
+use swc_ecma_transforms_testing::{test_fixture, FixtureTestConfig};
+use swc_ecma_transforms_typescript::typescript;
+
+fn tr(commonjs: bool, out_file_extension: &str) -> impl Pass {
+    let unresolved_mark = Mark::new();
+    let top_level_mark = Mark::new();
+
+    (
+        resolver(unresolved_mark, top_level_mark, true),
+        typescript::typescript(Default::default(), unresolved_mark, top_level_mark),
+        rewrite_import_extensions(unresolved_mark, out_file_extension),
+        commonjs.then(|| {
+            common_js(
+                Default::default(),
+                unresolved_mark,
+                Default::default(),
+                FeatureFlag::all(),
+            )
+        }),
+    )
+}
+
+#[testing::fixture("tests/fixture/rewrite-import-extensions/**/input.ts")]
+fn fixture(input: PathBuf) {
+    let dir = input.parent().unwrap();
+    let commonjs = dir.ends_with("commonjs");
+    let out_file_extension = if dir.ends_with("out-file-extension") {
+        "mjs"
+    } else {
+        "js"
+    };
+
+    test_fixture(
+        Syntax::Typescript(TsSyntax::default()),
+        &|_| tr(commonjs, out_file_extension),
+        &input,
+        &dir.join("output.js"),
+        FixtureTestConfig {
+            module: Some(true),
+            ..Default::default()
+        },
+    );
+}
@@ -1,6 +1,8 @@
 use serde::{Deserialize, Serialize};
 use swc_common::sync::Lrc;
 
+use crate::TsEnumTable;
+
 #[derive(Debug, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct Config {
@@ -34,6 +36,18 @@ pub struct Config {
     /// Defaults to false.
     #[serde(default)]
     pub ts_enum_is_mutable: bool,
+
+    /// Enums exported from the modules imported by the file being
+    /// transformed. If set, members of enums imported from them are inlined,
+    /// and imports which become unused are removed.
+    ///
+    /// As the table is keyed by import specifiers, it must be built for the
+    /// file this config is used for. See [TsEnumTable].
+    ///
+    /// Members of regular enums are not inlined if
+    /// [Config::ts_enum_is_mutable] is set.
+    #[serde(skip)]
+    pub ts_enum_table: Option<TsEnumTable>,
 }
 
 #[derive(Debug, Default, Serialize, Deserialize)]
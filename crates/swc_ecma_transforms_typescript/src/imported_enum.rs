@@ -0,0 +1,363 @@
+use rustc_hash::FxHashMap;
+use swc_atoms::Atom;
+use swc_common::SyntaxContext;
+use swc_ecma_ast::*;
+use swc_ecma_visit::{noop_visit_mut_type, VisitMut, VisitMutWith};
+
+use crate::{
+    transform::get_member_key,
+    ts_enum::{EnumValueComputer, TsEnumRecord, TsEnumRecordKey, TsEnumRecordValue},
+};
+
+/// Values of enum members exported from the modules imported by a file, keyed
+/// by the module specifiers as written in that file.
+///
+/// This is used to inline enum members imported from other files, like `tsc`
+/// does. As the transform works on a single file, the caller should resolve
+/// and parse imported modules, e.g. with `swc_ecma_loader`, and collect their
+/// enums with [ExportedEnums::collect].
+///
+/// A table is only valid for the file it's built for: the same specifier, like
+/// `./enums`, may refer to different modules in other files. Build a table for
+/// each file instead of sharing one.
+#[derive(Debug, Default, Clone)]
+pub struct TsEnumTable {
+    modules: FxHashMap<Atom, ExportedEnums>,
+}
+
+impl TsEnumTable {
+    /// Adds enums exported from the module imported as `specifier` by the file
+    /// the table is built for.
+    pub fn insert(&mut self, specifier: Atom, enums: ExportedEnums) {
+        self.modules.entry(specifier).or_default().extend(enums);
+    }
+
+    fn get(&self, specifier: &Atom, path: &[Atom]) -> Option<&ExportedEnum> {
+        self.modules.get(specifier)?.enums.get(path)
+    }
+}
+
+/// Enums exported from a module.
+#[derive(Debug, Default, Clone)]
+pub struct ExportedEnums {
+    /// Keyed by the exported names of the enums and the namespaces containing
+    /// them, like `["Ns", "Direction"]` for `Ns.Direction`.
+    enums: FxHashMap<Vec<Atom>, ExportedEnum>,
+}
+
+#[derive(Debug, Default, Clone)]
+struct ExportedEnum {
+    is_const: bool,
+    members: FxHashMap<Atom, TsEnumValue>,
+}
+
+/// A constant value of an enum member.
+#[derive(Debug, Clone, PartialEq)]
+pub enum TsEnumValue {
+    String(Atom),
+    Number(f64),
+}
+
+impl ExportedEnums {
+    /// Collects enums exported from `module`, including enums declared with
+    /// `declare`, so `.d.ts` files can be used.
+    ///
+    /// Enums exported from namespaces declared with `export namespace` are
+    /// collected too, and are inlined if accessed like `Ns.Direction.Up`.
+    ///
+    /// Members whose values can't be computed at compile time are ignored.
+    /// Re-exports from other modules, like `export * from "./foo"`, are not
+    /// followed.
+    pub fn collect(module: &Module) -> Self {
+        let mut enums = Self::default();
+
+        enums.collect_items(&module.body, &[]);
+
+        enums
+    }
+
+    /// Collects enums exported from `items`, which are the body of a module or
+    /// of the namespace at `path`.
+    fn collect_items(&mut self, items: &[ModuleItem], path: &[Atom]) {
+        let mut record = TsEnumRecord::default();
+        let mut const_enums = FxHashMap::default();
+        let mut exports = Vec::new();
+
+        for item in items {
+            let (decl, is_export) = match item {
+                ModuleItem::Stmt(Stmt::Decl(Decl::TsEnum(decl))) => (decl, false),
+                ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(ExportDecl {
+                    decl: Decl::TsEnum(decl),
+                    ..
+                })) => (decl, true),
+                ModuleItem::ModuleDecl(ModuleDecl::ExportNamed(NamedExport {
+                    specifiers,
+                    src: None,
+                    type_only: false,
+                    ..
+                })) => {
+                    for specifier in specifiers {
+                        if let ExportSpecifier::Named(ExportNamedSpecifier {
+                            orig: ModuleExportName::Ident(orig),
+                            exported,
+                            is_type_only: false,
+                            ..
+                        }) = specifier
+                        {
+                            let exported = match exported {
+                                Some(ModuleExportName::Ident(exported)) => exported.sym.clone(),
+                                Some(ModuleExportName::Str(exported)) => exported.value.clone(),
+                                None => orig.sym.clone(),
+                            };
+
+                            exports.push((exported, orig.to_id()));
+                        }
+                    }
+                    continue;
+                }
+                ModuleItem::ModuleDecl(ModuleDecl::ExportDefaultExpr(ExportDefaultExpr {
+                    expr,
+                    ..
+                })) => {
+                    if let Expr::Ident(ident) = &**expr {
+                        exports.push(("default".into(), ident.to_id()));
+                    }
+                    continue;
+                }
+                ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(ExportDecl {
+                    decl: Decl::TsModule(decl),
+                    ..
+                })) => {
+                    if let (TsModuleName::Ident(id), Some(body)) = (&decl.id, &decl.body) {
+                        self.collect_namespace(body, path, &id.sym);
+                    }
+                    continue;
+                }
+                _ => continue,
+            };
+
+            let enum_id = decl.id.to_id();
+            let mut default_init: TsEnumRecordValue = 0.0.into();
+
+            for member in &decl.members {
+                let value = member
+                    .init
+                    .clone()
+                    .map(|expr| {
+                        EnumValueComputer {
+                            enum_id: &enum_id,
+                            unresolved_ctxt: SyntaxContext::empty(),
+                            record: &record,
+                        }
+                        .compute(expr)
+                    })
+                    .filter(TsEnumRecordValue::has_value)
+                    .unwrap_or_else(|| default_init.clone());
+
+                default_init = value.inc();
+
+                record.insert(
+                    TsEnumRecordKey {
+                        enum_id: enum_id.clone(),
+                        member_name: member.id.as_ref().clone(),
+                    },
+                    value,
+                );
+            }
+
+            *const_enums.entry(enum_id.clone()).or_default() |= decl.is_const;
+
+            if is_export {
+                exports.push((decl.id.sym.clone(), enum_id));
+            }
+        }
+
+        for (key, value) in record {
+            let value = match value {
+                TsEnumRecordValue::String(value) => TsEnumValue::String(value),
+                TsEnumRecordValue::Number(value) => TsEnumValue::Number(*value),
+                _ => continue,
+            };
+
+            for (exported, _) in exports.iter().filter(|(_, id)| *id == key.enum_id) {
+                let mut exported_path = path.to_vec();
+                exported_path.push(exported.clone());
+
+                let exported = self.enums.entry(exported_path).or_default();
+
+                exported.is_const = const_enums[&key.enum_id];
+                exported
+                    .members
+                    .insert(key.member_name.clone(), value.clone());
+            }
+        }
+    }
+
+    /// Collects enums exported from the namespace `name` in `path`, including
+    /// nested namespaces declared like `namespace A.B {}`.
+    fn collect_namespace(&mut self, body: &TsNamespaceBody, path: &[Atom], name: &Atom) {
+        let mut path = path.to_vec();
+        path.push(name.clone());
+
+        match body {
+            TsNamespaceBody::TsModuleBlock(block) => self.collect_items(&block.body, &path),
+            TsNamespaceBody::TsNamespaceDecl(decl) => {
+                self.collect_namespace(&decl.body, &path, &decl.id.sym)
+            }
+        }
+    }
+
+    fn extend(&mut self, other: ExportedEnums) {
+        self.enums.extend(other.enums);
+    }
+}
+
+/// Inlines members of enums imported from modules in [TsEnumTable].
+///
+/// Members of regular enums are not inlined if `ts_enum_is_mutable` is set.
+pub(crate) struct ImportedEnumInliner<'a> {
+    pub table: &'a TsEnumTable,
+    pub ts_enum_is_mutable: bool,
+
+    /// Local names of named and default imports, with the specifiers and
+    /// the imported names.
+    imports: FxHashMap<Id, (Atom, Atom)>,
+    /// Local names of namespace imports.
+    namespace_imports: FxHashMap<Id, Atom>,
+}
+
+impl<'a> ImportedEnumInliner<'a> {
+    pub fn new(table: &'a TsEnumTable, ts_enum_is_mutable: bool) -> Self {
+        Self {
+            table,
+            ts_enum_is_mutable,
+            imports: Default::default(),
+            namespace_imports: Default::default(),
+        }
+    }
+
+    fn get_enum(&self, obj: &Expr) -> Option<&'a ExportedEnum> {
+        // Names of members accessed from the imported binding, in reverse order.
+        let mut path = Vec::new();
+        let mut obj = obj;
+
+        while let Expr::Member(MemberExpr {
+            obj: member_obj,
+            prop,
+            ..
+        }) = obj
+        {
+            path.push(get_member_key(prop)?);
+            obj = member_obj;
+        }
+
+        let Expr::Ident(ident) = obj else {
+            return None;
+        };
+
+        let specifier = if let Some((specifier, export_name)) = self.imports.get(&ident.to_id()) {
+            path.push(export_name.clone());
+            specifier
+        } else {
+            self.namespace_imports.get(&ident.to_id())?
+        };
+
+        path.reverse();
+
+        let exported = self.table.get(specifier, &path)?;
+
+        if self.ts_enum_is_mutable && !exported.is_const {
+            return None;
+        }
+
+        Some(exported)
+    }
+}
+
+impl VisitMut for ImportedEnumInliner<'_> {
+    noop_visit_mut_type!();
+
+    fn visit_mut_module(&mut self, n: &mut Module) {
+        for item in &n.body {
+            let ModuleItem::ModuleDecl(ModuleDecl::Import(import)) = item else {
+                continue;
+            };
+
+            for specifier in &import.specifiers {
+                match specifier {
+                    ImportSpecifier::Named(ImportNamedSpecifier {
+                        local, imported, ..
+                    }) => {
+                        let imported = match imported {
+                            Some(ModuleExportName::Ident(imported)) => imported.sym.clone(),
+                            Some(ModuleExportName::Str(imported)) => imported.value.clone(),
+                            None => local.sym.clone(),
+                        };
+
+                        self.imports
+                            .insert(local.to_id(), (import.src.value.clone(), imported));
+                    }
+                    ImportSpecifier::Default(ImportDefaultSpecifier { local, .. }) => {
+                        self.imports
+                            .insert(local.to_id(), (import.src.value.clone(), "default".into()));
+                    }
+                    ImportSpecifier::Namespace(ImportStarAsSpecifier { local, .. }) => {
+                        self.namespace_imports
+                            .insert(local.to_id(), import.src.value.clone());
+                    }
+                }
+            }
+        }
+
+        if self.imports.is_empty() && self.namespace_imports.is_empty() {
+            return;
+        }
+
+        n.visit_mut_children_with(self);
+    }
+
+    fn visit_mut_script(&mut self, _: &mut Script) {}
+
+    fn visit_mut_expr(&mut self, n: &mut Expr) {
+        n.visit_mut_children_with(self);
+
+        let Expr::Member(MemberExpr { obj, prop, .. }) = n else {
+            return;
+        };
+
+        let Some(exported) = self.get_enum(obj) else {
+            return;
+        };
+
+        let Some(value) = get_member_key(prop).and_then(|name| exported.members.get(&name)) else {
+            return;
+        };
+
+        *n = match value {
+            TsEnumValue::String(value) => TsEnumRecordValue::String(value.clone()),
+            TsEnumValue::Number(value) => TsEnumRecordValue::Number((*value).into()),
+        }
+        .into();
+    }
+
+    fn visit_mut_simple_assign_target(&mut self, n: &mut SimpleAssignTarget) {
+        // Members of enums should not be replaced on the left hand side.
+        if let SimpleAssignTarget::Member(MemberExpr { obj, prop, .. }) = n {
+            obj.visit_mut_children_with(self);
+            prop.visit_mut_with(self);
+            return;
+        }
+
+        n.visit_mut_children_with(self);
+    }
+
+    fn visit_mut_update_expr(&mut self, n: &mut UpdateExpr) {
+        if let Expr::Member(MemberExpr { obj, prop, .. }) = &mut *n.arg {
+            obj.visit_mut_children_with(self);
+            prop.visit_mut_with(self);
+            return;
+        }
+
+        n.visit_mut_children_with(self);
+    }
+}
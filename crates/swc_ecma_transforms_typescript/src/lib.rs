@@ -2,8 +2,13 @@
 #![allow(clippy::vec_box)]
 #![allow(clippy::mutable_key_type)]
 
-pub use self::{strip_type::*, typescript::*};
+pub use self::{
+    imported_enum::{ExportedEnums, TsEnumTable, TsEnumValue},
+    strip_type::*,
+    typescript::*,
+};
 mod config;
+mod imported_enum;
 mod macros;
 mod strip_import_export;
 mod strip_type;
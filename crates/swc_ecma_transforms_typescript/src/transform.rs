@@ -1523,7 +1523,7 @@ fn get_enum_id(e: &Expr) -> Option<Id> {
     }
 }
 
-fn get_member_key(prop: &MemberProp) -> Option<Atom> {
+pub(crate) fn get_member_key(prop: &MemberProp) -> Option<Atom> {
     match prop {
         MemberProp::Ident(ident) => Some(ident.sym.clone()),
         MemberProp::Computed(ComputedPropName { expr, .. }) => match &**expr {
@@ -7,7 +7,10 @@ use swc_ecma_transforms_react::{parse_expr_for_jsx, JsxDirectives};
 use swc_ecma_visit::{visit_mut_pass, VisitMut, VisitMutWith};
 
 pub use crate::config::*;
-use crate::{strip_import_export::StripImportExport, strip_type::StripType, transform::transform};
+use crate::{
+    imported_enum::ImportedEnumInliner, strip_import_export::StripImportExport,
+    strip_type::StripType, transform::transform,
+};
 
 #[cfg(feature = "concurrent")]
 macro_rules! static_str {
@@ -52,6 +55,13 @@ impl VisitMut for TypeScript {
     fn visit_mut_program(&mut self, n: &mut Program) {
         let was_module = n.as_module().and_then(|m| self.get_last_module_span(m));
 
+        if let Some(table) = &self.config.ts_enum_table {
+            n.visit_mut_with(&mut ImportedEnumInliner::new(
+                table,
+                self.config.ts_enum_is_mutable,
+            ));
+        }
+
         if !self.config.verbatim_module_syntax {
             n.visit_mut_with(&mut StripImportExport {
                 import_not_used_as_values: self.config.import_not_used_as_values,
@@ -0,0 +1,5 @@
+import { Ns } from "./enums";
+import { Other } from "./other";
+console.log(1, 2, "red", "red-blue", 4);
+console.log(2, Other.A);
+console.log(3, "on", Ns.Deep);
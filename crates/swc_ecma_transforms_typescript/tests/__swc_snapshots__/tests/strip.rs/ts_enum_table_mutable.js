@@ -0,0 +1,3 @@
+import { Color } from "./enums";
+console.log(1, Color.Red);
+Color.Red = "green";
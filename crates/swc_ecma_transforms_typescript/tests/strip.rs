@@ -1,8 +1,8 @@
 use std::path::PathBuf;
 
-use swc_common::{comments::NoopComments, pass::Optional, Mark};
-use swc_ecma_ast::Pass;
-use swc_ecma_parser::{Syntax, TsSyntax};
+use swc_common::{comments::NoopComments, pass::Optional, FileName, Mark, SourceMap};
+use swc_ecma_ast::{EsVersion, Pass};
+use swc_ecma_parser::{parse_file_as_module, Syntax, TsSyntax};
 use swc_ecma_transforms_base::resolver;
 use swc_ecma_transforms_compat::{
     class_fields_use_set::class_fields_use_set,
@@ -15,7 +15,8 @@ use swc_ecma_transforms_proposal::decorators;
 use swc_ecma_transforms_react::jsx;
 use swc_ecma_transforms_testing::{test, test_exec, test_fixture, Tester};
 use swc_ecma_transforms_typescript::{
-    tsx, typescript, ImportsNotUsedAsValues, TsImportExportAssignConfig, TsxConfig,
+    tsx, typescript, ExportedEnums, ImportsNotUsedAsValues, TsEnumTable,
+    TsImportExportAssignConfig, TsxConfig,
 };
 
 fn tr(t: &mut Tester) -> impl Pass {
@@ -2833,3 +2834,92 @@ test!(
     ts_jsx_bad_pragma,
     r#"/** @jsx bad-pragma */"#
 );
+
+fn ts_enum_table() -> TsEnumTable {
+    let cm = SourceMap::default();
+    let fm = cm.new_source_file(
+        FileName::Anon.into(),
+        r#"
+        export declare const enum Direction {
+            Up = 1,
+            Down,
+        }
+        export enum Color {
+            Red = "red",
+            Blue = `${Red}-blue`,
+        }
+        enum Internal {
+            A = Direction.Up << 2,
+        }
+        export { Internal as Renamed };
+        export namespace Ns {
+            export const enum Inner {
+                A = 3,
+            }
+            export namespace Deep.Deeper {
+                export enum Flag {
+                    On = "on",
+                }
+            }
+        }
+        "#
+        .into(),
+    );
+    let module = parse_file_as_module(
+        &fm,
+        Syntax::Typescript(TsSyntax::default()),
+        EsVersion::latest(),
+        None,
+        &mut Vec::new(),
+    )
+    .unwrap();
+
+    let mut table = TsEnumTable::default();
+    table.insert("./enums".into(), ExportedEnums::collect(&module));
+
+    table
+}
+
+test!(
+    Syntax::Typescript(TsSyntax::default()),
+    |t| tr_config(
+        t,
+        Some(typescript::Config {
+            ts_enum_table: Some(ts_enum_table()),
+            ..Default::default()
+        }),
+        None,
+        true,
+    ),
+    ts_enum_table_inline,
+    r#"
+    import { Direction, Color, Renamed as R, Ns } from "./enums";
+    import * as enums from "./enums";
+    import { Other } from "./other";
+
+    console.log(Direction.Up, Direction["Down"], Color.Red, Color.Blue, R.A);
+    console.log(enums.Direction.Down, Other.A);
+    console.log(Ns.Inner.A, enums.Ns.Deep.Deeper.Flag.On, Ns.Deep);
+    "#
+);
+
+test!(
+    Syntax::Typescript(TsSyntax::default()),
+    |t| tr_config(
+        t,
+        Some(typescript::Config {
+            ts_enum_is_mutable: true,
+            ts_enum_table: Some(ts_enum_table()),
+            ..Default::default()
+        }),
+        None,
+        true,
+    ),
+    ts_enum_table_mutable,
+    r#"
+    import { Direction, Color } from "./enums";
+
+    console.log(Direction.Up, Color.Red);
+    Color.Red = "green";
+    "#
+);
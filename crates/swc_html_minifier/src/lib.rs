@@ -2973,7 +2973,8 @@ impl MinifyCss for DefaultCssMinifier {
             return None;
         }
 
-        swc_css_minifier::minify(&mut stylesheet, options.minifier);
+        // Keep the original CSS if the targets can't be resolved
+        swc_css_minifier::minify(&mut stylesheet, options.minifier).ok()?;
 
         let mut minified = String::new();
         let wr = swc_css_codegen::writer::basic::BasicCssWriter::new(
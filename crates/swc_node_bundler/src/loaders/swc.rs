@@ -127,14 +127,26 @@ impl SwcLoader {
             }
         }
 
-        let fm = self
-            .compiler
-            .cm
-            .load_file(match name {
-                FileName::Real(v) => v,
-                _ => bail!("swc-loader only accepts path. Got `{}`", name),
-            })
-            .with_context(|| format!("failed to load file `{}`", name))?;
+        let path = match name {
+            FileName::Real(v) => v,
+            _ => bail!("swc-loader only accepts path. Got `{}`", name),
+        };
+
+        let fm = match path {
+            // Files in zip archives of Yarn PnP
+            #[cfg(feature = "pnp")]
+            _ if swc_ecma_loader::resolvers::pnp::is_archive_path(path) => {
+                let src = swc_ecma_loader::resolvers::pnp::read_to_string(path)
+                    .with_context(|| format!("failed to load file `{}`", name))?;
+
+                self.compiler.cm.new_source_file(name.clone().into(), src)
+            }
+            _ => self
+                .compiler
+                .cm
+                .load_file(path)
+                .with_context(|| format!("failed to load file `{}`", name))?,
+        };
 
         if let FileName::Real(path) = name {
             if let Some(ext) = path.extension() {
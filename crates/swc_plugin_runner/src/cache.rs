@@ -49,6 +49,8 @@ pub struct PluginModuleCacheInner {
     // Current it doesn't have any invalidation or expiration logics like lru,
     // having a lot of plugins may create some memory pressure.
     compiled_module_bytes: FxHashMap<String, (wasmer::Store, wasmer::Module)>,
+    // Plugin modules compiled with metering, which are only stored in memory.
+    metered_module_bytes: FxHashMap<String, (wasmer::Store, wasmer::Module)>,
 }
 
 impl PluginModuleCacheInner {
@@ -85,6 +87,12 @@ impl PluginModuleCacheInner {
         self.memory_cache_store.insert(key, value);
     }
 
+    /// Returns the raw plugin module bytes stored in the memory cache, if
+    /// any.
+    pub fn get_raw_bytes(&self, key: &str) -> Option<&[u8]> {
+        self.memory_cache_store.get(key).map(|v| &**v)
+    }
+
     /// Insert already compiled wasmer::Module into cache.
     /// The module stored in this cache will return CompiledPluginModuleBytes,
     /// which costs near-zero time when calling its `compile_module` method as
@@ -182,6 +190,33 @@ impl PluginModuleCacheInner {
         }
         None
     }
+
+    /// Returns a module compiled with metering, which is required to limit
+    /// fuel of the plugin with [crate::limits::PluginLimits].
+    ///
+    /// Unlike [Self::get], the module is compiled from the raw bytes on the
+    /// first call for the key, and it's not stored in the filesystem cache.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn get_metered(&mut self, key: &str) -> Result<Option<Box<dyn PluginModuleBytes>>, Error> {
+        if !self.metered_module_bytes.contains_key(key) {
+            let Some(bytes) = self.memory_cache_store.get(key) else {
+                return Ok(None);
+            };
+
+            let compiled = crate::wasix_runtime::compile_metered_module(bytes)
+                .context("Cannot compile plugin binary with metering")?;
+
+            self.metered_module_bytes.insert(key.to_string(), compiled);
+        }
+
+        let (store, module) = &self.metered_module_bytes[key];
+
+        Ok(Some(Box::new(CompiledPluginModuleBytes::new(
+            key.to_string(),
+            module.clone(),
+            Store::new(store.engine().clone()),
+        ))))
+    }
 }
 
 #[derive(Default)]
@@ -211,6 +246,7 @@ impl PluginModuleCache {
             fs_cache_hash_store: Default::default(),
             memory_cache_store: Default::default(),
             compiled_module_bytes: Default::default(),
+            metered_module_bytes: Default::default(),
         }
     }
 }
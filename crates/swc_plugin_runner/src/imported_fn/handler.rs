@@ -2,7 +2,7 @@ use swc_common::{
     errors::{Diagnostic, HANDLER},
     plugin::serialized::PluginSerializedBytes,
 };
-use swc_transform_common::output::experimental_emit;
+use swc_transform_common::output::{emit_artifact as emit_artifact_to_host, experimental_emit};
 use wasmer::FunctionEnvMut;
 
 use crate::{host_environment::BaseHostEnvironment, memory_interop::copy_bytes_into_host};
@@ -50,3 +50,23 @@ pub fn emit_output(env: FunctionEnvMut<BaseHostEnvironment>, output_ptr: i32, ou
 
     experimental_emit(output.0 .0, output.0 .1);
 }
+
+pub fn emit_artifact(
+    env: FunctionEnvMut<BaseHostEnvironment>,
+    artifact_ptr: i32,
+    artifact_len: i32,
+) {
+    let memory = env
+        .data()
+        .memory
+        .as_ref()
+        .expect("Memory instance should be available, check initialization");
+
+    let artifact_bytes = copy_bytes_into_host(&memory.view(&env), artifact_ptr, artifact_len);
+    let serialized = PluginSerializedBytes::from_slice(&artifact_bytes[..]);
+    let (path, content) = PluginSerializedBytes::deserialize::<(String, Vec<u8>)>(&serialized)
+        .expect("Should able to be deserialized into artifact")
+        .into_inner();
+
+    emit_artifact_to_host(path, content);
+}
@@ -9,6 +9,7 @@ pub mod cache;
 mod host_environment;
 #[cfg(feature = "__rkyv")]
 mod imported_fn;
+pub mod limits;
 #[cfg(feature = "__rkyv")]
 mod memory_interop;
 pub mod plugin_module_bytes;
@@ -0,0 +1,392 @@
+//! Limits on resources a plugin can use while running a transform.
+
+use std::fmt;
+
+/// Limits on resources a plugin can use while running a transform.
+///
+/// A plugin exceeding them fails with [PluginLimitError] instead of running
+/// forever or exhausting the memory of the host.
+///
+/// There is no wall-clock deadline. Fuel only counts instructions executed by
+/// the plugin, so time spent in host functions, like the ones serializing the
+/// program or source map lookups, is not limited.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct PluginLimits {
+    /// The maximum number of Wasm instructions the transform can execute.
+    ///
+    /// This requires a module compiled with metering, like the ones returned
+    /// by [crate::cache::PluginModuleCacheInner::get_metered].
+    pub fuel: Option<u64>,
+
+    /// The maximum size of the linear memory of the plugin, in bytes.
+    pub max_memory: Option<u64>,
+}
+
+/// A limit of [PluginLimits] exceeded by a plugin.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PluginLimit {
+    Fuel(u64),
+    Memory(u64),
+}
+
+/// An error returned by [crate::TransformExecutor::transform] if a plugin
+/// exceeds one of its [PluginLimits].
+///
+/// Use [anyhow::Error::downcast_ref] to get it from the returned error.
+#[derive(Debug, Clone)]
+pub struct PluginLimitError {
+    /// The name of the plugin module.
+    pub plugin: String,
+    /// The name of the file being transformed, if known.
+    pub filename: Option<String>,
+    pub limit: PluginLimit,
+}
+
+impl fmt::Display for PluginLimitError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.limit {
+            PluginLimit::Fuel(fuel) => write!(
+                f,
+                "plugin `{}` exceeded the fuel limit of {} instructions",
+                self.plugin, fuel
+            )?,
+            PluginLimit::Memory(bytes) => write!(
+                f,
+                "plugin `{}` exceeded the memory limit of {} bytes",
+                self.plugin, bytes
+            )?,
+        }
+
+        if let Some(filename) = &self.filename {
+            write!(f, " while transforming `{}`", filename)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl std::error::Error for PluginLimitError {}
+
+#[cfg(not(target_arch = "wasm32"))]
+pub(crate) use self::native::*;
+
+#[cfg(not(target_arch = "wasm32"))]
+mod native {
+    use std::{
+        ptr::NonNull,
+        sync::{
+            atomic::{AtomicBool, Ordering},
+            Arc,
+        },
+    };
+
+    use parking_lot::Mutex;
+    use wasmer::{
+        sys::{
+            wasmparser::{BlockType, Operator},
+            BaseTunables, FunctionMiddleware, MiddlewareReaderState, ModuleMiddleware, Tunables,
+        },
+        vm::{
+            LinearMemory, MemoryError, MemoryStyle, TableStyle, VMMemory, VMMemoryDefinition,
+            VMTable, VMTableDefinition,
+        },
+        ExportIndex, GlobalInit, GlobalType, LocalFunctionIndex, MemoryType, MiddlewareError,
+        Mutability, Pages, Type, WASM_MAX_PAGES, WASM_PAGE_SIZE,
+    };
+    use wasmer_types::{entity::EntityRef, GlobalIndex, ModuleInfo};
+
+    /// The name of the exported global storing the remaining fuel.
+    pub(crate) const REMAINING_FUEL_EXPORT: &str = "__swc_remaining_fuel";
+    /// The name of the exported global set to `1` if the fuel is exhausted.
+    pub(crate) const FUEL_EXHAUSTED_EXPORT: &str = "__swc_fuel_exhausted";
+
+    /// A middleware counting executed instructions, which traps if the
+    /// remaining fuel is not enough to execute the next basic block.
+    ///
+    /// The globals are added to each module, so a module must be compiled at
+    /// a time.
+    #[derive(Debug, Default)]
+    pub(crate) struct Metering {
+        globals: Mutex<Option<MeteringGlobals>>,
+    }
+
+    #[derive(Debug, Clone, Copy)]
+    struct MeteringGlobals {
+        remaining: GlobalIndex,
+        exhausted: GlobalIndex,
+    }
+
+    impl ModuleMiddleware for Metering {
+        fn generate_function_middleware(
+            &self,
+            _: LocalFunctionIndex,
+        ) -> Box<dyn FunctionMiddleware> {
+            Box::new(FunctionMetering {
+                globals: self
+                    .globals
+                    .lock()
+                    .expect("globals should be added before compiling functions"),
+                cost: 0,
+            })
+        }
+
+        fn transform_module_info(&self, info: &mut ModuleInfo) -> Result<(), MiddlewareError> {
+            let remaining = info
+                .globals
+                .push(GlobalType::new(Type::I64, Mutability::Var));
+            info.global_initializers
+                .push(GlobalInit::I64Const(u64::MAX as i64));
+            info.exports
+                .insert(REMAINING_FUEL_EXPORT.into(), ExportIndex::Global(remaining));
+
+            let exhausted = info
+                .globals
+                .push(GlobalType::new(Type::I32, Mutability::Var));
+            info.global_initializers.push(GlobalInit::I32Const(0));
+            info.exports
+                .insert(FUEL_EXHAUSTED_EXPORT.into(), ExportIndex::Global(exhausted));
+
+            *self.globals.lock() = Some(MeteringGlobals {
+                remaining,
+                exhausted,
+            });
+
+            Ok(())
+        }
+    }
+
+    #[derive(Debug)]
+    struct FunctionMetering {
+        globals: MeteringGlobals,
+        /// The cost of the instructions since the last check.
+        cost: u64,
+    }
+
+    impl FunctionMiddleware for FunctionMetering {
+        fn feed<'a>(
+            &mut self,
+            operator: Operator<'a>,
+            state: &mut MiddlewareReaderState<'a>,
+        ) -> Result<(), MiddlewareError> {
+            self.cost += 1;
+
+            // Instructions which may change the control flow end a basic block, so the
+            // cost of the block is checked and subtracted before them.
+            if matches!(
+                operator,
+                Operator::Loop { .. }
+                    | Operator::Block { .. }
+                    | Operator::End
+                    | Operator::If { .. }
+                    | Operator::Else
+                    | Operator::Unreachable
+                    | Operator::Br { .. }
+                    | Operator::BrTable { .. }
+                    | Operator::BrIf { .. }
+                    | Operator::Call { .. }
+                    | Operator::CallIndirect { .. }
+                    | Operator::Return
+                    | Operator::ReturnCall { .. }
+                    | Operator::ReturnCallIndirect { .. }
+            ) {
+                let remaining = self.globals.remaining.index() as u32;
+                let cost = self.cost as i64;
+
+                for operator in [
+                    Operator::GlobalGet {
+                        global_index: remaining,
+                    },
+                    Operator::I64Const { value: cost },
+                    Operator::I64LtU,
+                    Operator::If {
+                        blockty: BlockType::Empty,
+                    },
+                    Operator::I32Const { value: 1 },
+                    Operator::GlobalSet {
+                        global_index: self.globals.exhausted.index() as u32,
+                    },
+                    Operator::Unreachable,
+                    Operator::End,
+                    Operator::GlobalGet {
+                        global_index: remaining,
+                    },
+                    Operator::I64Const { value: cost },
+                    Operator::I64Sub,
+                    Operator::GlobalSet {
+                        global_index: remaining,
+                    },
+                ] {
+                    state.push_operator(operator);
+                }
+
+                self.cost = 0;
+            }
+
+            state.push_operator(operator);
+
+            Ok(())
+        }
+    }
+
+    /// Tunables limiting the maximum size of linear memories.
+    pub(crate) struct MemoryLimitingTunables {
+        base: BaseTunables,
+        limit: Pages,
+        /// Set if a memory created by these tunables failed to grow.
+        grow_failed: Arc<AtomicBool>,
+    }
+
+    impl MemoryLimitingTunables {
+        pub fn new(base: BaseTunables, max_memory: u64) -> Self {
+            let pages = (max_memory / WASM_PAGE_SIZE as u64).min(WASM_MAX_PAGES as u64);
+
+            Self {
+                base,
+                limit: Pages(pages as u32),
+                grow_failed: Default::default(),
+            }
+        }
+
+        /// Returns a flag set if a memory created by these tunables failed to
+        /// grow, which means the plugin tried to exceed the limit.
+        pub fn grow_failed(&self) -> Arc<AtomicBool> {
+            self.grow_failed.clone()
+        }
+
+        fn track_grow(&self, memory: VMMemory) -> VMMemory {
+            VMMemory(Box::new(GrowTrackingMemory {
+                inner: memory,
+                grow_failed: self.grow_failed.clone(),
+            }))
+        }
+
+        fn adjust_memory(&self, ty: &MemoryType) -> Result<MemoryType, MemoryError> {
+            if ty.minimum > self.limit {
+                return Err(MemoryError::MinimumMemoryTooLarge {
+                    min_requested: ty.minimum,
+                    max_allowed: self.limit,
+                });
+            }
+
+            let mut ty = *ty;
+            ty.maximum = Some(ty.maximum.map_or(self.limit, |max| max.min(self.limit)));
+
+            Ok(ty)
+        }
+    }
+
+    impl Tunables for MemoryLimitingTunables {
+        fn memory_style(&self, memory: &MemoryType) -> MemoryStyle {
+            // The style should be the same as the one used to compile the module.
+            self.base.memory_style(memory)
+        }
+
+        fn table_style(&self, table: &wasmer::TableType) -> TableStyle {
+            self.base.table_style(table)
+        }
+
+        fn create_host_memory(
+            &self,
+            ty: &MemoryType,
+            style: &MemoryStyle,
+        ) -> Result<VMMemory, MemoryError> {
+            self.base
+                .create_host_memory(&self.adjust_memory(ty)?, style)
+                .map(|memory| self.track_grow(memory))
+        }
+
+        unsafe fn create_vm_memory(
+            &self,
+            ty: &MemoryType,
+            style: &MemoryStyle,
+            vm_definition_location: NonNull<VMMemoryDefinition>,
+        ) -> Result<VMMemory, MemoryError> {
+            self.base
+                .create_vm_memory(&self.adjust_memory(ty)?, style, vm_definition_location)
+                .map(|memory| self.track_grow(memory))
+        }
+
+        fn create_host_table(
+            &self,
+            ty: &wasmer::TableType,
+            style: &TableStyle,
+        ) -> Result<VMTable, String> {
+            self.base.create_host_table(ty, style)
+        }
+
+        unsafe fn create_vm_table(
+            &self,
+            ty: &wasmer::TableType,
+            style: &TableStyle,
+            vm_definition_location: NonNull<VMTableDefinition>,
+        ) -> Result<VMTable, String> {
+            self.base.create_vm_table(ty, style, vm_definition_location)
+        }
+    }
+
+    /// A linear memory which sets a flag if it fails to grow.
+    ///
+    /// `memory.grow` doesn't trap if it fails, so this is the only way to
+    /// tell whether a plugin failed because of the memory limit.
+    #[derive(Debug)]
+    struct GrowTrackingMemory {
+        inner: VMMemory,
+        grow_failed: Arc<AtomicBool>,
+    }
+
+    impl GrowTrackingMemory {
+        fn track<T>(&self, result: Result<T, MemoryError>) -> Result<T, MemoryError> {
+            if result.is_err() {
+                self.grow_failed.store(true, Ordering::Relaxed);
+            }
+
+            result
+        }
+    }
+
+    impl LinearMemory for GrowTrackingMemory {
+        fn ty(&self) -> MemoryType {
+            self.inner.ty()
+        }
+
+        fn size(&self) -> Pages {
+            self.inner.size()
+        }
+
+        fn style(&self) -> MemoryStyle {
+            self.inner.style()
+        }
+
+        fn grow(&mut self, delta: Pages) -> Result<Pages, MemoryError> {
+            let result = self.inner.grow(delta);
+            self.track(result)
+        }
+
+        fn grow_at_least(&mut self, min_size: u64) -> Result<(), MemoryError> {
+            let result = self.inner.grow_at_least(min_size);
+            self.track(result)
+        }
+
+        fn reset(&mut self) -> Result<(), MemoryError> {
+            self.inner.reset()
+        }
+
+        fn vmmemory(&self) -> NonNull<VMMemoryDefinition> {
+            self.inner.vmmemory()
+        }
+
+        fn try_clone(&self) -> Result<Box<dyn LinearMemory + 'static>, MemoryError> {
+            Ok(Box::new(GrowTrackingMemory {
+                inner: VMMemory(self.inner.try_clone()?),
+                grow_failed: self.grow_failed.clone(),
+            }))
+        }
+
+        fn copy(&mut self) -> Result<Box<dyn LinearMemory + 'static>, MemoryError> {
+            Ok(Box::new(GrowTrackingMemory {
+                inner: VMMemory(self.inner.copy()?),
+                grow_failed: self.grow_failed.clone(),
+            }))
+        }
+    }
+}
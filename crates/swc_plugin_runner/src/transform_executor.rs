@@ -1,7 +1,16 @@
-use std::{env, sync::Arc};
+use std::{
+    env,
+    panic::{self, AssertUnwindSafe},
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+};
 
 use anyhow::{anyhow, Context, Error};
 use parking_lot::Mutex;
+#[cfg(all(feature = "__rkyv", any(feature = "css", feature = "html")))]
+use swc_common::plugin::serialized::VersionedSerializable;
 #[cfg(feature = "__rkyv")]
 use swc_common::plugin::serialized::{PluginError, PluginSerializedBytes};
 #[cfg(any(
@@ -13,9 +22,11 @@ use swc_common::{
     plugin::{diagnostics::PluginCorePkgDiagnostics, metadata::TransformPluginMetadataContext},
     SourceMap,
 };
-use wasmer::{AsStoreMut, FunctionEnv, Instance, Store, TypedFunction};
+use wasmer::{AsStoreMut, FunctionEnv, Instance, Store, TypedFunction, Value};
 use wasmer_wasix::{default_fs_backing, is_wasi_module, Runtime, WasiEnv, WasiFunctionEnv};
 
+#[cfg(not(target_arch = "wasm32"))]
+use crate::limits::{MemoryLimitingTunables, FUEL_EXHAUSTED_EXPORT, REMAINING_FUEL_EXPORT};
 #[cfg(feature = "__rkyv")]
 use crate::{
     host_environment::BaseHostEnvironment,
@@ -27,7 +38,11 @@ use crate::{
     },
     memory_interop::write_into_memory_view,
 };
-use crate::{plugin_module_bytes::PluginModuleBytes, wasix_runtime::build_wasi_runtime};
+use crate::{
+    limits::{PluginLimit, PluginLimitError, PluginLimits},
+    plugin_module_bytes::PluginModuleBytes,
+    wasix_runtime::build_wasi_runtime,
+};
 
 /// An internal state to the plugin transform.
 struct PluginTransformState {
@@ -45,6 +60,10 @@ struct PluginTransformState {
     transform_result: Arc<Mutex<Vec<u8>>>,
     #[allow(unused)]
     plugin_core_diag: PluginCorePkgDiagnostics,
+    limits: PluginLimits,
+    /// Set if the memory of the plugin failed to grow because of
+    /// [PluginLimits::max_memory].
+    memory_grow_failed: Option<Arc<AtomicBool>>,
 }
 
 #[cfg(feature = "__rkyv")]
@@ -60,27 +79,36 @@ impl PluginTransformState {
         let should_enable_comments_proxy =
             u32::from(should_enable_comments_proxy.unwrap_or_default());
 
+        // Allocation may fail if the memory of the plugin is limited.
+        let serialized_len = program.as_ptr().1;
+        let allocated_ptr = self
+            .exported_plugin_alloc
+            .call(
+                &mut self.store,
+                serialized_len
+                    .try_into()
+                    .expect("Should able to convert size"),
+            )
+            .with_context(|| {
+                format!(
+                    "Should able to allocate memory for the size of {}",
+                    serialized_len
+                )
+            })?;
+
         // Copy host's serialized bytes into guest (plugin)'s allocated memory.
-        let guest_program_ptr = write_into_memory_view(
-            memory,
-            &mut self.store.as_store_mut(),
-            program,
-            |s, serialized_len| {
-                self.exported_plugin_alloc
-                    .call(
-                        s,
-                        serialized_len
-                            .try_into()
-                            .expect("Should able to convert size"),
-                    )
-                    .unwrap_or_else(|_| {
-                        panic!(
-                            "Should able to allocate memory for the size of {}",
-                            serialized_len
-                        )
-                    })
-            },
-        );
+        let guest_program_ptr =
+            write_into_memory_view(memory, &mut self.store.as_store_mut(), program, |_, _| {
+                allocated_ptr
+            });
+
+        #[cfg(not(target_arch = "wasm32"))]
+        if let Some(fuel) = self.limits.fuel {
+            self.instance
+                .exports
+                .get_global(REMAINING_FUEL_EXPORT)?
+                .set(&mut self.store, Value::I64(fuel as i64))?;
+        }
 
         let returned_ptr_result = self.exported_plugin_transform.call(
             &mut self.store,
@@ -131,6 +159,34 @@ impl PluginTransformState {
         ret
     }
 
+    /// Returns the limit exceeded by the plugin, if any, after running the
+    /// transform.
+    fn exceeded_limit(&mut self) -> Option<PluginLimit> {
+        #[cfg(not(target_arch = "wasm32"))]
+        if let Some(fuel) = self.limits.fuel {
+            let exhausted = self
+                .instance
+                .exports
+                .get_global(FUEL_EXHAUSTED_EXPORT)
+                .ok()?
+                .get(&mut self.store);
+
+            if matches!(exhausted, Value::I32(1)) {
+                return Some(PluginLimit::Fuel(fuel));
+            }
+        }
+
+        if let (Some(max_memory), Some(grow_failed)) =
+            (self.limits.max_memory, &self.memory_grow_failed)
+        {
+            if grow_failed.load(Ordering::Relaxed) {
+                return Some(PluginLimit::Memory(max_memory));
+            }
+        }
+
+        None
+    }
+
     /**
      * Check compile-time version of AST schema between the plugin and
      * the host. Returns true if it's compatible, false otherwise.
@@ -180,6 +236,7 @@ pub struct TransformExecutor {
     plugin_config: Option<serde_json::Value>,
     module_bytes: Box<dyn PluginModuleBytes>,
     runtime: Option<Arc<dyn Runtime + Send + Sync>>,
+    limits: PluginLimits,
 }
 
 #[cfg(feature = "__rkyv")]
@@ -214,15 +271,55 @@ impl TransformExecutor {
             plugin_config,
             module_bytes,
             runtime,
+            limits: Default::default(),
         }
     }
 
+    /// Sets limits on resources the plugin can use while running a transform.
+    ///
+    /// If the plugin exceeds them, [TransformExecutor::transform] returns a
+    /// [PluginLimitError].
+    pub fn with_limits(mut self, limits: PluginLimits) -> Self {
+        self.limits = limits;
+        self
+    }
+
     // Import, export, and create memory for the plugin to communicate between host
     // and guest then acquire necessary exports from the plugin.
     fn setup_plugin_env_exports(&mut self) -> Result<PluginTransformState, Error> {
         // First, compile plugin module bytes into wasmer::Module and get the
         // corresponding store
         let (mut store, module) = self.module_bytes.compile_module()?;
+        #[cfg_attr(target_arch = "wasm32", allow(unused_mut))]
+        let mut memory_grow_failed = None;
+
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            use wasmer::sys::{BaseTunables, NativeEngineExt};
+
+            if self.limits.fuel.is_some()
+                && module
+                    .exports()
+                    .all(|export| export.name() != REMAINING_FUEL_EXPORT)
+            {
+                anyhow::bail!(
+                    "Plugin `{}` should be compiled with metering to limit fuel",
+                    self.module_bytes.get_module_name()
+                );
+            }
+
+            // Tunables of the engine of the store are used to create the memory.
+            if let Some(max_memory) = self.limits.max_memory {
+                let mut engine = store.engine().clone();
+                let base = BaseTunables::for_target(engine.target());
+
+                let tunables = MemoryLimitingTunables::new(base, max_memory);
+                memory_grow_failed = Some(tunables.grow_failed());
+
+                engine.set_tunables(tunables);
+                store = Store::new(engine);
+            }
+        }
 
         let context_key_buffer = Arc::new(Mutex::new(Vec::new()));
         let metadata_env = FunctionEnv::new(
@@ -389,6 +486,8 @@ impl TransformExecutor {
             wasi_env,
             transform_result,
             plugin_core_diag: diag_result,
+            limits: self.limits,
+            memory_grow_failed,
         })
     }
 
@@ -400,8 +499,26 @@ impl TransformExecutor {
     ) -> Result<PluginSerializedBytes, Error> {
         let mut transform_state = self.setup_plugin_env_exports()?;
         transform_state.is_transform_schema_compatible()?;
-        transform_state
-            .run(program, self.unresolved_mark, should_enable_comments_proxy)
+
+        // Host functions panic if calls into the plugin fail, which is expected if the
+        // plugin exceeds the limits.
+        let result = panic::catch_unwind(AssertUnwindSafe(|| {
+            transform_state.run(program, self.unresolved_mark, should_enable_comments_proxy)
+        }));
+
+        if !matches!(result, Ok(Ok(..))) {
+            if let Some(limit) = transform_state.exceeded_limit() {
+                return Err(PluginLimitError {
+                    plugin: self.module_bytes.get_module_name().to_string(),
+                    filename: self.metadata_context.filename.clone(),
+                    limit,
+                }
+                .into());
+            }
+        }
+
+        result
+            .unwrap_or_else(|panic| panic::resume_unwind(panic))
             .with_context(|| {
                 format!(
                     "failed to run Wasm plugin transform. Please ensure the version of `swc_core` \
@@ -427,6 +544,38 @@ impl TransformExecutor {
                 )
             })
     }
+
+    /// Runs a CSS plugin, which is authored with `#[css_plugin_transform]`,
+    /// on `stylesheet`.
+    #[cfg(feature = "css")]
+    pub fn transform_stylesheet(
+        &mut self,
+        stylesheet: swc_css_ast::Stylesheet,
+    ) -> Result<swc_css_ast::Stylesheet, Error> {
+        let serialized =
+            PluginSerializedBytes::try_serialize(&VersionedSerializable::new(stylesheet))?;
+
+        // Comments are not stored out of the AST of stylesheets.
+        self.transform(&serialized, Some(false))?
+            .deserialize()
+            .map(|v| v.into_inner())
+    }
+
+    /// Runs a HTML plugin, which is authored with `#[html_plugin_transform]`,
+    /// on `document`.
+    #[cfg(feature = "html")]
+    pub fn transform_document(
+        &mut self,
+        document: swc_html_ast::Document,
+    ) -> Result<swc_html_ast::Document, Error> {
+        let serialized =
+            PluginSerializedBytes::try_serialize(&VersionedSerializable::new(document))?;
+
+        // Comments of documents are nodes of the AST.
+        self.transform(&serialized, Some(false))?
+            .deserialize()
+            .map(|v| v.into_inner())
+    }
 }
 
 const BUILD_DATE: &str = env!("VERGEN_BUILD_DATE");
@@ -9,12 +9,19 @@ use wasmer_wasix::Runtime;
 
 /// A shared instance to plugin runtime engine.
 /// ref: https://github.com/wasmerio/wasmer/issues/3793#issuecomment-1607117480
-static ENGINE: Lazy<Mutex<wasmer::Engine>> = Lazy::new(|| {
+static ENGINE: Lazy<Mutex<wasmer::Engine>> = Lazy::new(|| Mutex::new(new_engine(false)));
+
+/// A shared engine compiling modules with metering, which is required to limit
+/// fuel of plugins. The lock should be held while compiling a module.
+#[cfg(not(target_arch = "wasm32"))]
+static METERED_ENGINE: Lazy<Mutex<wasmer::Engine>> = Lazy::new(|| Mutex::new(new_engine(true)));
+
+fn new_engine(metering: bool) -> wasmer::Engine {
     // Use empty enumset to disable simd.
     use enumset::EnumSet;
     use wasmer::{
-        sys::{BaseTunables, EngineBuilder},
-        CompilerConfig, Target, Triple,
+        sys::{BaseTunables, CompilerConfig, EngineBuilder},
+        Target, Triple,
     };
     let mut set = EnumSet::new();
 
@@ -23,14 +30,18 @@ static ENGINE: Lazy<Mutex<wasmer::Engine>> = Lazy::new(|| {
     set.insert(wasmer::CpuFeature::SSE2);
     let target = Target::new(Triple::host(), set);
 
-    let config = wasmer_compiler_cranelift::Cranelift::default();
+    let mut config = wasmer_compiler_cranelift::Cranelift::default();
+    #[cfg(not(target_arch = "wasm32"))]
+    if metering {
+        config.push_middleware(Arc::new(crate::limits::Metering::default()));
+    }
     let mut engine = EngineBuilder::new(Box::new(config) as Box<dyn CompilerConfig>)
         .set_target(Some(target))
         .engine();
     let tunables = BaseTunables::for_target(engine.target());
     engine.set_tunables(tunables);
-    parking_lot::Mutex::new(wasmer::Engine::from(engine))
-});
+    wasmer::Engine::from(engine)
+}
 
 /// Dummy http client for wasix runtime to avoid instantiation failure for the
 /// default pluggable runtime. We don't support network in the host runtime
@@ -99,3 +110,17 @@ pub(crate) fn new_store() -> Store {
 pub(crate) fn new_store() -> Store {
     Store::default()
 }
+
+/// Compiles `bytes` with metering, so the fuel of the plugin can be limited.
+#[cfg(not(target_arch = "wasm32"))]
+pub(crate) fn compile_metered_module(
+    bytes: &[u8],
+) -> Result<(Store, wasmer::Module), wasmer::CompileError> {
+    // Metering adds globals to the module being compiled, so modules should not be
+    // compiled concurrently.
+    let engine = METERED_ENGINE.lock();
+    let store = Store::new(engine.clone());
+    let module = wasmer::Module::new(&store, bytes)?;
+
+    Ok((store, module))
+}
@@ -21,7 +21,7 @@ use swc_ecma_visit::{Visit, VisitWith};
 use testing::CARGO_TARGET_DIR;
 
 /// Returns the path to the built plugin
-fn build_plugin(dir: &Path) -> Result<PathBuf, Error> {
+fn build_plugin(dir: &Path, crate_name: &str) -> Result<PathBuf, Error> {
     {
         let mut cmd = Command::new("cargo");
         cmd.env("CARGO_TARGET_DIR", &*CARGO_TARGET_DIR);
@@ -43,7 +43,7 @@ fn build_plugin(dir: &Path) -> Result<PathBuf, Error> {
         let entry = entry?;
 
         let s = entry.file_name().to_string_lossy().into_owned();
-        if s.eq_ignore_ascii_case("swc_internal_plugin.wasm") {
+        if s.eq_ignore_ascii_case(&format!("{}.wasm", crate_name)) {
             return Ok(entry.path());
         }
     }
@@ -80,6 +80,7 @@ static PLUGIN_BYTES: Lazy<swc_plugin_runner::plugin_module_bytes::CompiledPlugin
                 .join("tests")
                 .join("fixture")
                 .join("swc_internal_plugin"),
+            "swc_internal_plugin",
         )
         .unwrap();
 
@@ -236,3 +237,77 @@ fn internal() {
         .expect("Should able to run single plugin transform with handler");
     });
 }
+
+#[cfg(feature = "__rkyv")]
+#[test]
+fn artifacts() {
+    use swc_common::plugin::serialized::VersionedSerializable;
+    use swc_transform_common::output::{capture_artifacts, Artifact};
+
+    let plugin_path = build_plugin(
+        &PathBuf::from(env::var("CARGO_MANIFEST_DIR").unwrap())
+            .join("tests")
+            .join("fixture")
+            .join("swc_artifact_plugin"),
+        "swc_artifact_plugin",
+    )
+    .unwrap();
+
+    tokio::runtime::Runtime::new().unwrap().block_on(async {
+        testing::run_test(false, |cm, _handler| {
+            let fm = cm.new_source_file(FileName::Anon.into(), "console.log(foo)".into());
+
+            let program = parse_file_as_program(
+                &fm,
+                Syntax::Es(Default::default()),
+                EsVersion::latest(),
+                None,
+                &mut Vec::new(),
+            )
+            .unwrap();
+
+            let program =
+                PluginSerializedBytes::try_serialize(&VersionedSerializable::new(program))
+                    .expect("Should serializable");
+
+            let raw_module_bytes =
+                std::fs::read(&plugin_path).expect("Should able to read plugin bytes");
+
+            let mut plugin_transform_executor = swc_plugin_runner::create_plugin_transform_executor(
+                &cm,
+                &Mark::new(),
+                &Arc::new(TransformPluginMetadataContext::new(
+                    None,
+                    "development".to_string(),
+                    None,
+                )),
+                None,
+                Box::new(
+                    swc_plugin_runner::plugin_module_bytes::RawPluginModuleBytes::new(
+                        "swc_artifact_plugin".to_string(),
+                        raw_module_bytes,
+                    ),
+                ),
+                None,
+                None,
+            );
+
+            let (_, artifacts) = capture_artifacts(|| {
+                plugin_transform_executor
+                    .transform(&program, Some(false))
+                    .expect("Plugin should apply transform")
+            });
+
+            assert_eq!(
+                artifacts,
+                vec![Artifact {
+                    path: "messages.json".into(),
+                    content: b"{}".to_vec(),
+                }]
+            );
+
+            Ok(())
+        })
+        .expect("Should able to run plugin transform");
+    });
+}
@@ -0,0 +1,12 @@
+use swc_core::{
+    ecma::ast::*,
+    plugin::{metadata::TransformPluginProgramMetadata, plugin_transform},
+};
+use swc_transform_common::output::emit_artifact;
+
+#[plugin_transform]
+pub fn process(program: Program, _metadata: TransformPluginProgramMetadata) -> Program {
+    emit_artifact("messages.json".into(), b"{}".to_vec());
+
+    program
+}
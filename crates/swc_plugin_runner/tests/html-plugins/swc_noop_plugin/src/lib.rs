@@ -0,0 +1,9 @@
+use swc_core::{
+    html::ast::Document,
+    plugin::{html_plugin_transform, metadata::TransformPluginProgramMetadata},
+};
+
+#[html_plugin_transform]
+pub fn process(program: Document, metadata: TransformPluginProgramMetadata) -> Document {
+    program
+}
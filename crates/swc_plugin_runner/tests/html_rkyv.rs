@@ -0,0 +1,108 @@
+#![cfg_attr(not(all(feature = "__rkyv", feature = "html")), allow(warnings))]
+use std::{
+    env, fs,
+    path::{Path, PathBuf},
+    process::{Command, Stdio},
+    sync::Arc,
+};
+
+use anyhow::{anyhow, Error};
+use serde_json::json;
+use swc_common::{plugin::metadata::TransformPluginMetadataContext, sync::Lazy, Mark};
+use testing::CARGO_TARGET_DIR;
+
+/// Returns the path to the built plugin
+fn build_plugin(dir: &Path) -> Result<PathBuf, Error> {
+    {
+        let mut cmd = Command::new("cargo");
+        cmd.env("CARGO_TARGET_DIR", &*CARGO_TARGET_DIR);
+
+        cmd.current_dir(dir);
+        cmd.args(["build", "--target=wasm32-wasip1", "--release"])
+            .stderr(Stdio::inherit());
+        cmd.output()?;
+
+        if !cmd
+            .status()
+            .expect("Exit code should be available")
+            .success()
+        {
+            return Err(anyhow!("Failed to build plugin"));
+        }
+    }
+
+    for entry in fs::read_dir(CARGO_TARGET_DIR.join("wasm32-wasip1").join("release"))? {
+        let entry = entry?;
+
+        let s = entry.file_name().to_string_lossy().into_owned();
+        if s.eq_ignore_ascii_case("swc_noop_plugin.wasm") {
+            return Ok(entry.path());
+        }
+    }
+
+    Err(anyhow!("Could not find built plugin"))
+}
+
+#[cfg(all(feature = "__rkyv", feature = "html"))]
+static PLUGIN_BYTES: Lazy<swc_plugin_runner::plugin_module_bytes::CompiledPluginModuleBytes> =
+    Lazy::new(|| {
+        let path = build_plugin(
+            &PathBuf::from(env::var("CARGO_MANIFEST_DIR").unwrap())
+                .join("tests")
+                .join("html-plugins")
+                .join("swc_noop_plugin"),
+        )
+        .unwrap();
+
+        let raw_module_bytes = std::fs::read(&path).expect("Should able to read plugin bytes");
+        let store = wasmer::Store::default();
+        let module = wasmer::Module::new(&store, raw_module_bytes).unwrap();
+
+        swc_plugin_runner::plugin_module_bytes::CompiledPluginModuleBytes::new(
+            path.as_os_str()
+                .to_str()
+                .expect("Should able to get path")
+                .to_string(),
+            module,
+            store,
+        )
+    });
+
+#[cfg(all(feature = "__rkyv", feature = "html"))]
+#[testing::fixture("../swc_html_parser/tests/fixture/**/input.html")]
+fn invoke(input: PathBuf) {
+    use swc_html_ast::Document;
+
+    tokio::runtime::Runtime::new().unwrap().block_on(async {
+        testing::run_test(false, |cm, _handler| {
+            let fm = cm.load_file(&input).unwrap();
+
+            let parsed: Document =
+                swc_html_parser::parse_file_as_document(&fm, Default::default(), &mut Vec::new())
+                    .unwrap();
+
+            let mut plugin_transform_executor = swc_plugin_runner::create_plugin_transform_executor(
+                &cm,
+                &Mark::new(),
+                &Arc::new(TransformPluginMetadataContext::new(
+                    None,
+                    "development".to_string(),
+                    None,
+                )),
+                None,
+                Box::new(PLUGIN_BYTES.clone()),
+                Some(json!({ "pluginConfig": "testValue" })),
+                None,
+            );
+
+            let document = plugin_transform_executor
+                .transform_document(parsed.clone())
+                .expect("Plugin should apply transform");
+
+            assert_eq!(parsed, document);
+
+            Ok(())
+        })
+        .expect("Should able to run plugin transform");
+    });
+}
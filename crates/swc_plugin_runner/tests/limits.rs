@@ -0,0 +1,190 @@
+#![cfg(all(feature = "__rkyv", not(target_arch = "wasm32")))]
+
+use std::{fmt::Write, sync::Arc};
+
+use swc_common::{
+    plugin::{
+        diagnostics::PluginCorePkgDiagnostics,
+        metadata::TransformPluginMetadataContext,
+        serialized::{PluginSerializedBytes, VersionedSerializable},
+    },
+    Mark,
+};
+use swc_plugin_runner::{
+    cache::PluginModuleCache,
+    limits::{PluginLimit, PluginLimitError, PluginLimits},
+    plugin_module_bytes::{PluginModuleBytes, RawPluginModuleBytes},
+};
+
+fn serialized_diagnostics() -> PluginSerializedBytes {
+    PluginSerializedBytes::try_serialize(&VersionedSerializable::new(PluginCorePkgDiagnostics {
+        pkg_version: "0.0.0".into(),
+        git_sha: "".into(),
+        cargo_features: "".into(),
+        ast_schema_version: 1,
+    }))
+    .expect("Should serializable")
+}
+
+/// Builds a minimal plugin running `body` as its transform.
+fn plugin(body: &str) -> Vec<u8> {
+    let diagnostics = serialized_diagnostics();
+    let mut data = String::new();
+    for b in diagnostics.as_slice() {
+        write!(data, "\\{:02x}", b).unwrap();
+    }
+
+    let wat = format!(
+        r#"(module
+            (import "env" "__set_transform_plugin_core_pkg_diagnostics"
+                (func $set_diagnostics (param i32 i32)))
+            (memory (export "memory") 1)
+            (data (i32.const 16) "{data}")
+            (func (export "__get_transform_plugin_core_pkg_diag") (result i32)
+                (call $set_diagnostics (i32.const 16) (i32.const {len}))
+                (i32.const 0))
+            (func (export "__alloc") (param i32) (result i32)
+                (i32.const 4096))
+            (func (export "__free") (param i32 i32) (result i32)
+                (i32.const 0))
+            (func (export "__transform_plugin_process_impl")
+                (param i32 i32 i32 i32) (result i32)
+                {body}))"#,
+        len = diagnostics.as_slice().len(),
+    );
+
+    wasmer::wat2wasm(wat.as_bytes())
+        .expect("Should be valid wat")
+        .into_owned()
+}
+
+fn metered(name: &str, bytes: Vec<u8>) -> Box<dyn PluginModuleBytes> {
+    let mut cache = PluginModuleCache::create_inner(false, None);
+    cache.insert_raw_bytes(name.into(), bytes);
+
+    cache
+        .get_metered(name)
+        .expect("Should compile with metering")
+        .expect("Should be in the cache")
+}
+
+fn run(
+    module_bytes: Box<dyn PluginModuleBytes>,
+    limits: PluginLimits,
+) -> Result<(), anyhow::Error> {
+    tokio::runtime::Runtime::new().unwrap().block_on(async {
+        testing::run_test(false, |cm, _handler| {
+            let mut executor = swc_plugin_runner::create_plugin_transform_executor(
+                &cm,
+                &Mark::new(),
+                &Arc::new(TransformPluginMetadataContext::new(
+                    Some("input.js".into()),
+                    "development".into(),
+                    None,
+                )),
+                None,
+                module_bytes,
+                None,
+                None,
+            )
+            .with_limits(limits);
+
+            Ok(executor
+                .transform(&serialized_diagnostics(), Some(false))
+                .map(drop))
+        })
+        .unwrap()
+    })
+}
+
+fn limit_error(result: Result<(), anyhow::Error>) -> PluginLimitError {
+    result
+        .expect_err("Should exceed the limit")
+        .downcast::<PluginLimitError>()
+        .expect("Should be a limit error")
+}
+
+#[test]
+fn fuel_limit() {
+    let err = limit_error(run(
+        metered("loop", plugin("(loop $l (br $l)) (i32.const 0)")),
+        PluginLimits {
+            fuel: Some(1_000_000),
+            ..Default::default()
+        },
+    ));
+
+    assert_eq!(err.plugin, "loop");
+    assert_eq!(err.filename.as_deref(), Some("input.js"));
+    assert_eq!(err.limit, PluginLimit::Fuel(1_000_000));
+    assert_eq!(
+        err.to_string(),
+        "plugin `loop` exceeded the fuel limit of 1000000 instructions while transforming \
+         `input.js`"
+    );
+}
+
+#[test]
+fn within_fuel_limit() {
+    run(
+        metered("noop", plugin("(i32.const 0)")),
+        PluginLimits {
+            fuel: Some(1_000),
+            ..Default::default()
+        },
+    )
+    .expect("Should not exceed the limit");
+}
+
+#[test]
+fn fuel_limit_requires_metering() {
+    let err = run(
+        Box::new(RawPluginModuleBytes::new(
+            "noop".into(),
+            plugin("(i32.const 0)"),
+        )),
+        PluginLimits {
+            fuel: Some(1_000),
+            ..Default::default()
+        },
+    )
+    .expect_err("Should require metering");
+
+    assert!(err.downcast_ref::<PluginLimitError>().is_none());
+}
+
+#[test]
+fn memory_limit() {
+    let err = limit_error(run(
+        Box::new(RawPluginModuleBytes::new(
+            "grow".into(),
+            plugin(
+                "(loop $l (br_if $l (i32.ne (memory.grow (i32.const 1)) (i32.const -1))))
+                 unreachable",
+            ),
+        )),
+        PluginLimits {
+            max_memory: Some(16 * 65536),
+            ..Default::default()
+        },
+    ));
+
+    assert_eq!(err.limit, PluginLimit::Memory(16 * 65536));
+}
+
+#[test]
+fn other_failure_near_memory_limit() {
+    let err = run(
+        Box::new(RawPluginModuleBytes::new(
+            "grow".into(),
+            plugin("(drop (memory.grow (i32.const 15))) unreachable"),
+        )),
+        PluginLimits {
+            max_memory: Some(16 * 65536),
+            ..Default::default()
+        },
+    )
+    .expect_err("Should fail");
+
+    assert!(err.downcast_ref::<PluginLimitError>().is_none());
+}
@@ -56,3 +56,79 @@ pub fn experimental_emit(key: String, value: String) {
         }
     });
 }
+
+/// A file emitted by a transform in addition to the transformed program, like
+/// an extracted stylesheet or a message catalog.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Artifact {
+    /// The path of the file, relative to the directory of the output file.
+    pub path: String,
+    pub content: Vec<u8>,
+}
+
+// `scoped_tls!` defines a helper static, so each key needs its own module.
+mod artifacts {
+    use std::cell::RefCell;
+
+    use better_scoped_tls::scoped_tls;
+
+    scoped_tls!(pub(super) static ARTIFACTS: RefCell<Vec<super::Artifact>>);
+}
+
+use self::artifacts::ARTIFACTS;
+
+/// (Experimental) Captures artifacts emitted by [emit_artifact].
+///
+/// This is not stable and may be removed in the future.
+pub fn capture_artifacts<Ret>(f: impl FnOnce() -> Ret) -> (Ret, Vec<Artifact>) {
+    let artifacts = RefCell::new(Default::default());
+
+    let ret = ARTIFACTS.set(&artifacts, f);
+
+    (ret, artifacts.into_inner())
+}
+
+#[cfg(all(feature = "plugin-mode", target_arch = "wasm32"))]
+extern "C" {
+    fn __emit_artifact(artifact_ptr: u32, artifact_len: u32);
+}
+
+/// (Experimental) Emits a file to be written next to the output file.
+///
+/// An artifact with the same path as a previous one replaces it.
+///
+/// This is not stable and may be removed in the future.
+#[cfg(all(feature = "plugin-mode", target_arch = "wasm32"))]
+pub fn emit_artifact(path: String, content: Vec<u8>) {
+    let artifact = (path, content);
+
+    let serialized = swc_common::plugin::serialized::PluginSerializedBytes::try_serialize(
+        &swc_common::plugin::serialized::VersionedSerializable::new(artifact),
+    )
+    .expect("Should able to serialize artifact");
+    let (ptr, len) = serialized.as_ptr();
+
+    unsafe {
+        __emit_artifact(ptr as u32, len as u32);
+    }
+}
+
+/// (Experimental) Emits a file to be written next to the output file.
+///
+/// An artifact with the same path as a previous one replaces it. Artifacts
+/// are dropped if they are not captured by [capture_artifacts].
+///
+/// This is not stable and may be removed in the future.
+#[cfg(not(all(feature = "plugin-mode", target_arch = "wasm32")))]
+pub fn emit_artifact(path: String, content: Vec<u8>) {
+    if !ARTIFACTS.is_set() {
+        return;
+    }
+
+    ARTIFACTS.with(|artifacts| {
+        let mut artifacts = artifacts.borrow_mut();
+
+        artifacts.retain(|artifact| artifact.path != path);
+        artifacts.push(Artifact { path, content });
+    });
+}
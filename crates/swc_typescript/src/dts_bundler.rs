@@ -0,0 +1,1075 @@
+//! Bundles declarations generated by [FastDts](crate::fast_dts::FastDts) into
+//! a single declaration file, like `api-extractor` does.
+
+use std::{
+    borrow::Cow,
+    path::{Component, Path, PathBuf},
+    sync::Arc,
+};
+
+use rustc_hash::{FxHashMap, FxHashSet};
+use swc_atoms::Atom;
+use swc_common::{BytePos, FileName, Mark, Span, Spanned, DUMMY_SP};
+use swc_ecma_ast::{
+    ClassDecl, ClassExpr, Decl, DefaultDecl, ExportAll, ExportNamedSpecifier, ExportSpecifier,
+    Expr, FnDecl, FnExpr, Id, Ident, ImportDecl, ImportDefaultSpecifier, ImportNamedSpecifier,
+    ImportSpecifier, ImportStarAsSpecifier, Module, ModuleDecl, ModuleExportName, ModuleItem,
+    NamedExport, Stmt, Str, TsModuleBlock, TsModuleDecl, TsModuleName, TsModuleRef,
+    TsNamespaceBody,
+};
+use swc_ecma_utils::find_pat_ids;
+use swc_ecma_visit::{Visit, VisitMut, VisitMutWith, VisitWith};
+
+use crate::{
+    diagnostic::{DtsIssue, SourceRange},
+    fast_dts::visitors::internal_annotation::InternalAnnotationTransformer,
+};
+
+/// Bundles declaration files into a single declaration file.
+///
+/// Starting from an entry module, relative imports are followed and
+/// declarations of the imported modules are merged into the bundle, renaming
+/// them if the names conflict. Only declarations reachable from the exports of
+/// the entry module are kept, and declarations with `@internal` annotations
+/// are removed. Imports of other packages are kept as-is.
+///
+/// Namespaces of bundled modules, like `import * as ns from "./ns"`, are
+/// emitted as `declare namespace ns { export { ... } }`.
+///
+/// All modules should be resolved with the same `unresolved_mark`.
+pub struct DtsBundler {
+    unresolved_mark: Mark,
+    modules: FxHashMap<Arc<FileName>, DtsModule>,
+}
+
+/// A declaration file to bundle.
+#[derive(Debug, Clone)]
+pub struct DtsModule {
+    /// Declarations, e.g. emitted by [FastDts](crate::fast_dts::FastDts).
+    pub module: Module,
+    /// See [FastDts::get_internal_annotations](crate::fast_dts::FastDts::get_internal_annotations).
+    pub internal_annotations: Option<FxHashSet<BytePos>>,
+}
+
+#[derive(Debug)]
+pub struct DtsBundle {
+    pub module: Module,
+    pub diagnostics: Vec<DtsIssue>,
+}
+
+impl DtsBundler {
+    pub fn new(unresolved_mark: Mark) -> Self {
+        Self {
+            unresolved_mark,
+            modules: Default::default(),
+        }
+    }
+
+    /// Adds a module which can be imported by relative paths from other
+    /// modules.
+    ///
+    /// Imports are resolved like TypeScript does, so `./foo` and `./foo.js`
+    /// may refer to a module added as `foo.ts` or `foo.d.ts`.
+    pub fn add_module(&mut self, filename: Arc<FileName>, module: DtsModule) {
+        self.modules.insert(filename, module);
+    }
+
+    /// Bundles `entry` and the modules imported from it.
+    ///
+    /// Returns `None` if `entry` is not added.
+    pub fn bundle(&self, entry: &FileName) -> Option<DtsBundle> {
+        let (entry, _) = self.modules.get_key_value(entry)?;
+
+        let mut graph = Graph {
+            bundler: self,
+            ids: Default::default(),
+            modules: Vec::new(),
+            order: Vec::new(),
+            diagnostics: Vec::new(),
+        };
+
+        let entry = graph.load(entry.clone());
+        let module = graph.bundle(entry);
+
+        Some(DtsBundle {
+            module,
+            diagnostics: graph.diagnostics,
+        })
+    }
+
+    fn resolve(&self, base: &FileName, src: &str) -> Option<Arc<FileName>> {
+        let FileName::Real(base) = base else {
+            return None;
+        };
+
+        let path = normalize_path(&base.parent().unwrap_or(Path::new("")).join(src));
+
+        let mut stems = vec![path.clone()];
+        if let Some(name) = path.file_name().and_then(|name| name.to_str()) {
+            for ext in [".js", ".jsx", ".mjs", ".cjs"] {
+                if let Some(stem) = name.strip_suffix(ext) {
+                    stems.push(path.with_file_name(stem));
+                }
+            }
+        }
+
+        let mut candidates = vec![path.clone()];
+        for stem in &stems {
+            for ext in RESOLVED_EXTENSIONS {
+                let mut candidate = stem.clone().into_os_string();
+                candidate.push(".");
+                candidate.push(ext);
+                candidates.push(candidate.into());
+            }
+        }
+        for ext in RESOLVED_EXTENSIONS {
+            candidates.push(path.join(format!("index.{ext}")));
+        }
+
+        candidates.into_iter().find_map(|candidate| {
+            self.modules
+                .get_key_value(&FileName::Real(candidate))
+                .map(|(filename, _)| filename.clone())
+        })
+    }
+}
+
+const RESOLVED_EXTENSIONS: &[&str] = &["d.ts", "ts", "tsx", "d.mts", "mts", "d.cts", "cts"];
+
+fn normalize_path(path: &Path) -> PathBuf {
+    let mut normalized = PathBuf::new();
+
+    for component in path.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => {
+                if !normalized.pop() {
+                    normalized.push("..");
+                }
+            }
+            _ => normalized.push(component),
+        }
+    }
+
+    normalized
+}
+
+fn is_relative(src: &str) -> bool {
+    src == "." || src == ".." || src.starts_with("./") || src.starts_with("../")
+}
+
+type ModuleId = usize;
+
+struct Graph<'a> {
+    bundler: &'a DtsBundler,
+    ids: FxHashMap<Arc<FileName>, ModuleId>,
+    modules: Vec<ModuleInfo>,
+    /// Modules in the order of emission. Dependencies come first.
+    order: Vec<ModuleId>,
+    diagnostics: Vec<DtsIssue>,
+}
+
+#[derive(Default)]
+struct ModuleInfo {
+    items: Vec<Item>,
+    /// Indexes of items declaring a binding.
+    decls: FxHashMap<Id, Vec<usize>>,
+    imports: FxHashMap<Id, Reference>,
+    /// Local names of imports in the order of appearance.
+    import_order: Vec<Id>,
+    exports: Vec<(Atom, Reference)>,
+    star_exports: Vec<Source>,
+    internal_annotations: Option<FxHashSet<BytePos>>,
+}
+
+struct Item {
+    item: ModuleItem,
+    /// Bindings declared by the item. Items without bindings, like
+    /// `declare global`, are always kept.
+    ids: Vec<Id>,
+}
+
+impl ModuleInfo {
+    fn has_internal_annotation(&self, pos: BytePos) -> bool {
+        self.internal_annotations
+            .as_ref()
+            .map_or(false, |annotations| annotations.contains(&pos))
+    }
+
+    fn add_item(&mut self, item: ModuleItem, ids: Vec<Id>) {
+        for id in &ids {
+            self.decls
+                .entry(id.clone())
+                .or_default()
+                .push(self.items.len());
+        }
+
+        self.items.push(Item { item, ids });
+    }
+
+    fn add_import(&mut self, local: Id, reference: Reference) {
+        self.import_order.push(local.clone());
+        self.imports.insert(local, reference);
+    }
+}
+
+#[derive(Clone)]
+enum Source {
+    Module(ModuleId),
+    External(Atom),
+}
+
+impl Source {
+    fn reference(&self, name: ExternalName) -> Reference {
+        match (self, name) {
+            (Source::Module(module), ExternalName::Export(name)) => {
+                Reference::Module(*module, name)
+            }
+            (Source::Module(module), ExternalName::Namespace) => Reference::Namespace(*module),
+            (Source::External(src), name) => Reference::External(src.clone(), name),
+        }
+    }
+}
+
+#[derive(Clone)]
+enum Reference {
+    /// A binding declared or imported in the module.
+    Local(Id),
+    /// An export of a bundled module.
+    Module(ModuleId, Atom),
+    /// The namespace of a bundled module.
+    Namespace(ModuleId),
+    /// An export of a module which is not bundled.
+    External(Atom, ExternalName),
+}
+
+#[derive(Clone, PartialEq, Eq, Hash)]
+enum ExternalName {
+    Export(Atom),
+    Namespace,
+}
+
+/// A binding in the bundle.
+#[derive(Clone, PartialEq, Eq, Hash)]
+enum Binding {
+    Local(ModuleId, Id),
+    Namespace(ModuleId),
+    External(Atom, ExternalName),
+}
+
+impl Graph<'_> {
+    fn mark_diagnostic<T: Into<Cow<'static, str>>>(
+        &mut self,
+        filename: &Arc<FileName>,
+        message: T,
+        span: Span,
+    ) {
+        self.diagnostics.push(DtsIssue {
+            message: message.into(),
+            range: SourceRange {
+                filename: filename.clone(),
+                span,
+            },
+        })
+    }
+
+    fn load(&mut self, filename: Arc<FileName>) -> ModuleId {
+        if let Some(&id) = self.ids.get(&filename) {
+            return id;
+        }
+
+        let id = self.modules.len();
+        self.ids.insert(filename.clone(), id);
+        self.modules.push(ModuleInfo::default());
+
+        let bundler = self.bundler;
+        let dts = &bundler.modules[&filename];
+        let mut info = ModuleInfo {
+            internal_annotations: dts.internal_annotations.clone(),
+            ..Default::default()
+        };
+
+        for item in dts.module.body.iter().cloned() {
+            if info.has_internal_annotation(item.span_lo()) {
+                continue;
+            }
+
+            match item {
+                ModuleItem::ModuleDecl(ModuleDecl::Import(import)) => {
+                    let src = self.resolve_src(&filename, &import.src);
+
+                    if import.specifiers.is_empty() {
+                        if let Source::External(..) = src {
+                            info.add_item(ModuleDecl::Import(import).into(), Vec::new());
+                        }
+                        continue;
+                    }
+
+                    for specifier in import.specifiers {
+                        let (local, name) = match specifier {
+                            ImportSpecifier::Named(ImportNamedSpecifier {
+                                local,
+                                imported,
+                                ..
+                            }) => {
+                                let name = imported
+                                    .map(|imported| imported.atom().clone())
+                                    .unwrap_or_else(|| local.sym.clone());
+
+                                (local, ExternalName::Export(name))
+                            }
+                            ImportSpecifier::Default(ImportDefaultSpecifier { local, .. }) => {
+                                (local, ExternalName::Export("default".into()))
+                            }
+                            ImportSpecifier::Namespace(ImportStarAsSpecifier { local, .. }) => {
+                                (local, ExternalName::Namespace)
+                            }
+                        };
+
+                        let reference = src.reference(name);
+                        self.check_namespace(&filename, &reference, local.span);
+                        info.add_import(local.to_id(), reference);
+                    }
+                }
+                ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(mut export)) => {
+                    let ids = decl_ids(&export.decl);
+                    extend_span_lo(&mut export.decl, export.span.lo);
+                    for id in &ids {
+                        info.exports
+                            .push((id.0.clone(), Reference::Local(id.clone())));
+                    }
+
+                    info.add_item(Stmt::Decl(export.decl).into(), ids);
+                }
+                ModuleItem::ModuleDecl(ModuleDecl::ExportDefaultDecl(export)) => {
+                    let (ident, mut decl) = match export.decl {
+                        DefaultDecl::Class(ClassExpr { ident, class }) => {
+                            let ident = ident.unwrap_or_else(default_ident);
+
+                            (
+                                ident.clone(),
+                                ClassDecl {
+                                    ident,
+                                    declare: true,
+                                    class,
+                                }
+                                .into(),
+                            )
+                        }
+                        DefaultDecl::Fn(FnExpr { ident, function }) => {
+                            let ident = ident.unwrap_or_else(default_ident);
+
+                            (
+                                ident.clone(),
+                                FnDecl {
+                                    ident,
+                                    declare: true,
+                                    function,
+                                }
+                                .into(),
+                            )
+                        }
+                        DefaultDecl::TsInterfaceDecl(decl) => (decl.id.clone(), decl.into()),
+                    };
+                    extend_span_lo(&mut decl, export.span.lo);
+
+                    // Overloads of a default function are declared multiple times.
+                    if !info.exports.iter().any(|(name, _)| name == "default") {
+                        info.exports
+                            .push(("default".into(), Reference::Local(ident.to_id())));
+                    }
+
+                    info.add_item(Stmt::Decl(decl).into(), vec![ident.to_id()]);
+                }
+                ModuleItem::ModuleDecl(ModuleDecl::ExportDefaultExpr(export)) => {
+                    match &*export.expr {
+                        Expr::Ident(ident) => {
+                            info.exports
+                                .push(("default".into(), Reference::Local(ident.to_id())));
+                        }
+                        _ => self.unsupported(
+                            &filename,
+                            "Default exports of expressions",
+                            export.span,
+                        ),
+                    }
+                }
+                ModuleItem::ModuleDecl(ModuleDecl::ExportNamed(export)) => {
+                    let src = export
+                        .src
+                        .as_ref()
+                        .map(|src| self.resolve_src(&filename, src));
+
+                    for specifier in export.specifiers {
+                        let (orig, exported) = match specifier {
+                            ExportSpecifier::Named(ExportNamedSpecifier {
+                                orig, exported, ..
+                            }) => {
+                                let exported = exported.unwrap_or_else(|| orig.clone());
+
+                                (Some(orig), exported)
+                            }
+                            ExportSpecifier::Default(specifier) => (
+                                Some(ModuleExportName::Ident(Ident::new_no_ctxt(
+                                    "default".into(),
+                                    DUMMY_SP,
+                                ))),
+                                ModuleExportName::Ident(specifier.exported),
+                            ),
+                            ExportSpecifier::Namespace(specifier) => (None, specifier.name),
+                        };
+
+                        let reference = match (&src, orig) {
+                            (None, Some(ModuleExportName::Ident(orig))) => {
+                                Some(Reference::Local(orig.to_id()))
+                            }
+                            (None, _) => None,
+                            (Some(src), Some(orig)) => {
+                                Some(src.reference(ExternalName::Export(orig.atom().clone())))
+                            }
+                            (Some(src), None) => Some(src.reference(ExternalName::Namespace)),
+                        };
+
+                        if let Some(reference) = reference {
+                            self.check_namespace(&filename, &reference, exported.span());
+                            info.exports.push((exported.atom().clone(), reference));
+                        }
+                    }
+                }
+                ModuleItem::ModuleDecl(ModuleDecl::ExportAll(export)) => {
+                    let src = self.resolve_src(&filename, &export.src);
+
+                    info.star_exports.push(src);
+                }
+                ModuleItem::ModuleDecl(ModuleDecl::TsImportEquals(mut import)) => {
+                    if let TsModuleRef::TsExternalModuleRef(module_ref) = &import.module_ref {
+                        if is_relative(&module_ref.expr.value) {
+                            self.unsupported(
+                                &filename,
+                                "`import = require()` of relative modules",
+                                import.span,
+                            );
+                            continue;
+                        }
+                    }
+
+                    let id = import.id.to_id();
+                    if import.is_export {
+                        info.exports
+                            .push((id.0.clone(), Reference::Local(id.clone())));
+                        import.is_export = false;
+                    }
+
+                    info.add_item(ModuleDecl::TsImportEquals(import).into(), vec![id]);
+                }
+                ModuleItem::ModuleDecl(ModuleDecl::TsExportAssignment(export)) => {
+                    self.unsupported(&filename, "`export =`", export.span);
+                }
+                ModuleItem::ModuleDecl(ModuleDecl::TsNamespaceExport(export)) => {
+                    self.unsupported(&filename, "`export as namespace`", export.span);
+                }
+                ModuleItem::Stmt(Stmt::Decl(decl)) => {
+                    let ids = decl_ids(&decl);
+
+                    info.add_item(Stmt::Decl(decl).into(), ids);
+                }
+                ModuleItem::Stmt(..) => {}
+            }
+        }
+
+        self.modules[id] = info;
+        self.order.push(id);
+
+        id
+    }
+
+    fn resolve_src(&mut self, filename: &Arc<FileName>, src: &Str) -> Source {
+        if !is_relative(&src.value) {
+            return Source::External(src.value.clone());
+        }
+
+        match self.bundler.resolve(filename, &src.value) {
+            Some(resolved) => Source::Module(self.load(resolved)),
+            None => {
+                self.cannot_find_module(filename, &src.value, src.span);
+                Source::External(src.value.clone())
+            }
+        }
+    }
+
+    /// Reports namespaces of bundled modules which can't be declared as a
+    /// namespace because they re-export other packages with `export *`.
+    fn check_namespace(&mut self, filename: &Arc<FileName>, reference: &Reference, span: Span) {
+        let Reference::Namespace(module) = reference else {
+            return;
+        };
+
+        let (_, external_stars) = self.module_exports(*module);
+        if !external_stars.is_empty() {
+            self.namespace_of_bundled_module(filename, span);
+        }
+    }
+
+    /// Resolves exports of `module`. Returns the exported bindings and the
+    /// packages re-exported by `export * from "..."`.
+    fn module_exports(&self, module: ModuleId) -> (Vec<(Atom, Binding)>, Vec<Atom>) {
+        let mut names = Vec::new();
+        let mut external_stars = Vec::new();
+        self.collect_exports(
+            module,
+            false,
+            &mut Default::default(),
+            &mut names,
+            &mut external_stars,
+        );
+
+        let exports = names
+            .into_iter()
+            .filter_map(|name| {
+                let binding = self.resolve_export(module, &name, &mut Default::default())?;
+                Some((name, binding))
+            })
+            .collect();
+
+        (exports, external_stars)
+    }
+
+    /// Collects names exported from `module`, including names exported by
+    /// `export * from "..."`.
+    fn collect_exports(
+        &self,
+        module: ModuleId,
+        is_star: bool,
+        visited: &mut FxHashSet<ModuleId>,
+        names: &mut Vec<Atom>,
+        external_stars: &mut Vec<Atom>,
+    ) {
+        if !visited.insert(module) {
+            return;
+        }
+
+        let info = &self.modules[module];
+
+        for (name, _) in &info.exports {
+            if (!is_star || name != "default") && !names.contains(name) {
+                names.push(name.clone());
+            }
+        }
+
+        for star in &info.star_exports {
+            match star {
+                Source::Module(module) => {
+                    self.collect_exports(*module, true, visited, names, external_stars)
+                }
+                Source::External(src) => {
+                    if !external_stars.contains(src) {
+                        external_stars.push(src.clone());
+                    }
+                }
+            }
+        }
+    }
+
+    fn resolve_export(
+        &self,
+        module: ModuleId,
+        name: &Atom,
+        visited: &mut FxHashSet<(ModuleId, Atom)>,
+    ) -> Option<Binding> {
+        if !visited.insert((module, name.clone())) {
+            return None;
+        }
+
+        let info = &self.modules[module];
+
+        if let Some((_, reference)) = info.exports.iter().find(|(export, _)| export == name) {
+            return self.resolve_reference(module, reference, visited);
+        }
+
+        if name == "default" {
+            return None;
+        }
+
+        info.star_exports.iter().find_map(|star| match star {
+            Source::Module(star) => self.resolve_export(*star, name, visited),
+            Source::External(..) => None,
+        })
+    }
+
+    fn resolve_reference(
+        &self,
+        module: ModuleId,
+        reference: &Reference,
+        visited: &mut FxHashSet<(ModuleId, Atom)>,
+    ) -> Option<Binding> {
+        match reference {
+            Reference::Local(id) => {
+                let info = &self.modules[module];
+
+                match info.imports.get(id) {
+                    Some(reference) => self.resolve_reference(module, reference, visited),
+                    None if info.decls.contains_key(id) => Some(Binding::Local(module, id.clone())),
+                    None => None,
+                }
+            }
+            Reference::Module(module, name) => self.resolve_export(*module, name, visited),
+            Reference::Namespace(module) => Some(Binding::Namespace(*module)),
+            Reference::External(src, name) => Some(Binding::External(src.clone(), name.clone())),
+        }
+    }
+
+    fn resolve(&self, module: ModuleId, reference: &Reference) -> Option<Binding> {
+        self.resolve_reference(module, reference, &mut Default::default())
+    }
+
+    fn include_item(&self, module: ModuleId, index: usize, usage: &mut Usage) {
+        if !usage.items.insert((module, index)) {
+            return;
+        }
+
+        let info = &self.modules[module];
+        let mut collector = IdentCollector::default();
+        info.items[index].item.visit_with(&mut collector);
+
+        for id in collector.ids {
+            usage.syms.insert(id.0.clone());
+
+            if id.1.outer() == self.bundler.unresolved_mark {
+                usage.globals.insert(id.0);
+            } else if info.decls.contains_key(&id) {
+                usage.queue.push(Binding::Local(module, id));
+            } else if let Some(binding) = info
+                .imports
+                .get(&id)
+                .and_then(|reference| self.resolve(module, reference))
+            {
+                usage.queue.push(binding);
+            }
+        }
+    }
+
+    fn bundle(&mut self, entry: ModuleId) -> Module {
+        // 1. Resolve exports of the entry module.
+        let (exports, external_stars) = self.module_exports(entry);
+
+        // 2. Collect declarations reachable from the exports.
+        let mut usage = Usage::default();
+        usage
+            .queue
+            .extend(exports.iter().map(|(_, binding)| binding.clone()));
+
+        for &module in &self.order {
+            for (index, item) in self.modules[module].items.iter().enumerate() {
+                if item.ids.is_empty() {
+                    self.include_item(module, index, &mut usage);
+                }
+            }
+        }
+
+        let mut next = 0;
+        while let Some(binding) = usage.queue.get(next).cloned() {
+            next += 1;
+
+            if !usage.bindings.insert(binding.clone()) {
+                continue;
+            }
+
+            match binding {
+                Binding::Local(module, id) => {
+                    for &index in &self.modules[module].decls[&id] {
+                        self.include_item(module, index, &mut usage);
+                    }
+                }
+                Binding::Namespace(module) => {
+                    // Packages re-exported by the module are reported while loading.
+                    let (members, _) = self.module_exports(module);
+                    usage
+                        .queue
+                        .extend(members.iter().map(|(_, binding)| binding.clone()));
+                    usage.namespaces.push((module, members));
+                }
+                Binding::External(src, name) => usage.externals.push((src, name)),
+            }
+        }
+
+        // 3. Name the bindings. Names of exports are preferred for the bindings
+        // exported from the entry module.
+        let mut names = Names {
+            taken: usage.globals.clone(),
+            syms: &usage.syms,
+            names: Default::default(),
+        };
+
+        for (name, binding) in &exports {
+            if let Binding::Local(_, id) = binding {
+                if id.0 == *name {
+                    names.claim(binding, name);
+                }
+            }
+        }
+
+        for &module in &self.order {
+            for (index, item) in self.modules[module].items.iter().enumerate() {
+                if usage.items.contains(&(module, index)) {
+                    for id in &item.ids {
+                        names.claim(&Binding::Local(module, id.clone()), &id.0);
+                    }
+                }
+            }
+        }
+
+        for &module in &self.order {
+            let info = &self.modules[module];
+
+            for local in &info.import_order {
+                if let Some(binding) = self.resolve(module, &info.imports[local]) {
+                    if matches!(binding, Binding::Namespace(..) | Binding::External(..))
+                        && usage.bindings.contains(&binding)
+                    {
+                        names.claim(&binding, &local.0);
+                    }
+                }
+            }
+        }
+
+        // Namespaces which are only members of other namespaces are named after
+        // the members.
+        let namespace_members = usage
+            .namespaces
+            .iter()
+            .flat_map(|(_, members)| members.iter());
+        for (name, binding) in exports.iter().chain(namespace_members) {
+            if let Binding::Namespace(..) | Binding::External(..) = binding {
+                let name = if name == "default" {
+                    "_default".into()
+                } else {
+                    name.clone()
+                };
+
+                names.claim(binding, &name);
+            }
+        }
+
+        let names = names.names;
+
+        // 4. Emit the bundle.
+        let mut body = Vec::new();
+
+        let mut srcs = Vec::new();
+        for (src, _) in &usage.externals {
+            if !srcs.contains(src) {
+                srcs.push(src.clone());
+            }
+        }
+
+        for src in srcs {
+            let mut specifiers = Vec::new();
+
+            for (_, name) in usage.externals.iter().filter(|(s, _)| *s == src) {
+                let local = Ident::new_no_ctxt(
+                    names[&Binding::External(src.clone(), name.clone())].clone(),
+                    DUMMY_SP,
+                );
+
+                match name {
+                    ExternalName::Export(name) if name == "default" => specifiers.insert(
+                        0,
+                        ImportSpecifier::Default(ImportDefaultSpecifier {
+                            span: DUMMY_SP,
+                            local,
+                        }),
+                    ),
+                    ExternalName::Export(name) => {
+                        specifiers.push(ImportSpecifier::Named(ImportNamedSpecifier {
+                            span: DUMMY_SP,
+                            imported: (*name != local.sym).then(|| module_export_name(name)),
+                            local,
+                            is_type_only: false,
+                        }))
+                    }
+                    ExternalName::Namespace => {
+                        body.push(import_decl(
+                            &src,
+                            vec![ImportSpecifier::Namespace(ImportStarAsSpecifier {
+                                span: DUMMY_SP,
+                                local,
+                            })],
+                        ));
+                    }
+                }
+            }
+
+            if !specifiers.is_empty() {
+                body.push(import_decl(&src, specifiers));
+            }
+        }
+
+        for &module in &self.order {
+            let info = &self.modules[module];
+
+            let mut renamer = Renamer {
+                names: Default::default(),
+            };
+            for id in info.decls.keys() {
+                if let Some(name) = names.get(&Binding::Local(module, id.clone())) {
+                    renamer.names.insert(id.clone(), name.clone());
+                }
+            }
+            for (id, reference) in &info.imports {
+                if let Some(name) = self
+                    .resolve(module, reference)
+                    .and_then(|binding| names.get(&binding))
+                {
+                    renamer.names.insert(id.clone(), name.clone());
+                }
+            }
+
+            for (index, item) in info.items.iter().enumerate() {
+                if !usage.items.contains(&(module, index)) {
+                    continue;
+                }
+
+                let mut item = item.item.clone();
+                item.visit_mut_with(&mut renamer);
+                if let Some(internal_annotations) = &info.internal_annotations {
+                    item.visit_mut_with(&mut InternalAnnotationTransformer::new(
+                        internal_annotations,
+                    ));
+                }
+
+                body.push(item);
+            }
+        }
+
+        for (module, members) in &usage.namespaces {
+            body.push(
+                Stmt::Decl(
+                    TsModuleDecl {
+                        span: DUMMY_SP,
+                        declare: true,
+                        global: false,
+                        namespace: true,
+                        id: TsModuleName::Ident(Ident::new_no_ctxt(
+                            names[&Binding::Namespace(*module)].clone(),
+                            DUMMY_SP,
+                        )),
+                        body: Some(TsNamespaceBody::TsModuleBlock(TsModuleBlock {
+                            span: DUMMY_SP,
+                            body: vec![named_export(export_specifiers(members, &names))],
+                        })),
+                    }
+                    .into(),
+                )
+                .into(),
+            );
+        }
+
+        let specifiers = export_specifiers(&exports, &names);
+
+        for src in &external_stars {
+            body.push(
+                ModuleDecl::ExportAll(ExportAll {
+                    span: DUMMY_SP,
+                    src: Box::new(src.clone().into()),
+                    type_only: false,
+                    with: None,
+                })
+                .into(),
+            );
+        }
+
+        if !specifiers.is_empty() || external_stars.is_empty() {
+            body.push(named_export(specifiers));
+        }
+
+        Module {
+            span: DUMMY_SP,
+            body,
+            shebang: None,
+        }
+    }
+}
+
+/// Diagnostics
+impl Graph<'_> {
+    fn cannot_find_module(&mut self, filename: &Arc<FileName>, src: &str, span: Span) {
+        self.mark_diagnostic(
+            filename,
+            format!("Cannot find module '{src}' to bundle declarations."),
+            span,
+        );
+    }
+
+    fn namespace_of_bundled_module(&mut self, filename: &Arc<FileName>, span: Span) {
+        self.mark_diagnostic(
+            filename,
+            "Namespaces of bundled modules re-exporting other packages with `export *` are not \
+             supported.",
+            span,
+        );
+    }
+
+    fn unsupported(&mut self, filename: &Arc<FileName>, what: &str, span: Span) {
+        self.mark_diagnostic(
+            filename,
+            format!("{what} are not supported when bundling declarations."),
+            span,
+        );
+    }
+}
+
+#[derive(Default)]
+struct Usage {
+    items: FxHashSet<(ModuleId, usize)>,
+    bindings: FxHashSet<Binding>,
+    /// Used bindings of other packages in the order of appearance.
+    externals: Vec<(Atom, ExternalName)>,
+    /// Used namespaces of bundled modules and their members.
+    namespaces: Vec<(ModuleId, Vec<(Atom, Binding)>)>,
+    queue: Vec<Binding>,
+    /// Names of unresolved references, which should not be shadowed.
+    globals: FxHashSet<Atom>,
+    /// All names referenced in included items.
+    syms: FxHashSet<Atom>,
+}
+
+struct Names<'a> {
+    taken: FxHashSet<Atom>,
+    syms: &'a FxHashSet<Atom>,
+    names: FxHashMap<Binding, Atom>,
+}
+
+impl Names<'_> {
+    fn claim(&mut self, binding: &Binding, name: &Atom) {
+        if self.names.contains_key(binding) {
+            return;
+        }
+
+        let name = if self.taken.contains(name) {
+            // Generated names should not be shadowed by any other binding.
+            (1..)
+                .map(|i| Atom::from(format!("{name}_{i}")))
+                .find(|name| !self.taken.contains(name) && !self.syms.contains(name))
+                .unwrap()
+        } else {
+            name.clone()
+        };
+
+        self.taken.insert(name.clone());
+        self.names.insert(binding.clone(), name);
+    }
+}
+
+fn decl_ids(decl: &Decl) -> Vec<Id> {
+    match decl {
+        Decl::Class(class) => vec![class.ident.to_id()],
+        Decl::Fn(function) => vec![function.ident.to_id()],
+        Decl::Var(var) => find_pat_ids(&var.decls),
+        Decl::Using(using) => find_pat_ids(&using.decls),
+        Decl::TsInterface(interface) => vec![interface.id.to_id()],
+        Decl::TsTypeAlias(alias) => vec![alias.id.to_id()],
+        Decl::TsEnum(ts_enum) => vec![ts_enum.id.to_id()],
+        Decl::TsModule(module) => match &module.id {
+            TsModuleName::Ident(ident) if !module.global => vec![ident.to_id()],
+            _ => Vec::new(),
+        },
+    }
+}
+
+/// Extends the span of `decl` to cover `export`, so comments attached to the
+/// export are kept.
+fn extend_span_lo(decl: &mut Decl, lo: BytePos) {
+    let span = match decl {
+        Decl::Class(class) => &mut class.class.span,
+        Decl::Fn(function) => &mut function.function.span,
+        Decl::Var(var) => &mut var.span,
+        Decl::Using(using) => &mut using.span,
+        Decl::TsInterface(interface) => &mut interface.span,
+        Decl::TsTypeAlias(alias) => &mut alias.span,
+        Decl::TsEnum(ts_enum) => &mut ts_enum.span,
+        Decl::TsModule(module) => &mut module.span,
+    };
+
+    span.lo = lo;
+}
+
+fn default_ident() -> Ident {
+    Ident::new_no_ctxt("_default".into(), DUMMY_SP)
+}
+
+fn module_export_name(name: &Atom) -> ModuleExportName {
+    let mut chars = name.chars();
+    let is_ident =
+        chars.next().map_or(false, Ident::is_valid_start) && chars.all(Ident::is_valid_continue);
+
+    if is_ident {
+        ModuleExportName::Ident(Ident::new_no_ctxt(name.clone(), DUMMY_SP))
+    } else {
+        ModuleExportName::Str(name.clone().into())
+    }
+}
+
+fn export_specifiers(
+    exports: &[(Atom, Binding)],
+    names: &FxHashMap<Binding, Atom>,
+) -> Vec<ExportSpecifier> {
+    exports
+        .iter()
+        .map(|(name, binding)| {
+            let local = &names[binding];
+
+            ExportSpecifier::Named(ExportNamedSpecifier {
+                span: DUMMY_SP,
+                orig: ModuleExportName::Ident(Ident::new_no_ctxt(local.clone(), DUMMY_SP)),
+                exported: (name != local).then(|| module_export_name(name)),
+                is_type_only: false,
+            })
+        })
+        .collect()
+}
+
+fn named_export(specifiers: Vec<ExportSpecifier>) -> ModuleItem {
+    ModuleDecl::ExportNamed(NamedExport {
+        span: DUMMY_SP,
+        specifiers,
+        src: None,
+        type_only: false,
+        with: None,
+    })
+    .into()
+}
+
+fn import_decl(src: &Atom, specifiers: Vec<ImportSpecifier>) -> ModuleItem {
+    ModuleDecl::Import(ImportDecl {
+        span: DUMMY_SP,
+        specifiers,
+        src: Box::new(src.clone().into()),
+        type_only: false,
+        with: None,
+        phase: Default::default(),
+    })
+    .into()
+}
+
+#[derive(Default)]
+struct IdentCollector {
+    ids: Vec<Id>,
+}
+
+impl Visit for IdentCollector {
+    fn visit_ident(&mut self, n: &Ident) {
+        self.ids.push(n.to_id());
+    }
+}
+
+struct Renamer {
+    names: FxHashMap<Id, Atom>,
+}
+
+impl VisitMut for Renamer {
+    fn visit_mut_ident(&mut self, n: &mut Ident) {
+        if let Some(name) = self.names.get(&n.to_id()) {
+            n.sym = name.clone();
+        }
+    }
+}
@@ -393,7 +393,7 @@ impl FastDts {
         };
 
         Some(ClassMember::ClassProp(ClassProp {
-            span: DUMMY_SP,
+            span: ts_param_prop.span,
             key: PropName::Ident(ident.into()),
             value: None,
             type_ann,
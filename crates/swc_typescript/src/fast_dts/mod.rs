@@ -26,7 +26,7 @@ mod function;
 mod inferrer;
 mod types;
 mod util;
-mod visitors;
+pub(crate) mod visitors;
 
 /// TypeScript Isolated Declaration support.
 ///
@@ -248,13 +248,15 @@ impl FastDts {
                         self.default_export_inferred(expr.span());
                     }
 
+                    let span = expr.span();
+
                     items.push(
                         VarDecl {
-                            span: DUMMY_SP,
+                            span,
                             kind: VarDeclKind::Const,
                             declare: true,
                             decls: vec![VarDeclarator {
-                                span: DUMMY_SP,
+                                span,
                                 name: Pat::Ident(BindingIdent {
                                     id: name_ident.clone(),
                                     type_ann,
@@ -1,4 +1,5 @@
 #![allow(clippy::boxed_local)]
 
 pub mod diagnostic;
+pub mod dts_bundler;
 pub mod fast_dts;
@@ -0,0 +1,20 @@
+import { Point, distance } from "./point";
+import type { Options } from "./options.js";
+
+/** Creates a shape. */
+export function createShape(points: Point[], options?: Options): Shape {
+    return { points, perimeter: perimeter(points) };
+}
+
+/** A shape. */
+export interface Shape {
+    points: Point[];
+    perimeter: number;
+}
+
+function perimeter(points: Point[]): number {
+    return points.reduce((sum, p, i) => sum + distance(p, points[(i + 1) % points.length]), 0);
+}
+
+export { Point };
+export default createShape;
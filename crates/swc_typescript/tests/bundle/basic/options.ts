@@ -0,0 +1,5 @@
+import { Unit } from "./unit";
+
+export interface Options {
+    unit: Unit;
+}
@@ -0,0 +1,10 @@
+export interface Point {
+    x: number;
+    y: number;
+}
+
+export function distance(a: Point, b: Point): number {
+    return Math.hypot(a.x - b.x, a.y - b.y);
+}
+
+export function unused(): void {}
@@ -0,0 +1,3 @@
+export type Unit = "px" | "em";
+
+export const DEFAULT_UNIT: Unit = "px";
@@ -0,0 +1,7 @@
+export interface Config {
+    name: string;
+}
+
+export function makeA(): Config {
+    return { name: "a" };
+}
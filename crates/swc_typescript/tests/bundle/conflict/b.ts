@@ -0,0 +1,5 @@
+interface Config {
+    enabled: boolean;
+}
+
+export type { Config };
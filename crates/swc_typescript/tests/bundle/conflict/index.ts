@@ -0,0 +1,11 @@
+import { Config as AConfig, makeA } from "./a";
+import { Config as BConfig } from "./b";
+
+export interface Config {
+    a: AConfig;
+    b: BConfig;
+}
+
+export function make<Config_1>(value: Config_1): Config {
+    return { a: makeA(), b: {} as BConfig };
+}
@@ -0,0 +1,13 @@
+import type { Readable } from "node:stream";
+import * as React from "react";
+import EventEmitter from "node:events";
+import { Props } from "./props";
+import { missing } from "./missing";
+
+export declare class Stream extends EventEmitter {
+    source: Readable;
+    render(props: Props): React.ReactNode;
+}
+
+export { Readable as Source } from "node:stream";
+export { missing };
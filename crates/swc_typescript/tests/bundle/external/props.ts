@@ -0,0 +1,7 @@
+import type { ReactNode } from "react";
+import type { Readable } from "stream";
+
+export interface Props {
+    children: ReactNode;
+    stream: Readable;
+}
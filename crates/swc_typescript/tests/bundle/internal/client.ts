@@ -0,0 +1,19 @@
+/** @internal */
+export interface Transport {
+    send(data: string): void;
+}
+
+export class Client {
+    /** @internal */
+    transport?: Transport;
+
+    request(path: string): Promise<string> {
+        return Promise.resolve(path);
+    }
+}
+
+export interface ClientOptions {
+    /** @internal */
+    debug: boolean;
+    timeout: number;
+}
@@ -0,0 +1 @@
+export { Client } from "./client";
@@ -0,0 +1,9 @@
+import * as units from "./units";
+
+export * as shapes from "./shapes";
+export * as widgets from "./widgets";
+
+export interface Size {
+    width: units.Length;
+    height: units.Length;
+}
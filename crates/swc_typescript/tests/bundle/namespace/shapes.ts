@@ -0,0 +1,15 @@
+import type { Length } from "./units";
+
+export interface Circle {
+    radius: Length;
+}
+
+export interface Square {
+    side: Length;
+}
+
+export * as units from "./units";
+
+export default function area(shape: Circle | Square): number {
+    return 0;
+}
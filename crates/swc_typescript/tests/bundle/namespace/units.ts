@@ -0,0 +1,6 @@
+export type Length = number;
+
+export enum Unit {
+    Px,
+    Em,
+}
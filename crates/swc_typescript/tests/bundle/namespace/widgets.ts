@@ -0,0 +1,5 @@
+export * from "react";
+
+export interface Widget {
+    size: number;
+}
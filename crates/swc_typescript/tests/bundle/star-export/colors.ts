@@ -0,0 +1,10 @@
+export enum Color {
+    Red,
+    Green,
+}
+
+export interface Palette {
+    primary: Color;
+}
+
+export default "colors";
@@ -0,0 +1,3 @@
+export * from "./colors";
+export * from "react";
+export { Shade as default } from "./shade";
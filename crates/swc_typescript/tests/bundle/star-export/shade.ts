@@ -0,0 +1,5 @@
+import { Color } from "./colors";
+
+export class Shade {
+    constructor(public color: Color, public lightness: number) {}
+}
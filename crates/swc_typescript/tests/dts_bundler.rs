@@ -0,0 +1,102 @@
+use std::{fs, path::PathBuf};
+
+use swc_common::{comments::SingleThreadedComments, FileName, Mark};
+use swc_ecma_codegen::to_code_with_comments;
+use swc_ecma_parser::{parse_file_as_program, Syntax, TsSyntax};
+use swc_ecma_transforms_base::{fixer::paren_remover, resolver};
+use swc_typescript::{
+    dts_bundler::{DtsBundler, DtsModule},
+    fast_dts::{FastDts, FastDtsOptions},
+};
+use testing::NormalizedOutput;
+
+#[testing::fixture("tests/bundle/**/index.ts")]
+fn fixture(input: PathBuf) {
+    let dir = input.parent().unwrap();
+    let mut bundle_code = String::new();
+
+    let res = testing::run_test2(false, |cm, handler| {
+        let unresolved_mark = Mark::new();
+        let comments = SingleThreadedComments::default();
+        let mut bundler = DtsBundler::new(unresolved_mark);
+
+        let mut files = fs::read_dir(dir)
+            .unwrap()
+            .map(|entry| entry.unwrap().path())
+            .filter(|path| path.extension().map_or(false, |ext| ext == "ts"))
+            .collect::<Vec<_>>();
+        files.sort();
+
+        for file in files {
+            let fm = cm.load_file(&file).expect("failed to load test case");
+            let top_level_mark = Mark::new();
+
+            let mut program = parse_file_as_program(
+                &fm,
+                Syntax::Typescript(TsSyntax {
+                    dts: file.to_string_lossy().ends_with(".d.ts"),
+                    ..Default::default()
+                }),
+                Default::default(),
+                Some(&comments),
+                &mut Vec::new(),
+            )
+            .map_err(|err| err.into_diagnostic(&handler).emit())
+            .map(|program| program.apply(resolver(unresolved_mark, top_level_mark, true)))
+            .map(|program| program.apply(paren_remover(None)))
+            .unwrap();
+
+            let internal_annotations = FastDts::get_internal_annotations(&comments);
+            let mut checker = FastDts::new(
+                fm.name.clone(),
+                unresolved_mark,
+                FastDtsOptions {
+                    internal_annotations: Some(internal_annotations.clone()),
+                },
+            );
+            for issue in checker.transform(&mut program) {
+                handler
+                    .struct_span_err(issue.range.span, &issue.message)
+                    .emit();
+            }
+
+            bundler.add_module(
+                fm.name.clone(),
+                DtsModule {
+                    module: program.expect_module(),
+                    internal_annotations: Some(internal_annotations),
+                },
+            );
+        }
+
+        let bundle = bundler
+            .bundle(&FileName::Real(input.clone()))
+            .expect("entry should be added");
+        bundle_code = to_code_with_comments(Some(&comments), &bundle.module);
+
+        for issue in bundle.diagnostics {
+            handler
+                .struct_span_err(issue.range.span, &issue.message)
+                .emit();
+        }
+
+        if handler.has_errors() {
+            Err(())
+        } else {
+            Ok(())
+        }
+    });
+
+    let mut output =
+        format!("```==================== .D.TS ====================\n\n{bundle_code}\n\n");
+
+    if let Err(issues) = res {
+        output.push_str(&format!(
+            "==================== Errors ====================\n{issues}\n\n```"
+        ));
+    }
+
+    NormalizedOutput::from(output)
+        .compare_to_file(dir.join("output.snap"))
+        .unwrap();
+}
@@ -7,7 +7,7 @@ use swc_ecma_transforms_base::{fixer::paren_remover, resolver};
 use swc_typescript::fast_dts::{FastDts, FastDtsOptions};
 use testing::NormalizedOutput;
 
-#[testing::fixture("tests/**/*.ts")]
+#[testing::fixture("tests/**/*.ts", exclude("bundle"))]
 #[testing::fixture("tests/**/*.tsx")]
 fn fixture(input: PathBuf) {
     let mut dts_code = String::new();
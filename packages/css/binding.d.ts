@@ -0,0 +1,38 @@
+/* auto-generated by NAPI-RS */
+/* eslint-disable */
+
+export interface CssModuleClassName {
+    /** `local`, `global` or `import`. */
+    kind: string;
+    name: string;
+    /** The module specifier, if `kind` is `import`. */
+    from?: string;
+}
+
+export interface Diagnostic {
+    level: string;
+    message: string;
+    span: any;
+}
+
+export declare function transformCss(
+    code: Buffer | string,
+    opts: Buffer,
+    signal?: AbortSignal | undefined | null
+): Promise<TransformOutput>;
+
+export declare function transformCssSync(
+    code: Buffer | string,
+    opts: Buffer
+): TransformOutput;
+
+export interface TransformOutput {
+    code: string;
+    map?: string;
+    /**
+     * A map of the class names in the stylesheet to the class names to use
+     * from JavaScript, if `cssModules` is enabled.
+     */
+    cssModules?: Record<string, Array<CssModuleClassName>>;
+    errors?: Array<Diagnostic>;
+}
@@ -0,0 +1,83 @@
+import * as binding from "./binding";
+
+export type Options = {
+    filename?: string;
+    parser?: {
+        allowWrongLineComments?: boolean;
+        cssModules?: boolean;
+        legacyNesting?: boolean;
+        legacyIe?: boolean;
+    };
+    minify?: boolean;
+    /**
+     * Renames class names, ids and keyframes to be local to the file.
+     *
+     * `pattern` defaults to `[local]_[hash]`. `[name]` is replaced with the
+     * name of the file, `[local]` with the original name and `[hash]` with a
+     * hash of the path of the file relative to the current directory.
+     */
+    cssModules?: boolean | { pattern?: string };
+    /**
+     * Browsers to support, as a browserslist query or a map of browser
+     * versions. If specified, syntax not supported by them is lowered and
+     * vendor prefixes are added.
+     */
+    targets?: string | string[] | Record<string, string>;
+    sourceMap?: boolean;
+    /**
+     * The source map of the input, e.g. one generated by a SCSS compiler.
+     * The generated source map points to its sources if specified.
+     */
+    inputSourceMap?: string;
+    /**
+     * Wasm plugins to run in order, authored with `#[css_plugin_transform]`.
+     * The first element of a tuple is the name of an npm package or a path
+     * to a `.wasm` file, and the second one is the config of the plugin.
+     *
+     * Plugins run right after parsing, so the other transforms apply to
+     * their output.
+     */
+    plugins?: [wasmPackage: string, config: Record<string, any>][];
+};
+
+export async function transformCss(
+    content: string | Buffer,
+    options?: Options
+): Promise<binding.TransformOutput> {
+    return binding.transformCss(
+        content,
+        toBuffer(resolvePlugins(options ?? {}))
+    );
+}
+
+export function transformCssSync(
+    content: string | Buffer,
+    options?: Options
+): binding.TransformOutput {
+    return binding.transformCssSync(
+        content,
+        toBuffer(resolvePlugins(options ?? {}))
+    );
+}
+
+/**
+ * Resolves names of plugins relative to the current directory, like `@swc/core`
+ * does.
+ */
+function resolvePlugins(options: Options): Options {
+    if (!options.plugins) {
+        return options;
+    }
+
+    return {
+        ...options,
+        plugins: options.plugins.map(([name, config]) => [
+            require.resolve(name, { paths: [process.cwd()] }),
+            config,
+        ]),
+    };
+}
+
+function toBuffer(t: any): Buffer {
+    return Buffer.from(JSON.stringify(t));
+}
@@ -40,6 +40,16 @@ export interface JsMinifyOptions {
     outputPath?: string;
 
     inlineSourcesContent?: boolean;
+
+    /**
+     * Path to a JSON file to persist mangled property names, in the format
+     * of `nameCache` of terser.
+     *
+     * The file is read before mangling and written after it, so that
+     * `mangle.props` yields the same names across builds. It's read once per
+     * process and shared by all files minified with it.
+     */
+    nameCacheFile?: string;
 }
 
 /**
@@ -522,6 +532,34 @@ export interface Options extends Config {
      * of source map files and swc does not write output to this path.
      */
     outputPath?: string;
+
+    /**
+     * This is experimental, and can be removed without a major version bump.
+     */
+    experimental?: {
+        errorFormat?: "json" | "normal";
+
+        /**
+         * Caches the output of files on disk, and reuses it if the file and
+         * the configuration did not change.
+         */
+        transformCache?: TransformCacheOptions;
+    };
+}
+
+export interface TransformCacheOptions {
+    /**
+     * The directory to store the cache in.
+     */
+    dir: string;
+
+    /**
+     * The maximum size of the cache in bytes. Least recently used entries are
+     * removed when it's exceeded.
+     *
+     * Defaults to 512 MiB.
+     */
+    maxSize?: number;
 }
 
 export interface CallerOptions {
@@ -667,6 +705,13 @@ export interface JscConfig {
          */
         plugins?: WasmPlugin[];
 
+        /**
+         * Limits on resources each Wasm plugin can use, keyed by the name of
+         * the plugin in `plugins`. A plugin exceeding them fails the transform
+         * with an error naming the plugin and the file.
+         */
+        pluginLimits?: Record<string, WasmPluginLimits>;
+
         /**
          * Run Wasm plugins before stripping TypeScript or decorators.
          *
@@ -684,6 +729,12 @@ export interface JscConfig {
          */
         emitIsolatedDts?: boolean;
 
+        /**
+         * Emit source maps of isolated dts files, mapping declarations to
+         * the original source. Requires `emitIsolatedDts`.
+         */
+        emitIsolatedDtsMap?: boolean;
+
         /**
          * Disable all lint rules.
          */
@@ -701,9 +752,54 @@ export interface JscConfig {
         [from: string]: string[];
     };
 
+    /**
+     * Conditions used to resolve `exports` and `imports` of package.json while
+     * rewriting import paths, e.g. `["node", "import"]`.
+     *
+     * `default` is always matched. If not set, `node` is used along with
+     * `import` or `require` depending on how the module is imported.
+     */
+    conditions?: string[];
+
     minify?: JsMinifyOptions;
 
     preserveAllComments?: boolean;
+
+    output?: JscOutputConfig;
+}
+
+export interface JscOutputConfig {
+    charset?: "utf8" | "ascii";
+    preamble?: string;
+    preserveAnnotations?: boolean;
+    sourceMapUrl?: string;
+
+    /**
+     * Quotes to use for string literals. Ignored if minifying.
+     *
+     * Defaults to `auto`, which keeps the quotes of the input if possible.
+     */
+    quoteStyle?: "auto" | "double" | "single";
+
+    /**
+     * Where to emit trailing commas in lists printed on multiple lines.
+     *
+     * Defaults to `none`.
+     */
+    trailingComma?: "none" | "es5" | "all";
+
+    /**
+     * Prints arguments of calls on multiple lines if the line would be
+     * longer than this. Ignored if minifying.
+     */
+    printWidth?: number;
+
+    /**
+     * Indentation of lines, e.g. `"tab"` or `{ "spaces": 2 }`.
+     *
+     * Defaults to four spaces.
+     */
+    indent?: "tab" | { spaces: number };
 }
 
 export type JscTarget =
@@ -1184,6 +1280,15 @@ export interface BaseModuleConfig {
      * If set to true, This will resolve top .mjs
      */
     resolveFully?: boolean;
+    /**
+     * Rewrites `.ts`, `.tsx`, `.mts` and `.cts` extensions in relative
+     * imports to `.js`, `.js`, `.mjs` and `.cjs`, like the TypeScript option
+     * with the same name. Unlike `resolveFully`, the imported files do not
+     * need to exist on disk.
+     *
+     * Defaults to `false`.
+     */
+    rewriteRelativeImportExtensions?: boolean;
 }
 
 export interface Es6Config extends BaseModuleConfig {
@@ -1220,6 +1325,22 @@ export interface Output {
      * Sourcemap (**not** base64 encoded)
      */
     map?: string;
+    /**
+     * Files emitted by Wasm plugins, like an extracted stylesheet, to be
+     * written next to the output file.
+     */
+    artifacts?: OutputArtifact[];
+}
+
+export interface OutputArtifact {
+    /**
+     * Path of the file, relative to the directory of the output file.
+     */
+    path: string;
+    /**
+     * Content of the file, base64 encoded.
+     */
+    content: string;
 }
 
 export interface MatchPattern {}
@@ -2937,3 +3058,20 @@ export type WasmAnalysisOptions = {
 };
 
 export type WasmPlugin = [wasmPackage: string, config: Record<string, any>];
+
+/**
+ * Limits on resources a Wasm plugin can use while transforming a file.
+ *
+ * There is no wall-clock deadline. Time spent in functions of swc called by a
+ * plugin is not counted.
+ */
+export interface WasmPluginLimits {
+    /**
+     * The maximum number of Wasm instructions a plugin can execute per file.
+     */
+    fuel?: number;
+    /**
+     * The maximum size of the linear memory of a plugin, in bytes.
+     */
+    maxMemory?: number;
+}
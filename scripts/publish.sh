@@ -18,6 +18,7 @@ echo "Publishing $version with swc_core $swc_core_version"
 
 # Update version
 (cd ./packages/core && npm version "$version" --no-git-tag-version --allow-same-version || true)
+(cd ./packages/css && npm version "$version" --no-git-tag-version --allow-same-version || true)
 (cd ./packages/html && npm version "$version" --no-git-tag-version --allow-same-version || true)
 (cd ./packages/minifier && npm version "$version" --no-git-tag-version --allow-same-version || true)
 (cd ./bindings && cargo set-version $version -p binding_core_wasm -p binding_minifier_wasm -p binding_typescript_wasm)